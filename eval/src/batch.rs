@@ -0,0 +1,70 @@
+use crate::eval_expr::EvalExpr;
+use crate::writer::ColorWriter;
+use crate::EvalType;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+/// Evaluates each whitespace separated expression of the file at `path` and
+/// prints one result per line.
+///
+/// Expressions are independent from each other, all of them use the current config
+/// but variables assigned in one expression are not visible to the others.
+pub fn run_batch(r#type: EvalType, path: &Path) -> std::io::Result<()> {
+    let reader = BufReader::new(File::open(path)?);
+
+    eval_batch(&EvalExpr::new(r#type), reader, |result| match result {
+        Ok(value) => ColorWriter::new().writeln(value),
+        Err(err) => ColorWriter::new().red().writeln_err(err),
+    })
+}
+
+/// Evaluates the expressions read from `reader` as they come, passing each result to `f`.
+pub fn eval_batch<R, F>(eval: &EvalExpr, reader: R, mut f: F) -> std::io::Result<()>
+where
+    R: BufRead,
+    F: FnMut(prexel::Result<String>),
+{
+    for line in reader.lines() {
+        for expr in line?.split_whitespace() {
+            f(eval.eval(expr));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn eval_batch_file_test() {
+        // A directory for this process, so the test can run along other instances of it
+        let dir = std::env::temp_dir().join(format!("prexel_eval_batch_test_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("expressions.txt");
+        let mut file = File::create(&path).unwrap();
+        writeln!(file, "1+2 2*3").unwrap();
+        writeln!(file).unwrap();
+        writeln!(file, "  8/4\t2^3").unwrap();
+        writeln!(file, "x=5 x").unwrap();
+        drop(file);
+
+        let mut results = Vec::new();
+        let reader = BufReader::new(File::open(&path).unwrap());
+        eval_batch(&EvalExpr::new(EvalType::Decimal), reader, |r| results.push(r)).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(results.len(), 6);
+        assert_eq!(results[0].as_deref().unwrap(), "3");
+        assert_eq!(results[1].as_deref().unwrap(), "6");
+        assert_eq!(results[2].as_deref().unwrap(), "2");
+        assert_eq!(results[3].as_deref().unwrap(), "8");
+
+        // Assignments are not expressions, and variables are not shared between expressions
+        assert!(results[4].is_err());
+        assert!(results[5].is_err());
+    }
+}
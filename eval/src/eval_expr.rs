@@ -1,7 +1,7 @@
 use crate::EvalType;
 use once_cell::sync::Lazy;
 use prexel::complex::Complex;
-use prexel::context::{Config, DefaultContext};
+use prexel::context::{Config, Context, DefaultContext};
 use prexel::evaluator::Evaluator;
 use std::sync::Mutex;
 use prexel::binary::binary_number_splitter;
@@ -36,19 +36,25 @@ impl EvalExpr {
 pub fn eval_decimal(expr: &str) -> prexel::Result<String> {
     let context = DefaultContext::with_config_decimal(CONFIG.lock().unwrap().clone());
     let evaluator = Evaluator::with_context(context);
-    evaluator.eval(expr).map(|v| v.to_string())
+    evaluator
+        .eval(expr)
+        .map(|v| evaluator.context().config().format_output(&v))
 }
 
 pub fn eval_float(expr: &str) -> prexel::Result<String> {
     let context = DefaultContext::with_config_unchecked(CONFIG.lock().unwrap().clone());
     let evaluator = Evaluator::<f64>::with_context(context);
-    evaluator.eval(expr).map(|v| v.to_string())
+    evaluator
+        .eval(expr)
+        .map(|v| evaluator.context().config().format_output(&v))
 }
 
 pub fn eval_integer(expr: &str) -> prexel::Result<String> {
     let context = DefaultContext::with_config_checked(CONFIG.lock().unwrap().clone());
     let evaluator = Evaluator::<i128>::with_context(context);
-    evaluator.eval(expr).map(|v| v.to_string())
+    evaluator
+        .eval(expr)
+        .map(|v| evaluator.context().config().format_output(&v))
 }
 
 pub fn eval_complex(expr: &str) -> prexel::Result<String> {
@@ -56,7 +62,9 @@ pub fn eval_complex(expr: &str) -> prexel::Result<String> {
         CONFIG.lock().unwrap().clone().with_complex_number(true),
     );
     let evaluator = Evaluator::<Complex<f64>>::with_context(context);
-    evaluator.eval(expr).map(|v| v.to_string())
+    evaluator
+        .eval(expr)
+        .map(|v| evaluator.context().config().format_output(&v))
 }
 
 pub fn eval_binary(expr: &str) -> prexel::Result<String> {
@@ -4,11 +4,13 @@ mod list;
 mod repl;
 mod style;
 mod collections;
+mod batch;
 
 use crate::writer::{ColorWriter, set_use_colors};
 use crate::eval_expr::EvalExpr;
 use crate::list::ListKind;
 use clap::{Parser, Subcommand};
+use std::path::PathBuf;
 use std::str::FromStr;
 use crate::repl::ReplConfig;
 
@@ -42,6 +44,9 @@ struct Cli {
     #[clap(long, global = true, help = "Disables color output")]
     no_color: bool,
 
+    #[clap(long, global = true, help = "Separates the thousands of the results with the given character")]
+    thousands_separator: Option<char>,
+
     #[clap(subcommand)]
     commands: Commands,
 }
@@ -52,7 +57,15 @@ enum Commands {
     Eval {
         #[clap(long, short, default_value = "decimal")]
         r#type: EvalType,
-        expression: String,
+        #[clap(required_unless_present = "file")]
+        expression: Option<String>,
+        #[clap(
+            long,
+            short,
+            conflicts_with = "expression",
+            help = "Evaluates each whitespace separated expression in the file independently"
+        )]
+        file: Option<PathBuf>,
     },
 
     #[clap(about = "Evaluates math expressions in a REPL (read-eval-print loop)")]
@@ -86,11 +99,24 @@ fn main() {
     let cli: Cli = Cli::parse();
     set_use_colors(!cli.no_color);
 
+    if let Some(separator) = cli.thousands_separator {
+        let mut config = eval_expr::CONFIG.lock().unwrap();
+        *config = config.clone().with_thousands_output_separator(separator);
+    }
+
     match cli.commands {
-        Commands::Eval { r#type, expression } => match EvalExpr::new(r#type).eval(&expression) {
-            Ok(result) => ColorWriter::new().writeln(result),
-            Err(err) => ColorWriter::new().red().writeln_err(err),
-        },
+        Commands::Eval { r#type, file: Some(file), .. } => {
+            if let Err(err) = batch::run_batch(r#type, &file) {
+                ColorWriter::new().red().writeln_err(format!("{}: {}", file.display(), err));
+            }
+        }
+        Commands::Eval { r#type, expression, .. } => {
+            let expression = expression.unwrap_or_default();
+            match EvalExpr::new(r#type).eval(&expression) {
+                Ok(result) => ColorWriter::new().writeln(result),
+                Err(err) => ColorWriter::new().red().writeln_err(err),
+            }
+        }
         Commands::Repl { r#type, history } => {
             repl::run_repl(ReplConfig {
                 history_size: history,
@@ -4,15 +4,19 @@ use std::ops::ControlFlow;
 use std::str::{Chars, FromStr};
 use prexel::complex::Complex;
 use prexel::context::{Context, DefaultContext};
+use prexel::error::ErrorKind;
 use prexel::evaluator::Evaluator;
 use prexel::num_traits::Zero;
 use prexel::tokenizer::Tokenizer;
+use prexel::utils::{is_balanced, BalanceState};
 use prexel::utils::splitter::{DefaultSplitterBuilder, SplitWhitespaceOption};
 use prexel::utils::splitter::rules::{self, Outcome, SplitRule};
 use crate::eval_expr::CONFIG;
 use crate::EvalType;
 use crate::repl::repl::ReplBuilder;
+use crate::repl::repl_writer::ReplWriter;
 
+#[allow(clippy::module_inception)]
 pub mod repl;
 pub mod repl_writer;
 pub mod theme;
@@ -23,70 +27,271 @@ pub struct ReplConfig {
 }
 
 pub fn run_repl(config: ReplConfig) {
-    match config.eval_type {
+    let ReplConfig {
+        history_size,
+        eval_type
+    } = config;
+
+    let mut session = new_session(eval_type.clone(), &[]);
+    let mut eval_type = eval_type;
+
+    let repl = ReplBuilder::new()
+        .prompt_prefix(">>> ")
+        .pre_text("Press CTRL+C or type 'exit' to Exit, type ':type <name>' to change the number type")
+        .exit_text("Bye bye!")
+        .history_size(history_size)
+        .build();
+
+    let mut input = MultilineInput::default();
+
+    repl.run(|s, writer| {
+        let expression = match input.push_line(&s) {
+            Some(expression) => expression,
+            None => {
+                writer.write("... ");
+                return None;
+            }
+        };
+
+        let expression = expression.trim();
+
+        match expression {
+            // Exits the REPL
+            "exit" => {
+                return Some(ControlFlow::Break(()));
+            }
+
+            // Runs a REPL command
+            _ if expression.starts_with(':') => match parse_command(expression) {
+                Ok(command) => run_command(command, &mut eval_type, &mut session, writer),
+                Err(err) => {
+                    writer.red().writeln_err(err);
+                }
+            },
+
+            _ => {
+                if !session.eval_line(expression, writer) {
+                    input.resume(expression);
+                    writer.write("... ");
+                }
+            }
+        }
+
+        None
+    });
+}
+
+fn run_command(
+    command: ReplCommand,
+    eval_type: &mut EvalType,
+    session: &mut Box<dyn ReplSession>,
+    writer: &mut ReplWriter,
+) {
+    match command {
+        ReplCommand::SetType(new_type) => {
+            *session = new_session(new_type.clone(), &session.variables());
+            writer.green().writeln(format!("Number type changed to {:?}", new_type));
+            *eval_type = new_type;
+        }
+        ReplCommand::Vars => {
+            writer.cyan().writeln(variables_output(session.as_ref()));
+        }
+        ReplCommand::Funcs => {
+            writer.cyan().writeln(session.functions().join(", "));
+        }
+        ReplCommand::Clear => {
+            *session = new_session(eval_type.clone(), &[]);
+            writer.green().writeln("Variables cleared");
+        }
+    }
+}
+
+/// Accumulates the lines of an expression that continues in the next line, that is
+/// when a line ends with `\` or there are grouping symbols not closed, eg: `Max(1,`,
+/// or the expression is incomplete, eg: `8 +`, see `MultilineInput::resume`.
+#[derive(Debug, Default)]
+struct MultilineInput {
+    buf: String,
+}
+
+impl MultilineInput {
+    /// Adds a line to the input, returns the expression if is complete.
+    fn push_line(&mut self, line: &str) -> Option<String> {
+        let line = line.trim_end();
+
+        match line.strip_suffix('\\') {
+            Some(rest) => {
+                self.buf.push_str(rest);
+                None
+            }
+            None => {
+                self.buf.push_str(line);
+
+                if let BalanceState::Unbalanced(_) = is_balanced(&self.buf, &CONFIG.lock().unwrap()) {
+                    // Keeps the lines separated
+                    self.buf.push(' ');
+                    None
+                } else {
+                    Some(std::mem::take(&mut self.buf))
+                }
+            }
+        }
+    }
+
+    /// Continues the given incomplete expression with the next lines.
+    fn resume(&mut self, expression: &str) {
+        self.buf.push_str(expression);
+        self.buf.push(' ');
+    }
+}
+
+/// A command of the REPL, eg: `:type complex`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+enum ReplCommand {
+    /// Changes the number type used for evaluate the expressions.
+    SetType(EvalType),
+    /// Lists the variables.
+    Vars,
+    /// Lists the functions.
+    Funcs,
+    /// Removes the variables.
+    Clear,
+}
+
+fn parse_command(command: &str) -> Result<ReplCommand, String> {
+    let mut parts = command.split_whitespace();
+
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(":type"), Some(name), None) => EvalType::from_str(name).map(ReplCommand::SetType),
+        (Some(":type"), _, _) => Err("Expected a single number type, eg: `:type complex`".to_string()),
+        (Some(":vars"), None, None) => Ok(ReplCommand::Vars),
+        (Some(":funcs"), None, None) => Ok(ReplCommand::Funcs),
+        (Some(":clear"), None, None) => Ok(ReplCommand::Clear),
+        _ => Err(format!("Unknown command: `{}`", command.trim())),
+    }
+}
+
+/// The state of the REPL for a number type.
+trait ReplSession {
+    /// Evaluates the given expression and returns the formatted result.
+    fn eval(&mut self, expression: &str) -> prexel::Result<String>;
+
+    /// Evaluates or executes the given line, returns `false` if the expression is incomplete
+    /// and the next line should continue it, eg: `8 +`.
+    fn eval_line(&mut self, expression: &str, writer: &mut ReplWriter) -> bool;
+
+    /// Gets the name and value of the variables.
+    fn variables(&self) -> Vec<(String, String)>;
+
+    /// Gets the names of the functions, sorted.
+    fn functions(&self) -> Vec<String>;
+}
+
+/// Gets the variables of the session as `name = value` lines, sorted by name.
+fn variables_output(session: &dyn ReplSession) -> String {
+    let mut variables = session.variables();
+    variables.sort();
+
+    variables
+        .iter()
+        .map(|(name, value)| format!("{} = {}", name, value))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Creates a session for the given type, the variables that cannot be converted are ignored.
+fn new_session(eval_type: EvalType, variables: &[(String, String)]) -> Box<dyn ReplSession> {
+    let config = CONFIG.lock().unwrap().clone();
+
+    match eval_type {
         EvalType::Decimal => {
-            let context = DefaultContext::with_config_decimal(CONFIG.lock().unwrap().clone());
-            eval_loop(config, move || context)
+            let context = DefaultContext::with_config_decimal(config);
+            Box::new(EvalSession::new(context, false, variables))
         }
         EvalType::Float => {
-            let context =
-                DefaultContext::<f64>::with_config_unchecked(CONFIG.lock().unwrap().clone());
-            eval_loop(config, move || context)
+            let context = DefaultContext::<f64>::with_config_unchecked(config);
+            Box::new(EvalSession::new(context, false, variables))
         }
         EvalType::Integer => {
-            let context =
-                DefaultContext::<i128>::with_config_checked(CONFIG.lock().unwrap().clone());
-            eval_loop(config, move || context)
+            let context = DefaultContext::<i128>::with_config_checked(config);
+            Box::new(EvalSession::new(context, false, variables))
         }
         EvalType::Complex => {
             let context = DefaultContext::<Complex<f64>>::with_config_complex(
-                CONFIG.lock().unwrap().clone().with_complex_number(true),
+                config.with_complex_number(true),
             );
-            eval_loop(config, move || context)
+            Box::new(EvalSession::new(context, false, variables))
         },
         EvalType::Binary => {
-            let context = DefaultContext::with_config_binary(CONFIG.lock().unwrap().clone());
-            eval_loop(config, move || context)
+            let context = DefaultContext::with_config_binary(config);
+            Box::new(EvalSession::new(context, true, variables))
         },
     }
 }
 
-fn eval_loop<'a, N, F>(config: ReplConfig, factory: F)
+/// Converts a binary value exported by a session, eg: `b101`, to its decimal representation,
+/// returns `None` if the value is not binary.
+fn binary_to_decimal(value: &str) -> Option<String> {
+    let digits = value.strip_prefix('b')?;
+    // The negative values are displayed in two's complement
+    u128::from_str_radix(digits, 2)
+        .ok()
+        .map(|n| (n as i128).to_string())
+}
+
+struct EvalSession<'a, N> {
+    evaluator: Evaluator<'a, N>,
+    is_binary: bool,
+}
+
+impl<'a, N> EvalSession<'a, N>
     where
         N: FromStr + Clone + Display + Debug + Zero + 'a,
-        F: FnOnce() -> DefaultContext<'a, N>,
         <N as FromStr>::Err: Display,
 {
-    const RESULT: &str = "$result";
+    const RESULT: &'static str = "$result";
 
-    let ReplConfig {
-        history_size,
-        eval_type
-    } = config;
+    fn new(mut context: DefaultContext<'a, N>, is_binary: bool, variables: &[(String, String)]) -> Self {
+        context.set_variable(Self::RESULT, N::zero()).unwrap();
 
-    let mut context = factory();
-    context.set_variable(RESULT, N::zero()).unwrap();
+        for (name, value) in variables {
+            // Binary values are converted to decimal, so they can be used by any number type
+            let value = binary_to_decimal(value).unwrap_or_else(|| value.clone());
+            if let Ok(value) = N::from_str(&value) {
+                context.set_variable(name, value).unwrap();
+            }
+        }
 
-    let is_binary = matches!(eval_type, EvalType::Binary);
-    let tokenizer = repl_tokenizer(is_binary);
-    let mut evaluator = Evaluator::with_context_and_tokenizer(context, tokenizer);
+        let tokenizer = repl_tokenizer(is_binary);
+        let evaluator = Evaluator::with_context_and_tokenizer(context, tokenizer);
+        EvalSession { evaluator, is_binary }
+    }
+}
 
-    let repl = ReplBuilder::new()
-        .prompt_prefix(">>> ")
-        .pre_text("Press CTRL+C or type 'exit' to Exit")
-        .exit_text("Bye bye!")
-        .history_size(history_size)
-        .build();
+impl<'a, N> ReplSession for EvalSession<'a, N>
+    where
+        N: FromStr + Clone + Display + Debug + Zero + 'a,
+        <N as FromStr>::Err: Display,
+{
+    fn eval(&mut self, expression: &str) -> prexel::Result<String> {
+        let evaluator = &mut self.evaluator;
+        let result = evaluator.eval(expression)?;
 
-    repl.run(|s, writer| {
-        let expression = s.trim();
+        // Binary numbers are not grouped by thousands
+        let output = if self.is_binary {
+            result.to_string()
+        } else {
+            evaluator.context().config().format_output(&result)
+        };
 
-        match expression {
-            // Exits the REPL
-            "exit" => {
-                return Some(ControlFlow::Break(()));
-            }
+        evaluator.mut_context().set_variable(Self::RESULT, result)?;
+        Ok(output)
+    }
+
+    fn eval_line(&mut self, expression: &str, writer: &mut ReplWriter) -> bool {
+        let evaluator = &mut self.evaluator;
 
+        match expression {
             // Assign a variable
             _ if expression.contains('=') => {
                 let parts = expression
@@ -113,12 +318,12 @@ fn eval_loop<'a, N, F>(config: ReplConfig, factory: F)
             }
 
             // Evaluates the expression
-            _ => match evaluator.eval(expression) {
-                Ok(result) => {
-                    writer.green().writeln(&result);
-                    if let Err(err) = evaluator.mut_context().set_variable(RESULT, result) {
-                        writer.red().writeln_err(err);
-                    }
+            _ => match self.eval(expression) {
+                Ok(output) => {
+                    writer.green().writeln(&output);
+                }
+                Err(err) if err.kind() == ErrorKind::IncompleteExpression => {
+                    return false;
                 }
                 Err(err) => {
                     writer.red().writeln_err(err);
@@ -126,8 +331,38 @@ fn eval_loop<'a, N, F>(config: ReplConfig, factory: F)
             },
         }
 
-        None
-    });
+        true
+    }
+
+    fn variables(&self) -> Vec<(String, String)> {
+        self.evaluator
+            .context()
+            .variables()
+            .iter()
+            .map(|(name, value)| {
+                // Binary numbers are displayed without its prefix
+                let value = if self.is_binary {
+                    format!("b{}", value)
+                } else {
+                    value.to_string()
+                };
+
+                (name.to_string(), value)
+            })
+            .collect()
+    }
+
+    fn functions(&self) -> Vec<String> {
+        let mut functions = self.evaluator
+            .context()
+            .function_names()
+            .into_iter()
+            .map(|name| name.to_string())
+            .collect::<Vec<_>>();
+
+        functions.sort();
+        functions
+    }
 }
 
 fn repl_tokenizer<'a, N>(is_binary: bool) -> Tokenizer<'a, N>
@@ -171,3 +406,124 @@ fn repl_tokenizer<'a, N>(is_binary: bool) -> Tokenizer<'a, N>
 
     Tokenizer::with_splitter(builder.build())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_command_test() {
+        assert_eq!(parse_command(":type complex"), Ok(ReplCommand::SetType(EvalType::Complex)));
+        assert_eq!(parse_command("  :type   binary "), Ok(ReplCommand::SetType(EvalType::Binary)));
+
+        assert!(parse_command(":type").is_err());
+        assert!(parse_command(":type natural").is_err());
+        assert!(parse_command(":type float integer").is_err());
+        assert_eq!(parse_command(":vars"), Ok(ReplCommand::Vars));
+        assert_eq!(parse_command(":funcs"), Ok(ReplCommand::Funcs));
+        assert_eq!(parse_command(":clear"), Ok(ReplCommand::Clear));
+        assert!(parse_command(":vars x").is_err());
+        assert!(parse_command(":unknown").is_err());
+    }
+
+    fn value_of(session: &dyn ReplSession, name: &str) -> Option<String> {
+        session
+            .variables()
+            .into_iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, value)| value)
+    }
+
+    #[test]
+    fn switch_type_variables_test() {
+        let variables = [
+            ("x".to_string(), "5".to_string()),
+            ("y".to_string(), "2.5".to_string()),
+        ];
+
+        let decimal = new_session(EvalType::Decimal, &variables);
+        assert_eq!(value_of(decimal.as_ref(), "x"), Some("5".to_string()));
+        assert_eq!(value_of(decimal.as_ref(), "y"), Some("2.5".to_string()));
+
+        // `2.5` cannot be converted to an integer
+        let integer = new_session(EvalType::Integer, &decimal.variables());
+        assert_eq!(value_of(integer.as_ref(), "x"), Some("5".to_string()));
+        assert_eq!(value_of(integer.as_ref(), "y"), None);
+        assert_eq!(value_of(integer.as_ref(), "$result"), Some("0".to_string()));
+
+        let mut binary = new_session(EvalType::Binary, &integer.variables());
+        assert_eq!(value_of(binary.as_ref(), "x"), Some("b101".to_string()));
+
+        // The binary values are converted back to numbers
+        binary.eval("~b11").unwrap();
+        let from_binary = new_session(EvalType::Integer, &binary.variables());
+        assert_eq!(value_of(from_binary.as_ref(), "x"), Some("5".to_string()));
+        assert_eq!(value_of(from_binary.as_ref(), "$result"), Some("-4".to_string()));
+
+        let from_binary = new_session(EvalType::Decimal, &binary.variables());
+        assert_eq!(value_of(from_binary.as_ref(), "x"), Some("5".to_string()));
+
+        let from_binary = new_session(EvalType::Binary, &binary.variables());
+        assert_eq!(value_of(from_binary.as_ref(), "x"), Some("b101".to_string()));
+        assert_eq!(value_of(from_binary.as_ref(), "$result"), Some(format!("b{:b}", -4_i128)));
+
+        let complex = new_session(EvalType::Complex, &decimal.variables());
+        assert_eq!(value_of(complex.as_ref(), "y"), Some("2.5+0i".to_string()));
+    }
+
+    #[test]
+    fn vars_and_funcs_test() {
+        let mut session = new_session(EvalType::Integer, &[]);
+        session.eval_line("x=3", &mut ReplWriter::new());
+
+        let output = variables_output(session.as_ref());
+        assert!(output.contains("x = 3"));
+        assert!(output.contains("$result = 0"));
+
+        let functions = session.functions();
+        assert!(functions.contains(&"max".to_string()));
+        assert!(functions.contains(&"sqrt".to_string()));
+    }
+
+    #[test]
+    fn clear_test() {
+        let mut writer = ReplWriter::new();
+        let mut eval_type = EvalType::Integer;
+        let mut session = new_session(eval_type.clone(), &[]);
+
+        session.eval_line("x=3", &mut writer);
+        assert_eq!(session.eval("x + 1"), Ok("4".to_string()));
+
+        run_command(ReplCommand::Clear, &mut eval_type, &mut session, &mut writer);
+        assert!(session.eval("x + 1").is_err());
+        assert_eq!(session.eval("$result + 1"), Ok("1".to_string()));
+        assert_eq!(eval_type, EvalType::Integer);
+    }
+
+    #[test]
+    fn multiline_input_test() {
+        let mut input = MultilineInput::default();
+        assert_eq!(input.push_line("2 + 3"), Some("2 + 3".to_string()));
+
+        assert_eq!(input.push_line("Max(1,"), None);
+        assert_eq!(input.push_line("  [2 * 3],"), None);
+        let expression = input.push_line("4) + 1").unwrap();
+
+        let mut session = new_session(EvalType::Integer, &[]);
+        assert_eq!(session.eval(&expression), Ok("7".to_string()));
+
+        assert_eq!(input.push_line("10 * \\"), None);
+        assert_eq!(input.push_line("2"), Some("10 * 2".to_string()));
+
+        // More close symbols are left to the evaluator
+        assert_eq!(input.push_line("2)"), Some("2)".to_string()));
+
+        // Incomplete expressions are resumed with the next line
+        let error = session.eval("8 +").unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::IncompleteExpression);
+
+        input.resume("8 +");
+        let expression = input.push_line("2").unwrap();
+        assert_eq!(session.eval(&expression), Ok("10".to_string()));
+    }
+}
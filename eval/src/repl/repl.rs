@@ -47,17 +47,15 @@ impl Repl {
                 continue;
             }
 
-            match event::read().unwrap() {
-                Event::Key(event) => match event.code {
-                    KeyCode::Backspace => {
-                        if buf.len() > 0 {
-                            buf.pop();
-                            self.writer.write("\x08 \x08");
-                        }
+            if let Event::Key(event) = event::read().unwrap() {
+                match event.code {
+                    KeyCode::Backspace if !buf.is_empty() => {
+                        buf.pop();
+                        self.writer.write("\x08 \x08");
                     }
                     KeyCode::Delete => {}
                     KeyCode::Enter => {
-                        let s = buf.drain(..).collect::<String>();
+                        let s = std::mem::take(&mut buf);
                         self.writer.writeln("");
                         self.writer.flush();
 
@@ -71,32 +69,28 @@ impl Repl {
                             None => {}
                         }
 
-                        if s.trim().len() > 0 {
+                        if !s.trim().is_empty() {
                             if history.last() != Some(&s) {
                                 history.push(s);
                             }
                             history_cursor = history.len();
                         }
                     }
-                    KeyCode::Up => {
-                        if history_cursor > 0 {
-                            history_cursor -= 1;
+                    KeyCode::Up if history_cursor > 0 => {
+                        history_cursor -= 1;
+                        buf.clear();
+                        buf.push_str(&history[history_cursor]);
+                        self.writer.rewrite(&buf);
+                    }
+                    KeyCode::Down if history_cursor < history.len() => {
+                        history_cursor += 1;
+                        if history_cursor == history.len() {
+                            buf.clear();
+                        } else {
                             buf.clear();
                             buf.push_str(&history[history_cursor]);
-                            self.writer.rewrite(&buf);
-                        }
-                    }
-                    KeyCode::Down => {
-                        if history_cursor < history.len() {
-                            history_cursor += 1;
-                            if history_cursor == history.len() {
-                                buf.clear();
-                            } else {
-                                buf.clear();
-                                buf.push_str(&history[history_cursor]);
-                            }
-                            self.writer.rewrite(&buf);
                         }
+                        self.writer.rewrite(&buf);
                     }
                     KeyCode::Left => {}
                     KeyCode::Right => {}
@@ -108,8 +102,7 @@ impl Repl {
                         break;
                     }
                     _ => {}
-                },
-                _ => {}
+                }
             }
 
             // Flush the output
@@ -75,8 +75,8 @@ impl ReplWriter {
     }
 
     pub fn rewrite<D: Display>(&mut self, data: D) {
-        let fg = self.writer.fg.clone();
-        let bg = self.writer.bg.clone();
+        let fg = self.writer.fg;
+        let bg = self.writer.bg;
 
         execute!(
             std::io::stdout(),
@@ -90,8 +90,8 @@ impl ReplWriter {
     }
 
     pub fn rewrite_err<D: Display>(&mut self, data: D) {
-        let fg = self.writer.fg.clone();
-        let bg = self.writer.bg.clone();
+        let fg = self.writer.fg;
+        let bg = self.writer.bg;
 
         execute!(
             std::io::stderr(),
@@ -1,4 +1,4 @@
-use crate::models::{EvaluatedExpression, NumberType};
+use crate::models::{EvalExpression, EvaluatedExpression, NumberType, TokenizedExpression};
 use crate::{ApiResponse, services};
 use actix_web::web::Query;
 use actix_web::{get, post, web, HttpRequest, HttpResponse, Responder, Result};
@@ -46,14 +46,54 @@ pub async fn eval(req: HttpRequest, body: web::Bytes) -> ApiResponse {
     let query = Query::<EvalParams>::from_query(req.query_string()).ok();
     let only_result = query.and_then(|q| q.only_result).unwrap_or(false);
 
-    if !only_result || eval_result.is_err() {
-        Ok(HttpResponse::Ok().json(EvaluatedExpression::from(eval_result)))
-    } else {
-        let text = eval_result.unwrap();
-        Ok(HttpResponse::Ok().body(text))
+    match eval_result {
+        Ok(text) if only_result => Ok(HttpResponse::Ok().body(text)),
+        _ => Ok(HttpResponse::Ok().json(EvaluatedExpression::from(eval_result))),
     }
 }
 
+#[post("/tokenize")]
+pub async fn tokenize(body: web::Bytes) -> ApiResponse {
+    match parse_expression(body) {
+        Ok(expression) => {
+            let result = services::tokenize_expression(expression);
+            Ok(HttpResponse::Ok().json(TokenizedExpression::from(result)))
+        }
+        Err(response) => Ok(response),
+    }
+}
+
+#[post("/rpn")]
+pub async fn rpn(body: web::Bytes) -> ApiResponse {
+    match parse_expression(body) {
+        Ok(expression) => {
+            let result = services::rpn_expression(expression);
+            Ok(HttpResponse::Ok().json(TokenizedExpression::from(result)))
+        }
+        Err(response) => Ok(response),
+    }
+}
+
+fn parse_expression(body: web::Bytes) -> Result<EvalExpression, HttpResponse> {
+    let body = match String::from_utf8(body.to_vec()) {
+        Ok(s) => s,
+        Err(_) => {
+            return Err(HttpResponse::BadRequest()
+                .json(TokenizedExpression::with_error("Invalid JSON".to_string())));
+        }
+    };
+
+    let deserializer = &mut serde_json::Deserializer::from_str(&body);
+    serde_path_to_error::deserialize(deserializer).map_err(|err| {
+        let path = err.path().to_string();
+        let error_message = match path.as_str() {
+            "." => "Invalid json".to_string(),
+            _ => format!("Invalid value type at path: {}", path),
+        };
+        HttpResponse::BadRequest().json(TokenizedExpression::with_error(error_message))
+    })
+}
+
 #[get("/operators")]
 pub async fn get_operators(req: HttpRequest) -> Result<impl Responder> {
     let query = Query::<GetFunctions>::from_query(req.query_string()).ok();
@@ -154,4 +194,102 @@ pub async fn get_functions(req: HttpRequest) -> Result<impl Responder> {
             Ok(HttpResponse::Ok().json(functions))
         }
     }
-}
\ No newline at end of file
+}
+
+#[get("/meta")]
+pub async fn get_meta() -> Result<impl Responder> {
+    Ok(HttpResponse::Ok().json(services::get_meta()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Meta, TokenModel};
+    use actix_web::{test, App};
+
+    #[actix_rt::test]
+    async fn get_meta_test() {
+        let mut app = test::init_service(App::new().service(get_meta)).await;
+        let req = test::TestRequest::get().uri("/meta").to_request();
+        let meta: Meta = test::read_response_json(&mut app, req).await;
+
+        for t in NumberType::ALL.iter() {
+            assert!(meta.types.contains(t), "missing number type: {:?}", t);
+        }
+
+        // Fails to compile when a variant is added without updating `NumberType::ALL`
+        match NumberType::Decimal {
+            NumberType::Decimal
+            | NumberType::Float
+            | NumberType::Complex
+            | NumberType::Integer
+            | NumberType::Binary => {}
+        }
+
+        assert_eq!(meta.types.len(), NumberType::ALL.len());
+        assert!(meta.limits.max_expression_length > 0);
+        assert!(meta.limits.max_requests > 0);
+    }
+
+    #[actix_rt::test]
+    async fn tokenize_test() {
+        let mut app = test::init_service(App::new().service(tokenize)).await;
+        let req = test::TestRequest::post()
+            .uri("/tokenize")
+            .set_payload(r#"{"expression": "2 + x"}"#)
+            .to_request();
+        let result: TokenizedExpression = test::read_response_json(&mut app, req).await;
+
+        assert_eq!(
+            result.tokens,
+            Some(vec![
+                TokenModel::Number("2".to_string()),
+                TokenModel::BinaryOperator("+".to_string()),
+                TokenModel::Unknown("x".to_string()),
+            ])
+        );
+        assert_eq!(result.error, None);
+
+        let req = test::TestRequest::post()
+            .uri("/tokenize")
+            .set_payload(r#"{"expression": "2 * 3 +"}"#)
+            .to_request();
+        let result: TokenizedExpression = test::read_response_json(&mut app, req).await;
+        let error = result.error.unwrap();
+
+        assert_eq!(result.tokens, None);
+        assert_eq!(error.position, Some(6));
+        assert!(!error.message.is_empty());
+    }
+
+    #[actix_rt::test]
+    async fn rpn_test() {
+        let mut app = test::init_service(App::new().service(rpn)).await;
+        let req = test::TestRequest::post()
+            .uri("/rpn")
+            .set_payload(r#"{"expression": "2 * (3 + 4)", "type": "integer"}"#)
+            .to_request();
+        let result: TokenizedExpression = test::read_response_json(&mut app, req).await;
+
+        assert_eq!(
+            result.tokens,
+            Some(vec![
+                TokenModel::Number("2".to_string()),
+                TokenModel::Number("3".to_string()),
+                TokenModel::Number("4".to_string()),
+                TokenModel::BinaryOperator("+".to_string()),
+                TokenModel::BinaryOperator("*".to_string()),
+            ])
+        );
+
+        let req = test::TestRequest::post()
+            .uri("/rpn")
+            .set_payload(r#"{"expression": 2}"#)
+            .to_request();
+        let result: TokenizedExpression = test::read_response_json(&mut app, req).await;
+        let error = result.error.unwrap();
+
+        assert_eq!(error.message, "Invalid value type at path: expression");
+        assert_eq!(error.position, None);
+    }
+}
@@ -4,7 +4,8 @@ mod models;
 mod services;
 
 use crate::middlewares::rate_limiter::{
-    generate_rate_limit_id, get_rate_limit_identifier, RATE_LIMIT_ID,
+    generate_rate_limit_id, get_rate_limit_identifier, MAX_REQUESTS, RATE_LIMIT_ID,
+    TIME_BETWEEN_REQUESTS,
 };
 use actix_ratelimit::errors::ARError;
 use actix_ratelimit::{MemoryStore, MemoryStoreActor, RateLimiter};
@@ -16,7 +17,6 @@ use actix_web::middleware::normalize::TrailingSlash;
 use actix_web::middleware::{Logger, NormalizePath};
 use actix_web::{App, HttpResponse, HttpServer};
 use std::env;
-use std::time::Duration;
 
 pub type ApiResponse = Result<HttpResponse, HttpResponse>;
 
@@ -77,9 +77,12 @@ async fn main() -> std::io::Result<()> {
             .service(
                 actix_web::web::scope("")
                     .service(endpoints::eval)
+                    .service(endpoints::tokenize)
+                    .service(endpoints::rpn)
                     .service(endpoints::get_operators)
                     .service(endpoints::get_functions)
-                    .service(endpoints::get_constants),
+                    .service(endpoints::get_constants)
+                    .service(endpoints::get_meta),
             )
     })
     .bind(format!("0.0.0.0:{}", port))?
@@ -88,9 +91,6 @@ async fn main() -> std::io::Result<()> {
 }
 
 fn get_rate_limit_middleware() -> RateLimiter<MemoryStoreActor> {
-    const MAX_REQUESTS: usize = 100;
-    const TIME_BETWEEN_REQUESTS: Duration = Duration::from_secs(60);
-
     let store = MemoryStore::new();
 
     RateLimiter::new(MemoryStoreActor::from(store).start())
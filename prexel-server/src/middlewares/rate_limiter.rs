@@ -1,9 +1,16 @@
 use actix_web::dev::ServiceRequest;
 use actix_web::HttpMessage;
+use std::time::Duration;
 use uuid::Uuid;
 
 pub const RATE_LIMIT_ID : &str = "X-Ratelimit-Id";
 
+/// Max number of requests allowed per client in each interval.
+pub const MAX_REQUESTS: usize = 100;
+
+/// Interval after which the requests count of a client is reset.
+pub const TIME_BETWEEN_REQUESTS: Duration = Duration::from_secs(60);
+
 pub fn get_rate_limit_identifier(req: &ServiceRequest) -> Option<String> {
     fn internal_get_identifier(req: &ServiceRequest) -> Option<String> {
         if let Some(remote_addr) = req.connection_info().remote_addr() {
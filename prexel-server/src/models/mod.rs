@@ -1,4 +1,5 @@
 use std::{collections::HashMap, fmt::Display};
+use prexel::token::Token;
 use serde::{Deserialize, Serialize};
 
 /// Represents the result of an evaluation.
@@ -40,12 +41,46 @@ pub enum NumberType {
     Binary,
 }
 
+impl NumberType {
+    /// All the number types supported by the server.
+    pub const ALL: [NumberType; 5] = [
+        NumberType::Decimal,
+        NumberType::Float,
+        NumberType::Complex,
+        NumberType::Integer,
+        NumberType::Binary,
+    ];
+}
+
 /// Represents an expression to be evaluated.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct EvalExpression {
     pub expression: String,
     pub r#type: Option<NumberType>,
     pub variables: Option<HashMap<String, Variable>>,
+    pub config: Option<EvalConfig>,
+}
+
+/// Represents a grouping symbol pair allowed in an expression.
+#[derive(Debug, PartialEq, Clone, Copy, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GroupingType {
+    /// Parenthesis: `(` and `)`.
+    Parenthesis,
+
+    /// Bracket: `[` and `]`.
+    Bracket,
+
+    /// Brace: `{` and `}`.
+    Brace,
+}
+
+/// Represents the evaluation options provided by the caller.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct EvalConfig {
+    pub implicit_mul: Option<bool>,
+    pub grouping: Option<Vec<GroupingType>>,
 }
 
 /// Represents the result to evaluate an expression.
@@ -77,4 +112,114 @@ impl From<EvalResult> for EvaluatedExpression {
             },
         }
     }
-}
\ No newline at end of file
+}
+
+/// Represents a serialized token of an expression.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", content = "value")]
+pub enum TokenModel {
+    Number(String),
+    NumberWithUnit(String, String),
+    Str(String),
+    Variable(String),
+    Constant(String),
+    Function(String),
+    BinaryOperator(String),
+    UnaryOperator(String),
+    ArgCount(usize),
+    GroupingOpen(char),
+    GroupingClose(char),
+    Unknown(String),
+    Comma,
+}
+
+impl<N: Display> From<Token<N>> for TokenModel {
+    fn from(token: Token<N>) -> Self {
+        match token {
+            Token::Number(n) => TokenModel::Number(n.to_string()),
+            Token::NumberWithUnit(n, unit) => TokenModel::NumberWithUnit(n.to_string(), unit),
+            Token::Str(s) => TokenModel::Str(s),
+            Token::Variable(name) => TokenModel::Variable(name),
+            Token::Constant(name) => TokenModel::Constant(name),
+            Token::Function(name) => TokenModel::Function(name),
+            Token::BinaryOperator(name) => TokenModel::BinaryOperator(name),
+            Token::UnaryOperator(name) => TokenModel::UnaryOperator(name),
+            Token::ArgCount(n) => TokenModel::ArgCount(n),
+            Token::GroupingOpen(c) => TokenModel::GroupingOpen(c),
+            Token::GroupingClose(c) => TokenModel::GroupingClose(c),
+            Token::Unknown(name) => TokenModel::Unknown(name),
+            Token::Comma => TokenModel::Comma,
+        }
+    }
+}
+
+/// Represents an error of an expression, with the position in the expression that caused it, if known.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ErrorModel {
+    pub message: String,
+    pub position: Option<usize>,
+}
+
+impl From<String> for ErrorModel {
+    fn from(message: String) -> Self {
+        ErrorModel {
+            message,
+            position: None,
+        }
+    }
+}
+
+impl From<prexel::error::Error> for ErrorModel {
+    fn from(error: prexel::error::Error) -> Self {
+        ErrorModel {
+            message: error.to_string(),
+            position: error.position(),
+        }
+    }
+}
+
+/// Represents the result to tokenize an expression.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TokenizedExpression {
+    pub tokens: Option<Vec<TokenModel>>,
+    pub error: Option<ErrorModel>,
+}
+
+impl TokenizedExpression {
+    pub fn with_error(error: String) -> Self {
+        TokenizedExpression {
+            tokens: None,
+            error: Some(ErrorModel::from(error)),
+        }
+    }
+}
+
+impl From<Result<Vec<TokenModel>, ErrorModel>> for TokenizedExpression {
+    fn from(result: Result<Vec<TokenModel>, ErrorModel>) -> Self {
+        match result {
+            Ok(tokens) => TokenizedExpression {
+                tokens: Some(tokens),
+                error: None,
+            },
+            Err(error) => TokenizedExpression {
+                tokens: None,
+                error: Some(error),
+            },
+        }
+    }
+}
+
+/// Represents the capabilities and limits of the server.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Meta {
+    pub types: Vec<NumberType>,
+    pub limits: Limits,
+}
+
+/// Represents the limits applied to the requests.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Limits {
+    pub max_expression_length: usize,
+    pub max_requests: usize,
+    pub rate_limit_interval_secs: u64,
+}
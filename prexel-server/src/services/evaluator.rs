@@ -1,4 +1,4 @@
-use crate::models::{EvalExpression, EvalResult, NumberType};
+use crate::models::{EvalConfig, EvalExpression, EvalResult, GroupingType, NumberType};
 use once_cell::sync::Lazy;
 use prexel::complex;
 use prexel::context::{Config, Grouping};
@@ -9,26 +9,66 @@ use std::string::ToString;
 use prexel::binary::{Binary, binary_number_splitter};
 
 static CONFIG: Lazy<Config> = Lazy::new(|| {
-    Config::default()
-        .with_grouping(Grouping::Parenthesis)
+    Config::public_safe()
         .with_grouping(Grouping::Bracket)
         .with_implicit_mul(true)
 });
 
 pub fn eval_expression(expression: EvalExpression) -> EvalResult {
     let r#type = expression.r#type.unwrap_or(NumberType::Decimal);
+    let config = build_config(expression.config.as_ref())?;
 
     match r#type {
-        NumberType::Decimal => eval_decimal_expression(expression),
-        NumberType::Float => eval_float_expression(expression),
-        NumberType::Integer => eval_integer_expression(expression),
-        NumberType::Complex => eval_complex_expression(expression),
-        NumberType::Binary => eval_binary_expression(expression),
+        NumberType::Decimal => eval_decimal_expression(expression, config),
+        NumberType::Float => eval_float_expression(expression, config),
+        NumberType::Integer => eval_integer_expression(expression, config),
+        NumberType::Complex => eval_complex_expression(expression, config),
+        NumberType::Binary => eval_binary_expression(expression, config),
     }
 }
 
-fn eval_decimal_expression(expression: EvalExpression) -> EvalResult {
-    let mut context = DefaultContext::with_config_decimal(CONFIG.clone());
+/// Gets the max number of characters allowed in an expression.
+pub fn max_expression_length() -> usize {
+    CONFIG.max_expression_length.unwrap_or(usize::MAX)
+}
+
+/// Builds the `Config` to evaluate with, starting from the server defaults.
+///
+/// The grouping symbols must include parenthesis, which are required for function calls.
+pub(crate) fn build_config(eval_config: Option<&EvalConfig>) -> Result<Config, String> {
+    let eval_config = match eval_config {
+        Some(eval_config) => eval_config,
+        None => return Ok(CONFIG.clone()),
+    };
+
+    let mut config = match &eval_config.grouping {
+        Some(grouping) => {
+            if !grouping.contains(&GroupingType::Parenthesis) {
+                return Err("Invalid config: `grouping` must include `parenthesis`".to_string());
+            }
+
+            let mut config = Config::public_safe().with_implicit_mul(CONFIG.implicit_mul);
+            for grouping_type in grouping {
+                config = config.with_grouping(match grouping_type {
+                    GroupingType::Parenthesis => Grouping::Parenthesis,
+                    GroupingType::Bracket => Grouping::Bracket,
+                    GroupingType::Brace => Grouping::Brace,
+                });
+            }
+            config
+        }
+        None => CONFIG.clone(),
+    };
+
+    if let Some(implicit_mul) = eval_config.implicit_mul {
+        config = config.with_implicit_mul(implicit_mul);
+    }
+
+    Ok(config)
+}
+
+fn eval_decimal_expression(expression: EvalExpression, config: Config) -> EvalResult {
+    let mut context = DefaultContext::with_config_decimal(config);
 
     // Set variables
     if let Some(variables) = &expression.variables {
@@ -54,8 +94,8 @@ fn eval_decimal_expression(expression: EvalExpression) -> EvalResult {
     }
 }
 
-fn eval_float_expression(expression: EvalExpression) -> EvalResult {
-    let mut context = DefaultContext::with_config_checked(CONFIG.clone());
+fn eval_float_expression(expression: EvalExpression, config: Config) -> EvalResult {
+    let mut context = DefaultContext::with_config_checked(config);
 
     // Set variables
     if let Some(variables) = &expression.variables {
@@ -81,8 +121,8 @@ fn eval_float_expression(expression: EvalExpression) -> EvalResult {
     }
 }
 
-fn eval_complex_expression(expression: EvalExpression) -> EvalResult {
-    let mut context = DefaultContext::with_config_complex(CONFIG.clone().with_complex_number(true));
+fn eval_complex_expression(expression: EvalExpression, config: Config) -> EvalResult {
+    let mut context = DefaultContext::with_config_complex(config.with_complex_number(true));
 
     // Set variables
     if let Some(variables) = &expression.variables {
@@ -108,8 +148,8 @@ fn eval_complex_expression(expression: EvalExpression) -> EvalResult {
     }
 }
 
-fn eval_integer_expression(expression: EvalExpression) -> EvalResult {
-    let mut context = DefaultContext::with_config_checked(CONFIG.clone());
+fn eval_integer_expression(expression: EvalExpression, config: Config) -> EvalResult {
+    let mut context = DefaultContext::with_config_checked(config);
 
     // Set variables
     if let Some(variables) = &expression.variables {
@@ -135,8 +175,8 @@ fn eval_integer_expression(expression: EvalExpression) -> EvalResult {
     }
 }
 
-fn eval_binary_expression(expression: EvalExpression) -> EvalResult {
-    let mut context = DefaultContext::with_config_binary(CONFIG.clone());
+fn eval_binary_expression(expression: EvalExpression, config: Config) -> EvalResult {
+    let mut context = DefaultContext::with_config_binary(config);
 
     // Set variables
     if let Some(variables) = &expression.variables {
@@ -162,3 +202,68 @@ fn eval_binary_expression(expression: EvalExpression) -> EvalResult {
         Err(error) => EvalResult::Err(error.to_string()),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expression(expression: &str, config: Option<EvalConfig>) -> EvalExpression {
+        EvalExpression {
+            expression: expression.to_string(),
+            r#type: Some(NumberType::Integer),
+            variables: None,
+            config,
+        }
+    }
+
+    #[test]
+    fn eval_with_config_test() {
+        assert_eq!(eval_expression(expression("2(3)", None)), Ok("6".to_string()));
+
+        let config = EvalConfig {
+            implicit_mul: Some(false),
+            grouping: None,
+        };
+        assert!(eval_expression(expression("2(3)", Some(config))).is_err());
+
+        let config = EvalConfig {
+            implicit_mul: None,
+            grouping: Some(vec![GroupingType::Bracket]),
+        };
+        assert!(eval_expression(expression("2 + 3", Some(config))).is_err());
+    }
+
+    #[test]
+    fn deserialize_config_test() {
+        let json = r#"{"expression": "2(3)", "config": {"implicit_mul": false}}"#;
+        let expr: EvalExpression = serde_json::from_str(json).unwrap();
+        assert!(eval_expression(expr).is_err());
+
+        let json = r#"{"expression": "2", "config": {"max_steps": 10}}"#;
+        assert!(serde_json::from_str::<EvalExpression>(json).is_err());
+    }
+
+    #[test]
+    fn max_expression_length_test() {
+        let len = max_expression_length();
+        let expr = vec!["1"; len / 2 + 1].join("+");
+        assert!(eval_expression(expression(&expr, None)).is_err());
+
+        let config = EvalConfig {
+            implicit_mul: None,
+            grouping: Some(vec![GroupingType::Parenthesis]),
+        };
+        assert!(eval_expression(expression(&expr, Some(config))).is_err());
+
+        let expr = vec!["1"; len / 2].join("+");
+        assert_eq!(eval_expression(expression(&expr, None)), Ok((len / 2).to_string()));
+    }
+
+    #[test]
+    fn public_safe_config_test() {
+        assert!(eval_expression(expression("random()", None)).is_err());
+
+        let expr = format!("sum({})", vec!["1"; 200].join(","));
+        assert!(eval_expression(expression(&expr, None)).is_err());
+    }
+}
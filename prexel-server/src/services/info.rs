@@ -1,4 +1,7 @@
 use std::fmt::Display;
+use crate::middlewares::rate_limiter::{MAX_REQUESTS, TIME_BETWEEN_REQUESTS};
+use crate::models::{Limits, Meta, NumberType};
+use crate::services::max_expression_length;
 use prexel::context::DefaultContext;
 use serde::{Deserialize, Serialize};
 
@@ -7,6 +10,20 @@ pub struct FnInfo {
     name: String,
     aliases: Vec<String>,
     description: Option<String>,
+    category: Option<String>,
+}
+
+impl FnInfo {
+    fn new(name: &str, aliases: Option<&[&str]>, description: Option<&str>, category: Option<&str>) -> Self {
+        FnInfo {
+            name: name.to_string(),
+            aliases: aliases
+                .map(|v| v.iter().map(|s| s.to_string()).collect())
+                .unwrap_or_default(),
+            description: description.map(|s| s.to_string()),
+            category: category.map(|s| s.to_string()),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -16,52 +33,41 @@ pub struct VarInfo {
 }
 
 pub fn get_operators<N>(context: &DefaultContext<'_, N>) -> Vec<FnInfo> {
-    context
-        .binary_functions()
-        .iter()
-        .map(|(name, f)| FnInfo {
-            name: name.to_string(),
-            aliases: f
-                .aliases()
-                .map(|v| v.iter().map(|s| s.to_string()).collect())
-                .unwrap_or_default(),
-            description: f.description().map(|s| s.to_string()),
-        })
-        .chain(context.unary_functions().iter().map(|(name, f)| {
-            FnInfo {
-                name: name.to_string(),
-                aliases: f
-                    .aliases()
-                    .map(|v| v.iter().map(|s| s.to_string()).collect())
-                    .unwrap_or_default(),
-                description: f.description().map(|s| s.to_string()),
-            }
-        }))
-        .collect()
+    let binary = context
+        .iter_binary_functions()
+        .map(|(name, f)| FnInfo::new(name, f.aliases(), f.description(), f.category()));
+    let unary = context
+        .iter_unary_functions()
+        .map(|(name, f)| FnInfo::new(name, f.aliases(), f.description(), f.category()));
+
+    binary.chain(unary).collect()
 }
 
 pub fn get_functions<N: Display>(context: &DefaultContext<'_, N>) -> Vec<FnInfo> {
     context
-        .functions()
-        .iter()
-        .map(|(name, f)| FnInfo {
-            name: name.to_string(),
-            aliases: f
-                .aliases()
-                .map(|v| v.iter().map(|s| s.to_string()).collect())
-                .unwrap_or_default(),
-            description: f.description().map(|s| s.to_string()),
-        })
+        .iter_functions()
+        .map(|(name, f)| FnInfo::new(name, f.aliases(), f.description(), f.category()))
         .collect()
 }
 
 pub fn get_constants<N: Display>(context: &DefaultContext<'_, N>) -> Vec<VarInfo> {
     context
-        .constants()
-        .iter()
+        .iter_constants()
         .map(|(name, v)| VarInfo {
             name: name.to_string(),
             value: v.to_string(),
         })
         .collect()
 }
+
+/// Gets the supported number types and the limits of the server.
+pub fn get_meta() -> Meta {
+    Meta {
+        types: NumberType::ALL.to_vec(),
+        limits: Limits {
+            max_expression_length: max_expression_length(),
+            max_requests: MAX_REQUESTS,
+            rate_limit_interval_secs: TIME_BETWEEN_REQUESTS.as_secs(),
+        },
+    }
+}
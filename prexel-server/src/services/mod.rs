@@ -1,5 +1,7 @@
 mod evaluator;
 mod info;
+mod tokenizer;
 
 pub use evaluator::*;
 pub use info::*;
+pub use tokenizer::*;
@@ -0,0 +1,126 @@
+use crate::models::{ErrorModel, EvalExpression, NumberType, TokenModel};
+use crate::services::build_config;
+use prexel::binary::{binary_number_splitter, Binary};
+use prexel::complex::Complex;
+use prexel::context::DefaultContext;
+use prexel::decimal::Decimal;
+use prexel::evaluator::infix_to_rpn;
+use prexel::token::Token;
+use prexel::tokenizer::Tokenizer;
+use std::fmt::{Debug, Display};
+
+/// Tokenizes the expression using the context of its number type, without evaluating it.
+pub fn tokenize_expression(expression: EvalExpression) -> Result<Vec<TokenModel>, ErrorModel> {
+    tokenize(expression, false)
+}
+
+/// Converts the expression to reverse polish notation using the context of its number type.
+pub fn rpn_expression(expression: EvalExpression) -> Result<Vec<TokenModel>, ErrorModel> {
+    tokenize(expression, true)
+}
+
+fn tokenize(expression: EvalExpression, to_rpn: bool) -> Result<Vec<TokenModel>, ErrorModel> {
+    let r#type = expression.r#type.unwrap_or(NumberType::Decimal);
+    let config = build_config(expression.config.as_ref())?;
+    let expr = expression.expression.as_str();
+
+    match r#type {
+        NumberType::Decimal => {
+            let context = DefaultContext::<Decimal>::with_config_decimal(config);
+            into_models(Tokenizer::new().tokenize(&context, expr), &context, to_rpn)
+        }
+        NumberType::Float => {
+            let context = DefaultContext::<f64>::with_config_checked(config);
+            into_models(Tokenizer::new().tokenize(&context, expr), &context, to_rpn)
+        }
+        NumberType::Complex => {
+            let context =
+                DefaultContext::<Complex<f64>>::with_config_complex(config.with_complex_number(true));
+            into_models(Tokenizer::new().tokenize(&context, expr), &context, to_rpn)
+        }
+        NumberType::Integer => {
+            let context = DefaultContext::<i128>::with_config_checked(config);
+            into_models(Tokenizer::new().tokenize(&context, expr), &context, to_rpn)
+        }
+        NumberType::Binary => {
+            let context = DefaultContext::<Binary>::with_config_binary(config);
+            let tokenizer = Tokenizer::with_splitter(binary_number_splitter());
+            into_models(tokenizer.tokenize(&context, expr), &context, to_rpn)
+        }
+    }
+}
+
+fn into_models<'a, N>(
+    tokens: prexel::Result<Vec<Token<N>>>,
+    context: &DefaultContext<'a, N>,
+    to_rpn: bool,
+) -> Result<Vec<TokenModel>, ErrorModel>
+where
+    N: Clone + Debug + Display,
+{
+    let mut tokens = tokens?;
+
+    if to_rpn {
+        tokens = infix_to_rpn(&tokens, context)?;
+    }
+
+    Ok(tokens.into_iter().map(TokenModel::from).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokenize_expression_test() {
+        let json = r#"{"expression": "2+sin(x)"}"#;
+        let expression: EvalExpression = serde_json::from_str(json).unwrap();
+        let tokens = tokenize_expression(expression).unwrap();
+
+        assert_eq!(
+            tokens,
+            vec![
+                TokenModel::Number("2".to_string()),
+                TokenModel::BinaryOperator("+".to_string()),
+                TokenModel::Function("sin".to_string()),
+                TokenModel::GroupingOpen('('),
+                TokenModel::Unknown("x".to_string()),
+                TokenModel::GroupingClose(')'),
+            ]
+        );
+
+        assert_eq!(
+            serde_json::to_string(&tokens[..2]).unwrap(),
+            r#"[{"type":"Number","value":"2"},{"type":"BinaryOperator","value":"+"}]"#
+        );
+    }
+
+    #[test]
+    fn tokenize_expression_error_test() {
+        let json = r#"{"expression": ""}"#;
+        let expression: EvalExpression = serde_json::from_str(json).unwrap();
+        assert!(tokenize_expression(expression).is_err());
+
+        let json = r#"{"expression": "2 * 3 +"}"#;
+        let expression: EvalExpression = serde_json::from_str(json).unwrap();
+        let error = tokenize_expression(expression).unwrap_err();
+        assert_eq!(error.position, Some(6));
+    }
+
+    #[test]
+    fn rpn_expression_test() {
+        let json = r#"{"expression": "2 + 3 * 4", "type": "integer"}"#;
+        let expression: EvalExpression = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            rpn_expression(expression).unwrap(),
+            vec![
+                TokenModel::Number("2".to_string()),
+                TokenModel::Number("3".to_string()),
+                TokenModel::Number("4".to_string()),
+                TokenModel::BinaryOperator("*".to_string()),
+                TokenModel::BinaryOperator("+".to_string()),
+            ]
+        );
+    }
+}
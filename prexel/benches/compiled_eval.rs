@@ -0,0 +1,43 @@
+#[macro_use]
+extern crate bencher;
+
+use bencher::{black_box, Bencher};
+use prexel::context::{Context, DefaultContext};
+use prexel::evaluator::Evaluator;
+
+const SAMPLES: u64 = 10;
+const ITERATIONS: u32 = 100_000;
+const EXPRESSION: &str = "x^2 + 3 * x - sin(x) / 2";
+
+fn eval_bench(b: &mut Bencher) {
+    let mut evaluator: Evaluator<f64> = Evaluator::new();
+    evaluator.mut_context().set_variable("x", 0_f64).unwrap();
+
+    b.bench_n(SAMPLES, |bn| {
+        bn.iter(|| {
+            for _ in 0..ITERATIONS {
+                black_box(evaluator.eval(EXPRESSION)).unwrap();
+            }
+        })
+    });
+}
+
+fn compiled_eval_bench(b: &mut Bencher) {
+    let mut context: DefaultContext<f64> = DefaultContext::new_checked();
+    context.set_variable("x", 0_f64).unwrap();
+
+    let evaluator = Evaluator::with_context(context.clone());
+    let expr = evaluator.compile(EXPRESSION).unwrap();
+
+    b.bench_n(SAMPLES, |bn| {
+        bn.iter(|| {
+            for i in 0..ITERATIONS {
+                context.set_variable("x", i as f64).unwrap();
+                black_box(expr.eval(&context)).unwrap();
+            }
+        })
+    });
+}
+
+benchmark_group!(benches, eval_bench, compiled_eval_bench);
+benchmark_main!(benches);
@@ -0,0 +1,48 @@
+#[macro_use]
+extern crate bencher;
+
+use bencher::{black_box, Bencher};
+use prexel::context::{Context, DefaultContext};
+use prexel::evaluator::Evaluator;
+
+const SAMPLES: u64 = 1000;
+
+fn single_char_operator_lookup_bench(b: &mut Bencher) {
+    let context: DefaultContext<f64> = DefaultContext::new_unchecked();
+
+    b.bench_n(SAMPLES, |bn| {
+        bn.iter(|| {
+            for name in ["+", "-", "*", "/", "^"].iter() {
+                black_box(context.get_binary_function(name));
+            }
+        })
+    });
+}
+
+fn multi_char_operator_lookup_bench(b: &mut Bencher) {
+    let context: DefaultContext<f64> = DefaultContext::new_unchecked();
+
+    b.bench_n(SAMPLES, |bn| {
+        bn.iter(|| {
+            for _ in 0..5 {
+                black_box(context.get_binary_function("mod"));
+            }
+        })
+    });
+}
+
+fn eval_operators_bench(b: &mut Bencher) {
+    let evaluator: Evaluator<f64> = Evaluator::new();
+
+    b.bench_n(SAMPLES, |bn| {
+        bn.iter(|| black_box(evaluator.eval("2 + 3 * 4 - 8 / 2 ^ 2 + 10 mod 3")))
+    });
+}
+
+benchmark_group!(
+    benches,
+    single_char_operator_lookup_bench,
+    multi_char_operator_lookup_bench,
+    eval_operators_bench
+);
+benchmark_main!(benches);
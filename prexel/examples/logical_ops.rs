@@ -38,8 +38,8 @@ fn main() {
         let expression1 = "(true or false) and (true xor false)";
         let expression2 = "(false and true)";
 
-        assert_eq!(evaluator.eval(expression1).unwrap(), true);
-        assert_eq!(evaluator.eval(expression2).unwrap(), false);
+        assert!(evaluator.eval(expression1).unwrap());
+        assert!(!evaluator.eval(expression2).unwrap());
 
         // Prints the result
         println!("{} = {}", expression1, evaluator.eval(expression1).unwrap());
@@ -11,6 +11,13 @@ use num_traits::Zero;
 #[derive(Clone, Debug, Copy, PartialEq, Eq, Ord, PartialOrd)]
 pub struct Binary(pub i128);
 
+impl crate::evaluator::ResultType for Binary {
+    #[inline]
+    fn result_info(&self) -> crate::evaluator::ResultInfo {
+        crate::evaluator::ResultInfo::Real
+    }
+}
+
 impl Zero for Binary {
     fn zero() -> Self {
         Binary(0)
@@ -64,8 +71,8 @@ impl<'a> DefaultContext<'a, Binary> {
         context.add_binary_function(XorFunction).unwrap();
         context.add_binary_function(EqFunction).unwrap();
         context.add_binary_function(NeFunction).unwrap();
-        context.add_binary_function(GtFunction).unwrap();
-        context.add_binary_function(LtFunction).unwrap();
+        context.add_operator_unless_grouping(GtFunction).unwrap();
+        context.add_operator_unless_grouping(LtFunction).unwrap();
         context.add_binary_function(GteFunction).unwrap();
         context.add_binary_function(LteFunction).unwrap();
         context.add_binary_function(ShrFunction).unwrap();
@@ -102,6 +109,7 @@ pub fn binary_number_splitter<'a>() -> DefaultSplitter<'a> {
 
 pub mod math {
     use crate::binary::Binary;
+    use crate::descriptions::Category;
     use crate::function::{Associativity, BinaryFunction, Notation, Precedence, UnaryFunction};
 
     pub struct NotFunction;
@@ -125,6 +133,10 @@ pub mod math {
         fn description(&self) -> Option<&str> {
             Some("Returns the bitwise NOT of the given value.")
         }
+
+        fn category(&self) -> Option<&str> {
+            Some(Category::Bitwise.into())
+        }
     }
 
     pub struct AndFunction;
@@ -152,6 +164,10 @@ pub mod math {
         fn description(&self) -> Option<&str> {
             Some("Returns the bitwise AND of the given values.")
         }
+
+        fn category(&self) -> Option<&str> {
+            Some(Category::Bitwise.into())
+        }
     }
 
     pub struct OrFunction;
@@ -179,6 +195,10 @@ pub mod math {
         fn description(&self) -> Option<&str> {
             Some("Returns the bitwise OR of the given values.")
         }
+
+        fn category(&self) -> Option<&str> {
+            Some(Category::Bitwise.into())
+        }
     }
 
     pub struct XorFunction;
@@ -206,6 +226,10 @@ pub mod math {
         fn description(&self) -> Option<&str> {
             Some("Returns the bitwise XOR of the given values.")
         }
+
+        fn category(&self) -> Option<&str> {
+            Some(Category::Bitwise.into())
+        }
     }
 
     pub struct EqFunction;
@@ -234,6 +258,10 @@ pub mod math {
         fn description(&self) -> Option<&str> {
             Some("Returns 1 if the given values are equal, 0 otherwise.")
         }
+
+        fn category(&self) -> Option<&str> {
+            Some(Category::Comparison.into())
+        }
     }
 
     pub struct NeFunction;
@@ -262,6 +290,10 @@ pub mod math {
         fn description(&self) -> Option<&str> {
             Some("Returns 1 if the given values are not equal, 0 otherwise.")
         }
+
+        fn category(&self) -> Option<&str> {
+            Some(Category::Comparison.into())
+        }
     }
 
     pub struct GtFunction;
@@ -290,6 +322,10 @@ pub mod math {
         fn description(&self) -> Option<&str> {
             Some("Returns 1 if the left value is greater than the right value, 0 otherwise.")
         }
+
+        fn category(&self) -> Option<&str> {
+            Some(Category::Comparison.into())
+        }
     }
 
     pub struct LtFunction;
@@ -318,6 +354,10 @@ pub mod math {
         fn description(&self) -> Option<&str> {
             Some("Returns 1 if the left value is less than the right value, 0 otherwise.")
         }
+
+        fn category(&self) -> Option<&str> {
+            Some(Category::Comparison.into())
+        }
     }
 
     pub struct GteFunction;
@@ -346,6 +386,10 @@ pub mod math {
         fn description(&self) -> Option<&str> {
             Some("Returns 1 if the left value is greater than or equal to the right value, 0 otherwise.")
         }
+
+        fn category(&self) -> Option<&str> {
+            Some(Category::Comparison.into())
+        }
     }
 
     pub struct LteFunction;
@@ -374,6 +418,10 @@ pub mod math {
         fn description(&self) -> Option<&str> {
             Some("Returns 1 if the left value is less than or equal to the right value, 0 otherwise.")
         }
+
+        fn category(&self) -> Option<&str> {
+            Some(Category::Comparison.into())
+        }
     }
 
     pub struct ShrFunction;
@@ -401,6 +449,10 @@ pub mod math {
         fn description(&self) -> Option<&str> {
             Some("Returns the left value bits shifted right by the right value.")
         }
+
+        fn category(&self) -> Option<&str> {
+            Some(Category::Bitwise.into())
+        }
     }
 
     pub struct ShlFunction;
@@ -428,6 +480,10 @@ pub mod math {
         fn description(&self) -> Option<&str> {
             Some("Returns the left value bits shifted left by the right value.")
         }
+
+        fn category(&self) -> Option<&str> {
+            Some(Category::Bitwise.into())
+        }
     }
 }
 
@@ -551,4 +607,14 @@ mod tests {
         assert_eq!(eval("b101 shl 1"), Ok(Binary(10)));
         assert_eq!(eval("b101 shl 0"), Ok(Binary(5)));
     }
+
+    #[test]
+    fn angle_grouping_test() {
+        use crate::context::{Context, Grouping};
+
+        let context = DefaultContext::with_config_binary(Config::new().with_grouping(Grouping::Angle));
+        assert!(!context.is_binary_function(">"));
+        assert!(!context.is_binary_function("<"));
+        assert!(context.is_binary_function(">="));
+    }
 }
@@ -1,5 +1,67 @@
 pub type Complex<T> = num_complex::Complex<T>;
 
+impl<T: num_traits::Zero> crate::evaluator::ResultType for Complex<T> {
+    #[inline]
+    fn result_info(&self) -> crate::evaluator::ResultInfo {
+        if self.im.is_zero() {
+            crate::evaluator::ResultInfo::Real
+        } else {
+            crate::evaluator::ResultInfo::Complex
+        }
+    }
+}
+
+/// The result of a complex evaluation, which is a real number if the imaginary part is near zero.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RealOrComplex<T> {
+    /// A real number, eg: `sqrt(4) = 2`.
+    Real(T),
+    /// A complex number with a non-zero imaginary part, eg: `sqrt(-4) = 2i`.
+    Complex(Complex<T>),
+}
+
+impl<T: std::fmt::Display + Clone + num_traits::Num + PartialOrd> std::fmt::Display for RealOrComplex<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RealOrComplex::Real(n) => write!(f, "{}", n),
+            RealOrComplex::Complex(n) => write!(f, "{}", n),
+        }
+    }
+}
+
+impl<'a, T, C> crate::evaluator::Evaluator<'a, Complex<T>, C>
+where
+    C: crate::context::Context<'a, Complex<T>>,
+    T: num_traits::Float + std::fmt::Debug,
+    Complex<T>: std::str::FromStr,
+{
+    /// Evaluates the given expression and gets the result as a real number if the imaginary
+    /// part is zero or near zero relative to the real part, eg: `sqrt(4)` is `2` instead of `2+0i`.
+    ///
+    /// # Example
+    /// ```
+    /// use prexel::complex::{Complex, RealOrComplex};
+    /// use prexel::context::{Config, DefaultContext};
+    /// use prexel::evaluator::Evaluator;
+    ///
+    /// let config = Config::new().with_complex_number(true);
+    /// let evaluator: Evaluator<Complex<f64>> =
+    ///     Evaluator::with_context(DefaultContext::with_config_complex(config));
+    ///
+    /// assert_eq!(evaluator.eval_real_if_possible("sqrt(4)"), Ok(RealOrComplex::Real(2_f64)));
+    /// ```
+    pub fn eval_real_if_possible(&'a self, expression: &str) -> crate::Result<RealOrComplex<T>> {
+        let result = self.eval(expression)?;
+        let tolerance = T::epsilon() * result.re.abs().max(T::one());
+
+        if result.im.abs() <= tolerance {
+            Ok(RealOrComplex::Real(result.re))
+        } else {
+            Ok(RealOrComplex::Complex(result))
+        }
+    }
+}
+
 pub mod ops {
     use num_complex::Complex;
     use num_traits::{Float, FromPrimitive};
@@ -7,7 +69,7 @@ pub mod ops {
     use std::fmt::{Display, Debug};
 
     use crate::error::*;
-    use crate::function::{Associativity, BinaryFunction, Function, Precedence};
+    use crate::function::{Arity, Associativity, BinaryFunction, Function, Precedence};
     use crate::Result;
 
     #[cfg(feature = "docs")]
@@ -28,6 +90,14 @@ pub mod ops {
         }
 
         fn call(&self, left: Complex<T>, right: Complex<T>) -> Result<Complex<T>> {
+            // Integer exponents are computed by multiplication to avoid the rounding errors
+            // of `powc`, so `i^2` is exactly `-1`.
+            if right.im.is_zero() && right.re.fract().is_zero() {
+                if let Some(exponent) = right.re.to_i64() {
+                    return Ok(powi(left, exponent));
+                }
+            }
+
             Ok(Complex::powc(&left, right))
         }
 
@@ -35,6 +105,33 @@ pub mod ops {
         fn description(&self) -> Option<&str> {
             Some(Description::Pow.into())
         }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Pow.category().into())
+        }
+    }
+
+    /// Raises the value to an integer power using exponentiation by squaring.
+    fn powi<T: Float>(value: Complex<T>, exponent: i64) -> Complex<T> {
+        if exponent < 0 {
+            return powi(value, exponent.saturating_neg()).inv();
+        }
+
+        let mut result = Complex::new(T::one(), T::zero());
+        let mut base = value;
+        let mut exponent = exponent;
+
+        while exponent > 0 {
+            if exponent & 1 != 0 {
+                result = result * base;
+            }
+
+            base = base * base;
+            exponent >>= 1;
+        }
+
+        result
     }
 
     pub struct LogFunction;
@@ -43,6 +140,10 @@ pub mod ops {
             "log"
         }
 
+        fn arity(&self) -> Arity {
+            Arity::Range(1, 2)
+        }
+
         fn call(&self, args: &[Complex<T>]) -> Result<Complex<T>> {
             match args.len() {
                 1 => {
@@ -64,6 +165,11 @@ pub mod ops {
         fn description(&self) -> Option<&str> {
             Some(Description::Log.into())
         }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Log.category().into())
+        }
     }
 
     pub struct RandFunction;
@@ -72,6 +178,10 @@ pub mod ops {
             "random"
         }
 
+        fn arity(&self) -> Arity {
+            Arity::Range(0, 2)
+        }
+
         fn call(&self, args: &[Complex<T>]) -> Result<Complex<T>> {
             #[inline(always)]
             fn try_get_real<N: Float>(c: &Complex<N>) -> Result<N>{
@@ -129,6 +239,11 @@ pub mod ops {
         fn description(&self) -> Option<&str> {
             Some(Description::Rand.into())
         }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Rand.category().into())
+        }
     }
 
     #[cfg(not(feature = "docs"))]
@@ -143,6 +258,10 @@ pub mod ops {
                     stringify!($name)
                 }
 
+                fn arity(&self) -> Arity {
+                    Arity::Exactly(1)
+                }
+
                 fn call(&self, args: &[Complex<T>]) -> Result<Complex<T>> {
                     match args.len() {
                         1 => Ok(args[0].$method_name()),
@@ -165,6 +284,10 @@ pub mod ops {
                     stringify!($name)
                 }
 
+                fn arity(&self) -> Arity {
+                    Arity::Exactly(1)
+                }
+
                 fn call(&self, args: &[Complex<T>]) -> Result<Complex<T>> {
                     match args.len() {
                         1 => Ok(args[0].$method_name().inv()),
@@ -187,6 +310,10 @@ pub mod ops {
                     stringify!($name)
                 }
 
+                fn arity(&self) -> Arity {
+                    Arity::Exactly(1)
+                }
+
                 fn call(&self, args: &[Complex<T>]) -> Result<Complex<T>> {
                     match args.len() {
                         1 => Ok(args[0].$method_name()),
@@ -197,6 +324,10 @@ pub mod ops {
                 fn description(&self) -> Option<&str> {
                     Some($description.into())
                 }
+
+                fn category(&self) -> Option<&str> {
+                    Some($description.category().into())
+                }
             }
         };
     }
@@ -213,6 +344,10 @@ pub mod ops {
                     stringify!($name)
                 }
 
+                fn arity(&self) -> Arity {
+                    Arity::Exactly(1)
+                }
+
                 fn call(&self, args: &[Complex<T>]) -> Result<Complex<T>> {
                     match args.len() {
                         1 => Ok(args[0].$method_name().inv()),
@@ -223,6 +358,10 @@ pub mod ops {
                 fn description(&self) -> Option<&str> {
                     Some($description.into())
                 }
+
+                fn category(&self) -> Option<&str> {
+                    Some($description.category().into())
+                }
             }
         };
     }
@@ -405,10 +544,13 @@ pub mod context {
     use crate::complex::ops::PowOperator;
     use crate::context::{Config, Context, DefaultContext};
     use crate::ops::unchecked::*;
-    use crate::ops::math::UnaryPlus;
+    use crate::function::Precedence;
+    use crate::ops::math::{IfFunction, UnaryPlus, UnsupportedFunction, UnsupportedOperator};
     use super::ops::*;
     use std::fmt::{Debug, Display};
 
+    const COMPLEX: &str = "complex numbers";
+
     impl<'a, T> DefaultContext<'a, Complex<T>> where T: Float + FromPrimitive + Debug + Display {
         #[inline]
         pub fn new_complex() -> Self {
@@ -432,6 +574,15 @@ pub mod context {
             context.add_function(SumFunction).unwrap();
             context.add_function(AvgFunction).unwrap();
             context.add_function(ProdFunction).unwrap();
+            context.add_function(IfFunction).unwrap();
+
+            // The complex numbers are not ordered
+            for name in [">", "<", ">=", "<="] {
+                context.add_binary_function(UnsupportedOperator::new(name, COMPLEX, Precedence::VERY_LOW)).unwrap();
+            }
+            context.add_function(UnsupportedFunction::new("max", COMPLEX)).unwrap();
+            context.add_function(UnsupportedFunction::new("min", COMPLEX)).unwrap();
+
             context.add_function(SqrtFunction).unwrap();
             context.add_function(LnFunction).unwrap();
             context.add_function(LogFunction).unwrap();
@@ -465,3 +616,73 @@ pub mod context {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::complex::{Complex, RealOrComplex};
+    use crate::context::{Config, DefaultContext};
+    use crate::evaluator::{Evaluator, ResultInfo};
+
+    #[test]
+    fn eval_typed_test() {
+        let config = Config::new().with_complex_number(true);
+        let evaluator: Evaluator<Complex<f64>> =
+            Evaluator::with_context(DefaultContext::with_config_complex(config));
+
+        assert_eq!(
+            evaluator.eval_typed("sqrt(4)"),
+            Ok((Complex::new(2_f64, 0_f64), ResultInfo::Real))
+        );
+        assert_eq!(evaluator.eval_typed("sqrt(-1)").unwrap().1, ResultInfo::Complex);
+        assert_eq!(evaluator.eval_typed("i^2").unwrap().1, ResultInfo::Real);
+    }
+
+    #[test]
+    fn unsupported_ordering_test() {
+        use crate::error::ErrorKind;
+
+        let evaluator: Evaluator<Complex<f64>> = Evaluator::with_context(DefaultContext::new_complex());
+
+        let error = evaluator.eval("i > 1").unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::Unsupported);
+        assert_eq!(error.to_string(), "`>` is not supported for complex numbers");
+        assert_eq!(evaluator.eval("max(1, i)").unwrap_err().kind(), ErrorKind::Unsupported);
+        assert_eq!(evaluator.eval("Min(2, 3)").unwrap_err().kind(), ErrorKind::Unsupported);
+    }
+
+    #[test]
+    fn pow_integer_exponent_test() {
+        let config = Config::new().with_complex_number(true);
+        let evaluator: Evaluator<Complex<f64>> =
+            Evaluator::with_context(DefaultContext::with_config_complex(config));
+
+        assert_eq!(evaluator.eval("i^2"), Ok(Complex::new(-1_f64, 0_f64)));
+        assert_eq!(evaluator.eval("i^3"), Ok(Complex::new(0_f64, -1_f64)));
+        assert_eq!(evaluator.eval("i^4"), Ok(Complex::new(1_f64, 0_f64)));
+        assert_eq!(evaluator.eval("i^0"), Ok(Complex::new(1_f64, 0_f64)));
+        assert_eq!(evaluator.eval("(1+i)^2"), Ok(Complex::new(0_f64, 2_f64)));
+        assert_eq!(evaluator.eval("2^(-2)"), Ok(Complex::new(0.25_f64, 0_f64)));
+        assert_eq!(evaluator.eval("i^(-1)"), Ok(Complex::new(0_f64, -1_f64)));
+    }
+
+    #[test]
+    fn eval_real_if_possible_test() {
+        let config = Config::new().with_complex_number(true);
+        let evaluator: Evaluator<Complex<f64>> =
+            Evaluator::with_context(DefaultContext::with_config_complex(config));
+
+        assert_eq!(evaluator.eval_real_if_possible("sqrt(4)"), Ok(RealOrComplex::Real(2_f64)));
+        assert_eq!(evaluator.eval_real_if_possible("i^2"), Ok(RealOrComplex::Real(-1_f64)));
+        assert_eq!(
+            evaluator.eval_real_if_possible("(2i)^2 + 2i"),
+            Ok(RealOrComplex::Complex(Complex::new(-4_f64, 2_f64)))
+        );
+
+        match evaluator.eval_real_if_possible("sqrt(-4)").unwrap() {
+            RealOrComplex::Complex(n) => assert_eq!(n.im.abs(), 2_f64),
+            RealOrComplex::Real(n) => panic!("expected a complex number: {}", n),
+        }
+
+        assert_eq!(evaluator.eval_real_if_possible("sqrt(4)").unwrap().to_string(), "2");
+    }
+}
@@ -1,29 +1,42 @@
-use crate::function::{BinaryFunction, Function, UnaryFunction};
+use crate::function::{Arity, BinaryFunction, Function, LazyArg, Precedence, UnaryFunction};
 use crate::num::checked::CheckedNum;
 use crate::num::unchecked::UncheckedNum;
 use crate::ops::math::*;
 use crate::utils::ignore_case_str::eq_ignore_case;
-use crate::utils::ignore_case_string::IgnoreCaseString;
-use std::collections::HashSet;
+use crate::utils::format::group_thousands;
+use crate::utils::case_string::CaseString;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Display;
 use std::rc::Rc;
+use num_traits::{FromPrimitive, ToPrimitive, Zero};
 use crate::error::{Error, ErrorKind};
 
 #[cfg(debug_assertions)]
 use validate::TokenKind;
 
 /// Trait to provides the variables, constants and functions used for evaluate an expression.
+///
+/// # Remarks
+/// The methods taking generic functions requires `Self: Sized`, so the trait can be used
+/// as a trait object, eg: `&dyn Context<N>`.
 pub trait Context<'a, N> {
     /// Gets the configuration of the context.
     fn config(&self) -> &Config;
 
     /// Adds a function to the context.
-    fn add_function<F: Function<N> + 'a>(&mut self, func: F) -> crate::Result<()>;
+    fn add_function<F: Function<N> + 'a>(&mut self, func: F) -> crate::Result<()>
+    where
+        Self: Sized;
 
     /// Adds an unary function to the context.
-    fn add_unary_function<F: UnaryFunction<N> + 'a>(&mut self, func: F) -> crate::Result<()>;
+    fn add_unary_function<F: UnaryFunction<N> + 'a>(&mut self, func: F) -> crate::Result<()>
+    where
+        Self: Sized;
 
     /// Adds a binary function to the context.
-    fn add_binary_function<F: BinaryFunction<N> + 'a>(&mut self, func: F) -> crate::Result<()>;
+    fn add_binary_function<F: BinaryFunction<N> + 'a>(&mut self, func: F) -> crate::Result<()>
+    where
+        Self: Sized;
 
     /// Adds a constant value to the context.
     fn add_constant(&mut self, name: &str, value: N) -> crate::Result<()>;
@@ -31,15 +44,47 @@ pub trait Context<'a, N> {
     /// Adds or set the value of a variable in the context.
     fn set_variable(&mut self, name: &str, value: N) -> crate::Result<Option<N>>;
 
+    /// Removes the function with the given name and its aliases from the context,
+    /// returns `false` if not exists.
+    fn remove_function(&mut self, name: &str) -> bool;
+
+    /// Removes the unary function with the given name and its aliases from the context,
+    /// returns `false` if not exists.
+    fn remove_unary_function(&mut self, name: &str) -> bool;
+
+    /// Removes the binary function with the given name and its aliases from the context,
+    /// returns `false` if not exists.
+    fn remove_binary_function(&mut self, name: &str) -> bool;
+
+    /// Removes the variable with the given name from the context and returns its value.
+    fn remove_variable(&mut self, name: &str) -> Option<N>;
+
     /// Gets the value of a variable in the context.
     fn get_variable(&self, name: &str) -> Option<&N>;
 
+    /// Gets the value of the variables not found in the context,
+    /// or `None` if an unknown variable is an error, see `DefaultContext::with_unknown_variable_policy`.
+    #[inline]
+    fn unknown_variable_value(&self) -> Option<&N> {
+        None
+    }
+
     /// Gets the value of a constant in the context.
     fn get_constant(&self, name: &str) -> Option<&N>;
 
     /// Gets a function with the given name.
     fn get_function(&self, name: &str) -> Option<&Rc<dyn Function<N> + 'a>>;
 
+    /// Gets the function with the given name that accepts the given number of arguments,
+    /// for contexts with functions overloaded by its arity, by default is `get_function`.
+    ///
+    /// # Remarks
+    /// If none of the functions accepts the arguments, any of them can be returned.
+    #[inline]
+    fn get_function_with_arity(&self, name: &str, _arg_count: usize) -> Option<&Rc<dyn Function<N> + 'a>> {
+        self.get_function(name)
+    }
+
     /// Gets an unary function with the given name.
     fn get_unary_function(&self, name: &str) -> Option<&Rc<dyn UnaryFunction<N> + 'a>>;
 
@@ -75,6 +120,53 @@ pub trait Context<'a, N> {
     fn is_binary_function(&self, name: &str) -> bool {
         self.get_binary_function(name).is_some()
     }
+
+    /// Gets the kind of the given name, checked in the same order the `Tokenizer` does:
+    /// variables, constants, functions, binary operators and unary operators.
+    ///
+    /// # Remarks
+    /// A name like `-` which is both a binary and an unary operator is classified as
+    /// `NameKind::BinaryOperator`, in an expression it depends on its position.
+    ///
+    /// # Example
+    /// ```
+    /// use prexel::context::{Context, DefaultContext, NameKind};
+    ///
+    /// let context : DefaultContext<f64> = DefaultContext::new_unchecked();
+    /// assert_eq!(context.classify("sin"), Some(NameKind::Function));
+    /// assert_eq!(context.classify("PI"), Some(NameKind::Constant));
+    /// assert_eq!(context.classify("foo"), None);
+    /// ```
+    fn classify(&self, name: &str) -> Option<NameKind> {
+        if self.is_variable(name) {
+            Some(NameKind::Variable)
+        } else if self.is_constant(name) {
+            Some(NameKind::Constant)
+        } else if self.is_function(name) {
+            Some(NameKind::Function)
+        } else if self.is_binary_function(name) {
+            Some(NameKind::BinaryOperator)
+        } else if self.is_unary_function(name) {
+            Some(NameKind::UnaryOperator)
+        } else {
+            None
+        }
+    }
+}
+
+/// The kind of a name in a `Context`, see `Context::classify`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NameKind {
+    /// A variable.
+    Variable,
+    /// A constant.
+    Constant,
+    /// A function.
+    Function,
+    /// A binary operator, eg: `+`.
+    BinaryOperator,
+    /// An unary operator, eg: `!`.
+    UnaryOperator,
 }
 
 // Maps used for store the variables, constants and functions.
@@ -85,19 +177,81 @@ type Map<K, V> =  ritelinked::LinkedHashMap<K, V>;
 #[cfg(not(feature="linked-hashmap"))]
 type Map<K, V> = std::collections::HashMap<K, V>;
 
+/// The functions overloading other with the same name.
+type Overloads<'a, N> = Vec<Rc<dyn Function<N> + 'a>>;
+
+/// A lookup table for the operators named by a single ASCII character eg: `+`, `-`, `*`, `/`,
+/// which avoids hashing the operator name when is looked up during the evaluation.
+///
+/// # Remarks
+/// The table only mirrors the entries of the maps, those still store all the operators.
+#[derive(Clone)]
+struct AsciiTable<V> {
+    entries: Box<[Option<V>]>,
+}
+
+/// Gets the index of the given name in an `AsciiTable`, if the name is a single ASCII character.
+///
+/// Letters are stored in lowercase, to match the case insensitivity of the names of a `DefaultContext`.
+#[inline]
+fn ascii_index(name: &str) -> Option<usize> {
+    match name.as_bytes() {
+        [b] if b.is_ascii() => Some(b.to_ascii_lowercase() as usize),
+        _ => None,
+    }
+}
+
+impl<V> AsciiTable<V> {
+    #[inline]
+    fn new() -> Self {
+        AsciiTable {
+            entries: (0..128).map(|_| None).collect(),
+        }
+    }
+
+    #[inline]
+    fn insert(&mut self, name: &str, value: V) {
+        if let Some(index) = ascii_index(name) {
+            self.entries[index] = Some(value);
+        }
+    }
+
+    #[inline]
+    fn remove(&mut self, name: &str) {
+        if let Some(index) = ascii_index(name) {
+            self.entries[index] = None;
+        }
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> Option<&V> {
+        self.entries[index].as_ref()
+    }
+}
+
 /// Provides a default implementation of a math `Context`.
 #[derive(Clone)]
-pub struct DefaultContext<'a, N> {
+pub struct DefaultContext<'a, N: 'a> {
     /// The variables.
     variables: Map<String, N>,
     /// The constants.
-    constants: Map<IgnoreCaseString, N>,
+    constants: Map<CaseString, N>,
     /// The functions.
-    functions: Map<IgnoreCaseString, Rc<dyn Function<N> + 'a>>,
+    functions: Rc<Map<CaseString, Rc<dyn Function<N> + 'a>>>,
     /// The unary functions.
-    unary_functions: Map<IgnoreCaseString, Rc<dyn UnaryFunction<N> + 'a>>,
+    unary_functions: Rc<Map<CaseString, Rc<dyn UnaryFunction<N> + 'a>>>,
     /// The binary functions.
-    binary_functions: Map<IgnoreCaseString, Rc<dyn BinaryFunction<N> + 'a>>,
+    binary_functions: Rc<Map<CaseString, Rc<dyn BinaryFunction<N> + 'a>>>,
+    /// The unary functions named by a single ASCII character.
+    ascii_unary_functions: Rc<AsciiTable<Rc<dyn UnaryFunction<N> + 'a>>>,
+    /// The binary functions named by a single ASCII character.
+    ascii_binary_functions: Rc<AsciiTable<Rc<dyn BinaryFunction<N> + 'a>>>,
+    /// The functions with the same name of other in `functions` but a different arity.
+    overloads: Rc<Map<CaseString, Overloads<'a, N>>>,
+    /// The disabled functions, those always fails when called.
+    disabled_functions: Rc<Map<CaseString, Rc<dyn Function<N> + 'a>>>,
+    /// How the variables not found in this context are evaluated.
+    unknown_variable_policy: UnknownVariablePolicy<N>,
     /// Additional information about this context
     config: Config,
 }
@@ -113,6 +267,11 @@ impl<'a, N> DefaultContext<'a, N> {
             functions: Default::default(),
             binary_functions: Default::default(),
             unary_functions: Default::default(),
+            ascii_unary_functions: Rc::new(AsciiTable::new()),
+            ascii_binary_functions: Rc::new(AsciiTable::new()),
+            overloads: Default::default(),
+            disabled_functions: Default::default(),
+            unknown_variable_policy: UnknownVariablePolicy::Error,
             config: Config::new(),
         }
     }
@@ -127,10 +286,39 @@ impl<'a, N> DefaultContext<'a, N> {
             functions: Default::default(),
             binary_functions: Default::default(),
             unary_functions: Default::default(),
+            ascii_unary_functions: Rc::new(AsciiTable::new()),
+            ascii_binary_functions: Rc::new(AsciiTable::new()),
+            overloads: Default::default(),
+            disabled_functions: Default::default(),
+            unknown_variable_policy: UnknownVariablePolicy::Error,
             config,
         }
     }
 
+    /// Sets how the variables not found in this context are evaluated,
+    /// by default an unknown variable is an error.
+    ///
+    /// # Remarks
+    /// With `UnknownVariablePolicy::DefaultTo` a misspelled variable is silently evaluated
+    /// to the default value instead of failing, eg: `y + 1` when `x` was meant.
+    ///
+    /// # Example
+    /// ```
+    /// use prexel::context::{DefaultContext, UnknownVariablePolicy};
+    /// use prexel::evaluator::Evaluator;
+    ///
+    /// let context = DefaultContext::new_checked()
+    ///     .with_unknown_variable_policy(UnknownVariablePolicy::DefaultTo(0));
+    ///
+    /// let evaluator : Evaluator<i64> = Evaluator::with_context(context);
+    /// assert_eq!(evaluator.eval("x + 1"), Ok(1));
+    /// ```
+    #[inline]
+    pub fn with_unknown_variable_policy(mut self, policy: UnknownVariablePolicy<N>) -> Self {
+        self.unknown_variable_policy = policy;
+        self
+    }
+
     /// Gets a reference to the variable values of this context.
     #[inline]
     pub fn variables(&self) -> &Map<String, N> {
@@ -139,32 +327,113 @@ impl<'a, N> DefaultContext<'a, N> {
 
     /// Gets a reference to the constant values of this context.
     #[inline]
-    pub fn constants(&self) -> &Map<IgnoreCaseString, N> {
+    pub fn constants(&self) -> &Map<CaseString, N> {
         &self.constants
     }
 
     /// Gets a reference to the functions of this context.
     #[inline]
-    pub fn functions(&self) -> &Map<IgnoreCaseString, Rc<dyn Function<N> + 'a>> {
+    pub fn functions(&self) -> &Map<CaseString, Rc<dyn Function<N> + 'a>> {
         &self.functions
     }
 
+    /// Gets the names of the functions of this context, including its aliases.
+    pub fn function_names(&self) -> Vec<&str> {
+        self.functions.keys().map(|name| name.as_str()).collect()
+    }
+
+    /// Gets the names of the enabled functions that can be called with `n` arguments,
+    /// including its aliases.
+    ///
+    /// # Example
+    /// ```
+    /// use prexel::context::DefaultContext;
+    ///
+    /// let context : DefaultContext<f64> = DefaultContext::new_checked();
+    /// assert!(context.functions_with_arity(1).contains(&"sin"));
+    /// assert!(!context.functions_with_arity(2).contains(&"sin"));
+    /// ```
+    pub fn functions_with_arity(&self, n: usize) -> Vec<&str> {
+        self.functions
+            .keys()
+            .filter(|name| !self.disabled_functions.contains_key(*name))
+            .filter(|name| {
+                self.get_function_with_arity(name.as_str(), n)
+                    .is_some_and(|func| func.arity().accepts(n))
+            })
+            .map(|name| name.as_str())
+            .collect()
+    }
+
     /// Gets a reference to the unary functions of this context.
     #[inline]
-    pub fn unary_functions(&self) -> &Map<IgnoreCaseString, Rc<dyn UnaryFunction<N> + 'a>> {
+    pub fn unary_functions(&self) -> &Map<CaseString, Rc<dyn UnaryFunction<N> + 'a>> {
         &self.unary_functions
     }
 
     /// Gets a reference to the binary functions of this context.
     #[inline]
-    pub fn binary_functions(&self) -> &Map<IgnoreCaseString, Rc<dyn BinaryFunction<N> + 'a>> {
+    pub fn binary_functions(&self) -> &Map<CaseString, Rc<dyn BinaryFunction<N> + 'a>> {
         &self.binary_functions
     }
 
+    /// Gets an iterator over the variables of this context and its names.
+    #[inline]
+    pub fn iter_variables(&self) -> impl Iterator<Item = (&str, &N)> {
+        self.variables.iter().map(|(name, value)| (name.as_str(), value))
+    }
+
+    /// Gets an iterator over the constants of this context and its names.
+    #[inline]
+    pub fn iter_constants(&self) -> impl Iterator<Item = (&str, &N)> {
+        self.constants.iter().map(|(name, value)| (name.as_str(), value))
+    }
+
+    /// Gets an iterator over the functions of this context and the name each one was added with,
+    /// a function is included once for each of its aliases and overloads.
+    ///
+    /// # Example
+    /// ```
+    /// use prexel::context::DefaultContext;
+    ///
+    /// let context : DefaultContext<f64> = DefaultContext::new_checked();
+    /// let (name, sin) = context.iter_functions().find(|(name, _)| *name == "sin").unwrap();
+    /// assert_eq!(sin.name(), name);
+    /// ```
+    pub fn iter_functions(&self) -> impl Iterator<Item = (&str, &dyn Function<N>)> {
+        let overloads = self
+            .overloads
+            .iter()
+            .flat_map(|(name, funcs)| funcs.iter().map(move |func| (name, func)));
+
+        self.functions
+            .iter()
+            .chain(overloads)
+            .map(|(name, func)| (name.as_str(), func.as_ref() as &dyn Function<N>))
+    }
+
+    /// Gets an iterator over the unary functions of this context and the name each one was added with.
+    #[inline]
+    pub fn iter_unary_functions(&self) -> impl Iterator<Item = (&str, &dyn UnaryFunction<N>)> {
+        self.unary_functions
+            .iter()
+            .map(|(name, func)| (name.as_str(), func.as_ref() as &dyn UnaryFunction<N>))
+    }
+
+    /// Gets an iterator over the binary functions of this context and the name each one was added with.
+    #[inline]
+    pub fn iter_binary_functions(&self) -> impl Iterator<Item = (&str, &dyn BinaryFunction<N>)> {
+        self.binary_functions
+            .iter()
+            .map(|(name, func)| (name.as_str(), func.as_ref() as &dyn BinaryFunction<N>))
+    }
+
     /// Adds the specified function to the context using the given name.
     ///
     /// # Remarks
     /// - This allows to use a function with an alias.
+    /// - A function with the name of other can be added if their arities don't overlap,
+    ///   the one called is the one accepting the number of arguments, eg: `foo(1)` and `foo(1, 2)`.
     ///
     /// # Examples
     /// ```
@@ -177,26 +446,153 @@ impl<'a, N> DefaultContext<'a, N> {
     /// ```
     #[inline]
     pub fn add_function_as<F: Function<N> + 'a>(&mut self, func: F, name: &str) -> crate::Result<()> {
+        self.add_function_rc(Rc::new(func), name)
+    }
+
+    /// Adds each of the functions to the context using the given name, eg: to register a library
+    /// of functions at once.
+    ///
+    /// # Remarks
+    /// If a function cannot be added, returns the error and the remaining functions are not added.
+    ///
+    /// # Examples
+    /// ```
+    /// use prexel::context::DefaultContext;
+    /// use prexel::evaluator::Evaluator;
+    /// use prexel::function::Function;
+    /// use prexel::ops::math::{MaxFunction, MinFunction};
+    ///
+    /// let functions: Vec<(String, Box<dyn Function<f64>>)> = vec![
+    ///     ("largest".to_string(), Box::new(MaxFunction)),
+    ///     ("smallest".to_string(), Box::new(MinFunction)),
+    /// ];
+    ///
+    /// let mut context : DefaultContext<f64> = DefaultContext::new();
+    /// context.add_functions(functions).unwrap();
+    ///
+    /// let evaluator = Evaluator::with_context(context);
+    /// assert_eq!(evaluator.eval("largest(1, 5, 3)"), Ok(5_f64));
+    /// ```
+    pub fn add_functions<I>(&mut self, functions: I) -> crate::Result<()>
+    where
+        I: IntoIterator<Item = (String, Box<dyn Function<N> + 'a>)>,
+    {
+        for (name, func) in functions {
+            self.add_function_rc(Rc::from(func), &name)?;
+        }
+
+        Ok(())
+    }
+
+    fn add_function_rc(&mut self, func: Rc<dyn Function<N> + 'a>, name: &str) -> crate::Result<()> {
         #[cfg(debug_assertions)]
         validate::check_token_name(TokenKind::Function, name)?;
 
-        let function_name = IgnoreCaseString::from(name);
-        if self.functions.contains_key(&function_name) {
+        let function_name = self.key(name);
+        let arity = func.arity();
+        if !self.can_add_function(&function_name, &arity) {
             Err(Error::new(ErrorKind::Unknown, format!("A function named '{}' already exists", function_name)))
         } else {
-            let func = Rc::new(func);
-
             if let Some(aliases) = func.aliases() {
-                for alias in aliases.iter().map(|s| IgnoreCaseString::from(*s)) {
-                    if self.functions.contains_key(&alias) {
+                let case_sensitive = self.config.case_sensitive;
+                for alias in aliases.iter().map(|s| CaseString::new(s, case_sensitive)) {
+                    if !self.can_add_function(&alias, &arity) {
                         panic!("A function named '{}' already exists", alias);
                     }
 
-                    self.functions.insert(alias, func.clone());
+                    self.insert_function(alias, func.clone());
                 }
             }
-            self.functions.insert(function_name, func);
+
+            let is_disabled = self.config.is_function_disabled(name)
+                || func.aliases().is_some_and(|aliases| {
+                    aliases.iter().any(|alias| self.config.is_function_disabled(alias))
+                });
+
+            self.insert_function(function_name, func);
+
+            if is_disabled {
+                self.set_function_enabled(name, false)?;
+            }
+
+            Ok(())
+        }
+    }
+
+    /// Checks if a function with the given name and arity can be added, that is, if there is
+    /// no function with that name or all of them have an arity that don't overlap.
+    fn can_add_function(&self, name: &CaseString, arity: &Arity) -> bool {
+        match self.functions.get(name) {
+            None => true,
+            Some(func) => {
+                !func.arity().overlaps(arity)
+                    && self
+                        .overloads
+                        .get(name)
+                        .is_none_or(|funcs| funcs.iter().all(|f| !f.arity().overlaps(arity)))
+            }
+        }
+    }
+
+    /// Checks the given operator name is not a grouping symbol of the `Config`.
+    fn check_not_grouping(&self, name: &str) -> crate::Result<()> {
+        if self.config.is_grouping_symbol(name) {
+            Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("Invalid operator name, `{}` is a grouping symbol of the config", name),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Adds the operator to the context unless its name is a grouping symbol of the `Config`,
+    /// so the default contexts can be used with `Grouping::Angle` without `<` and `>`.
+    pub(crate) fn add_operator_unless_grouping<F: BinaryFunction<N> + 'a>(&mut self, func: F) -> crate::Result<()> {
+        if self.config.is_grouping_symbol(func.name()) {
             Ok(())
+        } else {
+            self.add_binary_function(func)
+        }
+    }
+
+    /// Gets the key of the given name in the maps of this context,
+    /// which ignores case if the `Config` is not case sensitive.
+    #[inline]
+    fn key(&self, name: &str) -> CaseString {
+        CaseString::new(name, self.config.case_sensitive)
+    }
+
+    /// Checks if the names are equal, ignoring case if the `Config` is not case sensitive.
+    #[inline]
+    fn eq_name(&self, a: &str, b: &str) -> bool {
+        if self.config.case_sensitive {
+            a == b
+        } else {
+            eq_ignore_case(a, b)
+        }
+    }
+
+    /// Gets the index of the given name in an `AsciiTable`, the table stores the letters
+    /// in lowercase so those are not looked up on it if the `Config` is case sensitive.
+    #[inline]
+    fn ascii_index(&self, name: &str) -> Option<usize> {
+        ascii_index(name).filter(|index| !self.config.case_sensitive || !(*index as u8).is_ascii_alphabetic())
+    }
+
+    /// Inserts the function in `functions` or in `overloads` if the name already exists.
+    fn insert_function(&mut self, name: CaseString, func: Rc<dyn Function<N> + 'a>) {
+        if self.functions.contains_key(&name) {
+            // `ritelinked` entries don't provide `or_default`, so both maps are used the same way
+            let overloads = Rc::make_mut(&mut self.overloads);
+            match overloads.get_mut(&name) {
+                Some(funcs) => funcs.push(func),
+                None => {
+                    overloads.insert(name, vec![func]);
+                }
+            }
+        } else {
+            Rc::make_mut(&mut self.functions).insert(name, func);
         }
     }
 
@@ -208,24 +604,28 @@ impl<'a, N> DefaultContext<'a, N> {
     pub fn add_unary_function_as<F: UnaryFunction<N> + 'a>(&mut self, func: F, name: &str) -> crate::Result<()> {
         #[cfg(debug_assertions)]
         validate::check_token_name(TokenKind::Operator, name)?;
+        self.check_not_grouping(name)?;
 
-        let function_name = IgnoreCaseString::from(name);
+        let function_name = self.key(name);
         if self.unary_functions.contains_key(&function_name) {
             Err(Error::new(ErrorKind::Unknown, format!("An unary function named '{}' already exists", function_name)))
         } else {
             let func = Rc::new(func);
 
             if let Some(aliases) = func.aliases() {
-                for alias in aliases.iter().map(|s| IgnoreCaseString::from(*s)) {
+                let case_sensitive = self.config.case_sensitive;
+                for alias in aliases.iter().map(|s| CaseString::new(s, case_sensitive)) {
                     if self.unary_functions.contains_key(&alias) {
                         panic!("An unary function named '{}' already exists", alias);
                     }
 
-                    self.unary_functions.insert(alias, func.clone());
+                    Rc::make_mut(&mut self.ascii_unary_functions).insert(alias.as_str(), func.clone());
+                    Rc::make_mut(&mut self.unary_functions).insert(alias, func.clone());
                 }
             }
 
-            self.unary_functions.insert(function_name, func);
+            Rc::make_mut(&mut self.ascii_unary_functions).insert(function_name.as_str(), func.clone());
+            Rc::make_mut(&mut self.unary_functions).insert(function_name, func);
             Ok(())
         }
     }
@@ -233,129 +633,629 @@ impl<'a, N> DefaultContext<'a, N> {
     /// Adds the specified binary function to the context using the given name.
     ///
     /// # Remarks
-    /// - This allows to use a binary function with an alias.
+    /// - This allows to use a binary function with an alias or other symbol, eg: `÷` for divisions.
     ///
     /// # Examples
     /// ```
     /// use prexel::context::{DefaultContext, Context};
-    /// use prexel::ops::unchecked::AddOperator;
+    /// use prexel::evaluator::Evaluator;
+    /// use prexel::ops::unchecked::{AddOperator, DivOperator};
     ///
     /// let mut context : DefaultContext<f64> = DefaultContext::new();
     /// context.add_binary_function(AddOperator);
     /// context.add_binary_function_as(AddOperator, "Plus");
+    /// context.add_binary_function_as(DivOperator, "÷");
+    ///
+    /// let evaluator = Evaluator::with_context(context);
+    /// assert_eq!(evaluator.eval("6 ÷ 2 Plus 1"), Ok(4_f64));
     /// ```
     #[inline]
     pub fn add_binary_function_as<F: BinaryFunction<N> + 'a>(&mut self, func: F, name: &str) -> crate::Result<()> {
         #[cfg(debug_assertions)]
         validate::check_token_name(TokenKind::Operator, name)?;
+        self.check_not_grouping(name)?;
 
-        let function_name = IgnoreCaseString::from(name);
+        let function_name = self.key(name);
         if self.binary_functions.contains_key(&function_name) {
             Err(Error::new(ErrorKind::Unknown, format!("A binary function named '{}' already exists", function_name)))
         } else {
             let func = Rc::new(func);
 
             if let Some(aliases) = func.aliases() {
-                for alias in aliases.iter().map(|s| IgnoreCaseString::from(*s)) {
+                let case_sensitive = self.config.case_sensitive;
+                for alias in aliases.iter().map(|s| CaseString::new(s, case_sensitive)) {
                     if self.binary_functions.contains_key(&alias) {
                         panic!("A binary function named '{}' already exists", alias);
                     }
 
-                    self.binary_functions.insert(alias, func.clone());
+                    Rc::make_mut(&mut self.ascii_binary_functions).insert(alias.as_str(), func.clone());
+                    Rc::make_mut(&mut self.binary_functions).insert(alias, func.clone());
                 }
             }
 
-            self.binary_functions.insert(function_name, func);
+            Rc::make_mut(&mut self.ascii_binary_functions).insert(function_name.as_str(), func.clone());
+            Rc::make_mut(&mut self.binary_functions).insert(function_name, func);
             Ok(())
         }
     }
 }
 
-impl<'a, N> Default for DefaultContext<'a, N> {
+/// Gets the value with the given name, if `case_sensitive` the name must have the same case.
+#[inline]
+fn get_by_name<'m, V>(map: &'m Map<CaseString, V>, name: &str, case_sensitive: bool) -> Option<&'m V> {
+    map.get(&CaseString::new(name, case_sensitive))
+}
+
+/// Removes the value with the given name and all the names sharing it, eg: its aliases,
+/// returns the removed names.
+fn remove_with_aliases<V: ?Sized>(
+    map: &mut Map<CaseString, Rc<V>>,
+    name: &str,
+    case_sensitive: bool,
+) -> Vec<CaseString> {
+    let value = match get_by_name(map, name, case_sensitive) {
+        Some(value) => value.clone(),
+        None => return Vec::new(),
+    };
+
+    let names: Vec<CaseString> = map
+        .iter()
+        .filter(|(_, v)| Rc::ptr_eq(v, &value))
+        .map(|(name, _)| name.clone())
+        .collect();
+
+    for name in &names {
+        map.remove(name);
+    }
+
+    names
+}
+
+impl<'a, N: 'a> DefaultContext<'a, N> {
+    /// Enables or disables the function with the given name and its aliases.
+    ///
+    /// # Remarks
+    /// A disabled function is still in the context, but returns an error when called.
+    ///
+    /// # Example
+    /// ```
+    /// use prexel::context::DefaultContext;
+    /// use prexel::evaluator::Evaluator;
+    ///
+    /// let mut context : DefaultContext<f64> = DefaultContext::new_checked();
+    /// context.set_function_enabled("random", false).unwrap();
+    ///
+    /// let evaluator = Evaluator::with_context(context);
+    /// assert!(evaluator.eval("random()").is_err());
+    /// ```
+    pub fn set_function_enabled(&mut self, name: &str, enabled: bool) -> crate::Result<()> {
+        let func = get_by_name(&self.functions, name, self.config.case_sensitive)
+            .cloned()
+            .ok_or_else(|| Error::new(ErrorKind::InvalidInput, format!("Function `{}` not found", name)))?;
+
+        let disabled: Rc<dyn Function<N> + 'a> = Rc::new(DisabledFunction(func.clone()));
+        for (name, _) in self.functions.iter().filter(|(_, f)| Rc::ptr_eq(f, &func)) {
+            if enabled {
+                Rc::make_mut(&mut self.disabled_functions).remove(name);
+            } else {
+                Rc::make_mut(&mut self.disabled_functions).insert(name.clone(), disabled.clone());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks if the function with the given name is enabled, returns `false` if not exists.
     #[inline]
-    fn default() -> Self {
-        DefaultContext::new()
+    pub fn is_function_enabled(&self, name: &str) -> bool {
+        get_by_name(&self.functions, name, self.config.case_sensitive).is_some()
+            && !self.disabled_functions.contains_key(&self.key(name))
     }
 }
 
-impl<'a, N> Context<'a, N> for DefaultContext<'a, N> {
+impl<'a, N> DefaultContext<'a, N> {
+    /// Adds the given function to this context and returns it, allowing to chain the calls.
+    ///
+    /// # Example
+    /// ```
+    /// use prexel::context::DefaultContext;
+    /// use prexel::evaluator::Evaluator;
+    /// use prexel::ops::checked::AddOperator;
+    /// use prexel::ops::math::MaxFunction;
+    ///
+    /// let context : DefaultContext<i64> = DefaultContext::new()
+    ///     .with_binary_function(AddOperator).unwrap()
+    ///     .with_function(MaxFunction).unwrap()
+    ///     .with_constant("TEN", 10).unwrap();
+    ///
+    /// let evaluator = Evaluator::with_context(context);
+    /// assert_eq!(evaluator.eval("Max(2, 3) + TEN"), Ok(13));
+    /// ```
     #[inline]
-    fn config(&self) -> &Config {
-        &self.config
+    pub fn with_function<F: Function<N> + 'a>(mut self, func: F) -> crate::Result<Self> {
+        self.add_function(func)?;
+        Ok(self)
     }
 
+    /// Adds the given unary function to this context and returns it, allowing to chain the calls.
     #[inline]
-    fn add_function<F: Function<N> + 'a>(&mut self, func: F) -> crate::Result<()> {
-        let name = func.name().to_string();
-        self.add_function_as(func, &name)
+    pub fn with_unary_function<F: UnaryFunction<N> + 'a>(mut self, func: F) -> crate::Result<Self> {
+        self.add_unary_function(func)?;
+        Ok(self)
     }
 
+    /// Adds the given binary function to this context and returns it, allowing to chain the calls.
     #[inline]
-    fn add_unary_function<F: UnaryFunction<N> + 'a>(&mut self, func: F) -> crate::Result<()> {
-        let name = func.name().to_string();
-        self.add_unary_function_as(func, &name)
+    pub fn with_binary_function<F: BinaryFunction<N> + 'a>(mut self, func: F) -> crate::Result<Self> {
+        self.add_binary_function(func)?;
+        Ok(self)
     }
 
+    /// Adds the given constant to this context and returns it, allowing to chain the calls.
     #[inline]
-    fn add_binary_function<F: BinaryFunction<N> + 'a>(&mut self, func: F) -> crate::Result<()> {
-        let name = func.name().to_string();
-        self.add_binary_function_as(func, &name)
+    pub fn with_constant(mut self, name: &str, value: N) -> crate::Result<Self> {
+        self.add_constant(name, value)?;
+        Ok(self)
     }
 
+    /// Sets the given variable in this context and returns it, allowing to chain the calls.
     #[inline]
-    fn add_constant(&mut self, name: &str, value: N) -> crate::Result<()> {
-        #[cfg(debug_assertions)]
-        validate::check_token_name(TokenKind::Constant, name)?;
+    pub fn with_variable(mut self, name: &str, value: N) -> crate::Result<Self> {
+        self.set_variable(name, value)?;
+        Ok(self)
+    }
+}
 
-        //self.constants.insert(IgnoreCaseString::from(name), value);
-        // let string = IgnoreCaseString::from(name);
-        if self.variables.keys().any(|k| eq_ignore_case(k, name)) {
-            Err(Error::new(ErrorKind::Unknown, format!("Invalid constant name, a variable named `{}` exists", name)))
-        } else {
-            self.constants.insert(IgnoreCaseString::from(name), value);
-            Ok(())
+impl<'a, N: ToPrimitive> DefaultContext<'a, N> {
+    /// Gets the names of the constants with its values as `f64`, eg: for a reference table,
+    /// the constants that cannot be represented as `f64` are not included.
+    ///
+    /// # Example
+    /// ```
+    /// use prexel::context::DefaultContext;
+    ///
+    /// let context : DefaultContext<f64> = DefaultContext::new_checked();
+    /// assert!(context.constants_as_f64().contains(&("E".to_string(), std::f64::consts::E)));
+    /// ```
+    pub fn constants_as_f64(&self) -> Vec<(String, f64)> {
+        self.constants
+            .iter()
+            .filter_map(|(name, value)| value.to_f64().map(|n| (name.to_string(), n)))
+            .collect()
+    }
+}
+
+impl<'a, N: Clone> DefaultContext<'a, N> {
+    /// Creates a context with the same constants, functions and config as this one,
+    /// but with no variables.
+    ///
+    /// # Remarks
+    /// The function tables are shared with this context instead of being copied, so forking
+    /// is cheap, each context copies its tables only if it adds or disables a function.
+    ///
+    /// # Example
+    /// ```
+    /// use prexel::context::{Context, DefaultContext};
+    ///
+    /// let mut context : DefaultContext<f64> = DefaultContext::new_unchecked();
+    /// context.set_variable("x", 10_f64).unwrap();
+    ///
+    /// let fork = context.fork();
+    /// assert!(fork.get_variable("x").is_none());
+    /// assert!(fork.get_function("sin").is_some());
+    /// ```
+    pub fn fork(&self) -> Self {
+        DefaultContext {
+            variables: Default::default(),
+            constants: self.constants.clone(),
+            functions: self.functions.clone(),
+            unary_functions: self.unary_functions.clone(),
+            binary_functions: self.binary_functions.clone(),
+            ascii_unary_functions: self.ascii_unary_functions.clone(),
+            ascii_binary_functions: self.ascii_binary_functions.clone(),
+            overloads: self.overloads.clone(),
+            disabled_functions: self.disabled_functions.clone(),
+            unknown_variable_policy: self.unknown_variable_policy.clone(),
+            config: self.config.clone(),
         }
     }
 
-    #[inline]
-    fn set_variable(&mut self, name: &str, value: N) -> crate::Result<Option<N>> {
-        #[cfg(debug_assertions)]
-        validate::check_token_name(TokenKind::Variable, name)?;
+    /// Gets a snapshot of the variables and constants of this context.
+    ///
+    /// # Example
+    /// ```
+    /// use prexel::context::{Context, DefaultContext};
+    /// use prexel::evaluator::Evaluator;
+    ///
+    /// let mut context : DefaultContext<f64> = DefaultContext::new_checked();
+    /// context.set_variable("x", 10_f64).unwrap();
+    /// let state = context.export_state();
+    ///
+    /// let mut other : DefaultContext<f64> = DefaultContext::new_checked();
+    /// other.import_state(state).unwrap();
+    ///
+    /// let evaluator = Evaluator::with_context(other);
+    /// assert_eq!(evaluator.eval("x * 2"), Ok(20_f64));
+    /// ```
+    pub fn export_state(&self) -> ContextState<N> {
+        ContextState {
+            variables: self
+                .variables
+                .iter()
+                .map(|(name, value)| (name.clone(), value.clone()))
+                .collect(),
+            constants: self
+                .constants
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.clone()))
+                .collect(),
+        }
+    }
 
-        if self
-            .constants
-            .keys()
-            .any(|k| eq_ignore_case(k.as_str(), name))
-        {
-            Err(Error::new(ErrorKind::Unknown, format!("Invalid variable name, a constant named `{}` exists", name)))
-        } else {
-            Ok(self.variables.insert(name.to_string(), value))
+    /// Adds the variables and constants of the given state to this context,
+    /// replacing the existing values with the same name.
+    pub fn import_state(&mut self, state: ContextState<N>) -> crate::Result<()> {
+        for (name, value) in state.constants {
+            self.add_constant(&name, value)?;
+        }
+
+        for (name, value) in state.variables {
+            self.set_variable(&name, value)?;
         }
+
+        Ok(())
     }
+}
+
+/// A snapshot of the variables and constants of a `DefaultContext`, used to save and restore its values.
+///
+/// # Remarks
+/// This is serializable with `serde` when the `serde` feature is enabled.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContextState<N> {
+    /// The variables.
+    pub variables: BTreeMap<String, N>,
+    /// The constants.
+    pub constants: BTreeMap<String, N>,
+}
 
+/// A function which always fails when called, used for disable other function.
+struct DisabledFunction<'a, N>(Rc<dyn Function<N> + 'a>);
+
+impl<'a, N> Function<N> for DisabledFunction<'a, N> {
     #[inline]
-    fn get_variable(&self, name: &str) -> Option<&N> {
-        self.variables.get(name)
+    fn name(&self) -> &str {
+        self.0.name()
     }
 
     #[inline]
-    fn get_constant(&self, name: &str) -> Option<&N> {
-        self.constants.get(&IgnoreCaseString::from(name))
+    fn aliases(&self) -> Option<&[&str]> {
+        self.0.aliases()
     }
 
     #[inline]
-    fn get_function(&self, name: &str) -> Option<&Rc<dyn Function<N> + 'a>> {
-        self.functions.get(&IgnoreCaseString::from(name))
+    fn arity(&self) -> Arity {
+        self.0.arity()
     }
 
-    #[inline]
-    fn get_unary_function(&self, name: &str) -> Option<&Rc<dyn UnaryFunction<N> + 'a>> {
-        self.unary_functions.get(&IgnoreCaseString::from(name))
+    fn call(&self, _: &[N]) -> crate::Result<N> {
+        Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("Function `{}` is disabled", self.0.name()),
+        ))
     }
 
     #[inline]
-    fn get_binary_function(&self, name: &str) -> Option<&Rc<dyn BinaryFunction<N> + 'a>> {
-        self.binary_functions.get(&IgnoreCaseString::from(name))
+    fn wants_lazy_args(&self) -> bool {
+        self.0.wants_lazy_args()
+    }
+
+    fn call_lazy(&self, _: &[LazyArg<'_, N>]) -> crate::Result<N> {
+        self.call(&[])
+    }
+
+    #[cfg(feature = "docs")]
+    fn description(&self) -> Option<&str> {
+        self.0.description()
+    }
+
+    #[cfg(feature = "docs")]
+    fn category(&self) -> Option<&str> {
+        self.0.category()
+    }
+}
+
+impl<'a, N> Default for DefaultContext<'a, N> {
+    #[inline]
+    fn default() -> Self {
+        DefaultContext::new()
+    }
+}
+
+impl<'a, N> Context<'a, N> for DefaultContext<'a, N> {
+    #[inline]
+    fn config(&self) -> &Config {
+        &self.config
+    }
+
+    #[inline]
+    fn add_function<F: Function<N> + 'a>(&mut self, func: F) -> crate::Result<()> {
+        let name = func.name().to_string();
+        self.add_function_as(func, &name)
+    }
+
+    #[inline]
+    fn add_unary_function<F: UnaryFunction<N> + 'a>(&mut self, func: F) -> crate::Result<()> {
+        let name = func.name().to_string();
+        self.add_unary_function_as(func, &name)
+    }
+
+    #[inline]
+    fn add_binary_function<F: BinaryFunction<N> + 'a>(&mut self, func: F) -> crate::Result<()> {
+        let name = func.name().to_string();
+        self.add_binary_function_as(func, &name)
+    }
+
+    #[inline]
+    fn add_constant(&mut self, name: &str, value: N) -> crate::Result<()> {
+        #[cfg(debug_assertions)]
+        validate::check_token_name(TokenKind::Constant, name)?;
+
+        //self.constants.insert(IgnoreCaseString::from(name), value);
+        // let string = IgnoreCaseString::from(name);
+        if self.variables.keys().any(|k| self.eq_name(k, name)) {
+            Err(Error::new(ErrorKind::Unknown, format!("Invalid constant name, a variable named `{}` exists", name)))
+        } else {
+            self.constants.insert(self.key(name), value);
+            Ok(())
+        }
+    }
+
+    #[inline]
+    fn set_variable(&mut self, name: &str, value: N) -> crate::Result<Option<N>> {
+        #[cfg(debug_assertions)]
+        validate::check_token_name(TokenKind::Variable, name)?;
+
+        if self
+            .constants
+            .keys()
+            .any(|k| self.eq_name(k.as_str(), name))
+        {
+            Err(Error::new(ErrorKind::Unknown, format!("Invalid variable name, a constant named `{}` exists", name)))
+        } else {
+            Ok(self.variables.insert(name.to_string(), value))
+        }
+    }
+
+    fn remove_function(&mut self, name: &str) -> bool {
+        let mut removed = match get_by_name(&self.functions, name, self.config.case_sensitive) {
+            Some(func) => vec![func.clone()],
+            None => return false,
+        };
+
+        let name = self.key(name);
+
+        let overloads = Rc::make_mut(&mut self.overloads);
+        if let Some(funcs) = overloads.remove(&name) {
+            removed.extend(funcs);
+        }
+
+        let is_removed = |func: &Rc<dyn Function<N> + 'a>| removed.iter().any(|f| Rc::ptr_eq(f, func));
+        for funcs in overloads.values_mut() {
+            funcs.retain(|f| !is_removed(f));
+        }
+
+        let functions = Rc::make_mut(&mut self.functions);
+        let names: Vec<CaseString> = functions
+            .iter()
+            .filter(|(_, f)| is_removed(f))
+            .map(|(name, _)| name.clone())
+            .collect();
+
+        for name in names {
+            functions.remove(&name);
+            Rc::make_mut(&mut self.disabled_functions).remove(&name);
+
+            // An overload of other function with this name takes its place
+            if let Some(mut funcs) = overloads.remove(&name) {
+                if !funcs.is_empty() {
+                    functions.insert(name.clone(), funcs.remove(0));
+                    overloads.insert(name, funcs);
+                }
+            }
+        }
+
+        overloads.retain(|_, funcs| !funcs.is_empty());
+        true
+    }
+
+    fn remove_unary_function(&mut self, name: &str) -> bool {
+        let names = remove_with_aliases(Rc::make_mut(&mut self.unary_functions), name, self.config.case_sensitive);
+        for name in &names {
+            Rc::make_mut(&mut self.ascii_unary_functions).remove(name.as_str());
+        }
+
+        !names.is_empty()
+    }
+
+    fn remove_binary_function(&mut self, name: &str) -> bool {
+        let names = remove_with_aliases(Rc::make_mut(&mut self.binary_functions), name, self.config.case_sensitive);
+        for name in &names {
+            Rc::make_mut(&mut self.ascii_binary_functions).remove(name.as_str());
+        }
+
+        !names.is_empty()
+    }
+
+    #[inline]
+    fn remove_variable(&mut self, name: &str) -> Option<N> {
+        self.variables.remove(name)
+    }
+
+    #[inline]
+    fn get_variable(&self, name: &str) -> Option<&N> {
+        self.variables.get(name)
+    }
+
+    #[inline]
+    fn unknown_variable_value(&self) -> Option<&N> {
+        match &self.unknown_variable_policy {
+            UnknownVariablePolicy::Error => None,
+            UnknownVariablePolicy::DefaultTo(n) => Some(n),
+        }
+    }
+
+    #[inline]
+    fn get_constant(&self, name: &str) -> Option<&N> {
+        get_by_name(&self.constants, name, self.config.case_sensitive)
+    }
+
+    #[inline]
+    fn get_function(&self, name: &str) -> Option<&Rc<dyn Function<N> + 'a>> {
+        let func = get_by_name(&self.functions, name, self.config.case_sensitive)?;
+        if !self.disabled_functions.is_empty() {
+            if let Some(disabled) = self.disabled_functions.get(&self.key(name)) {
+                return Some(disabled);
+            }
+        }
+
+        Some(func)
+    }
+
+    fn get_function_with_arity(&self, name: &str, arg_count: usize) -> Option<&Rc<dyn Function<N> + 'a>> {
+        let func = self.get_function(name)?;
+        if self.overloads.is_empty() || func.arity().accepts(arg_count) {
+            return Some(func);
+        }
+
+        // The disabled functions include all its overloads
+        let name = self.key(name);
+        if self.disabled_functions.contains_key(&name) {
+            return Some(func);
+        }
+
+        self.overloads
+            .get(&name)
+            .and_then(|funcs| funcs.iter().find(|f| f.arity().accepts(arg_count)))
+            .or(Some(func))
+    }
+
+    #[inline]
+    fn get_unary_function(&self, name: &str) -> Option<&Rc<dyn UnaryFunction<N> + 'a>> {
+        match self.ascii_index(name) {
+            Some(index) => self.ascii_unary_functions.get(index),
+            None => get_by_name(&self.unary_functions, name, self.config.case_sensitive),
+        }
+    }
+
+    #[inline]
+    fn get_binary_function(&self, name: &str) -> Option<&Rc<dyn BinaryFunction<N> + 'a>> {
+        match self.ascii_index(name) {
+            Some(index) => self.ascii_binary_functions.get(index),
+            None => get_by_name(&self.binary_functions, name, self.config.case_sensitive),
+        }
+    }
+}
+
+/// A read-only `Context` which resolves the lookups through an ordered list of contexts,
+/// the first context containing the value is the one used.
+///
+/// # Remarks
+/// Adding values to this context always fails.
+pub(crate) struct ScopedContext<'s, 'a, N> {
+    scopes: &'s [&'s dyn Context<'a, N>],
+    config: &'s Config,
+}
+
+impl<'s, 'a, N> ScopedContext<'s, 'a, N> {
+    /// Constructs a new `ScopedContext` from the given contexts and `Config`.
+    #[inline]
+    pub fn new(scopes: &'s [&'s dyn Context<'a, N>], config: &'s Config) -> Self {
+        ScopedContext { scopes, config }
+    }
+
+    #[inline]
+    fn read_only_error() -> Error {
+        Error::new(ErrorKind::InvalidInput, "Cannot modify a scoped context")
+    }
+}
+
+impl<'s, 'a, N> Context<'a, N> for ScopedContext<'s, 'a, N> {
+    #[inline]
+    fn config(&self) -> &Config {
+        self.config
+    }
+
+    fn add_function<F: Function<N> + 'a>(&mut self, _: F) -> crate::Result<()> {
+        Err(Self::read_only_error())
+    }
+
+    fn add_unary_function<F: UnaryFunction<N> + 'a>(&mut self, _: F) -> crate::Result<()> {
+        Err(Self::read_only_error())
+    }
+
+    fn add_binary_function<F: BinaryFunction<N> + 'a>(&mut self, _: F) -> crate::Result<()> {
+        Err(Self::read_only_error())
+    }
+
+    fn add_constant(&mut self, _: &str, _: N) -> crate::Result<()> {
+        Err(Self::read_only_error())
+    }
+
+    fn set_variable(&mut self, _: &str, _: N) -> crate::Result<Option<N>> {
+        Err(Self::read_only_error())
+    }
+
+    fn remove_function(&mut self, _: &str) -> bool {
+        false
+    }
+
+    fn remove_unary_function(&mut self, _: &str) -> bool {
+        false
+    }
+
+    fn remove_binary_function(&mut self, _: &str) -> bool {
+        false
+    }
+
+    fn remove_variable(&mut self, _: &str) -> Option<N> {
+        None
+    }
+
+    #[inline]
+    fn get_variable(&self, name: &str) -> Option<&N> {
+        self.scopes.iter().find_map(|c| c.get_variable(name))
+    }
+
+    #[inline]
+    fn unknown_variable_value(&self) -> Option<&N> {
+        self.scopes.iter().find_map(|c| c.unknown_variable_value())
+    }
+
+    #[inline]
+    fn get_constant(&self, name: &str) -> Option<&N> {
+        self.scopes.iter().find_map(|c| c.get_constant(name))
+    }
+
+    #[inline]
+    fn get_function(&self, name: &str) -> Option<&Rc<dyn Function<N> + 'a>> {
+        self.scopes.iter().find_map(|c| c.get_function(name))
+    }
+
+    #[inline]
+    fn get_function_with_arity(&self, name: &str, arg_count: usize) -> Option<&Rc<dyn Function<N> + 'a>> {
+        self.scopes.iter().find_map(|c| c.get_function_with_arity(name, arg_count))
+    }
+
+    #[inline]
+    fn get_unary_function(&self, name: &str) -> Option<&Rc<dyn UnaryFunction<N> + 'a>> {
+        self.scopes.iter().find_map(|c| c.get_unary_function(name))
+    }
+
+    #[inline]
+    fn get_binary_function(&self, name: &str) -> Option<&Rc<dyn BinaryFunction<N> + 'a>> {
+        self.scopes.iter().find_map(|c| c.get_binary_function(name))
     }
 }
 
@@ -384,22 +1284,70 @@ impl<'a, N: CheckedNum> DefaultContext<'a, N> {
         context.add_binary_function(AddOperator).unwrap();
         context.add_binary_function(SubOperator).unwrap();
         context.add_binary_function(MulOperator).unwrap();
-        context.add_binary_function(DivOperator).unwrap();
-        context.add_binary_function(PowOperator).unwrap();
-        context.add_binary_function(ModOperator).unwrap();
+        if context.config().float_ieee {
+            context.add_binary_function(crate::ops::ieee::DivOperator).unwrap();
+            context.add_binary_function(crate::ops::ieee::ModOperator).unwrap();
+            context.add_binary_function(crate::ops::ieee::PowOperator).unwrap();
+        } else {
+            context.add_binary_function(DivOperator).unwrap();
+            context.add_binary_function(ModOperator).unwrap();
+            context.add_binary_function(PowOperator).unwrap();
+        }
+        context.add_operator_unless_grouping(EqOperator).unwrap();
+        context.add_operator_unless_grouping(NeOperator).unwrap();
+        context.add_operator_unless_grouping(GtOperator).unwrap();
+        context.add_operator_unless_grouping(LtOperator).unwrap();
+        context.add_operator_unless_grouping(GteOperator).unwrap();
+        context.add_operator_unless_grouping(LteOperator).unwrap();
         context.add_unary_function(UnaryPlus).unwrap();
         context.add_unary_function(UnaryMinus).unwrap();
         context.add_unary_function(Factorial).unwrap();
+        context.add_function(CombinationFunction).unwrap();
+        context.add_function(PermutationFunction).unwrap();
         context.add_function(SumFunction).unwrap();
         context.add_function(ProdFunction).unwrap();
         context.add_function(AvgFunction).unwrap();
+        context.add_function(GeoMeanFunction).unwrap();
+        context.add_function(HarMeanFunction).unwrap();
+        context.add_function(GcdFunction).unwrap();
+        context.add_function(LcmFunction).unwrap();
         context.add_function(MaxFunction).unwrap();
+        context.add_function(IfFunction).unwrap();
+        context.add_function(AndFunction).unwrap();
+        context.add_function(OrFunction).unwrap();
+        context.add_function(NotFunction).unwrap();
         context.add_function(MinFunction).unwrap();
+        context.add_function(MaxIgnoreNanFunction).unwrap();
+        context.add_function(MinIgnoreNanFunction).unwrap();
+        context.add_function(PercentileFunction).unwrap();
         context.add_function(AbsFunction).unwrap();
-        context.add_function(SqrtFunction).unwrap();
-        context.add_function(LnFunction).unwrap();
-        context.add_function(LogFunction).unwrap();
-        context.add_function(ExpFunction).unwrap();
+        context.add_function(WrapFunction).unwrap();
+        context.add_function(ClampFunction).unwrap();
+        context.add_function(Clamp01Function).unwrap();
+        context.add_function(StepFunction).unwrap();
+        context.add_function(SmoothStepFunction).unwrap();
+        context.add_function(PowModFunction).unwrap();
+        if context.config().float_ieee {
+            use crate::ops::ieee;
+
+            context.add_function(ieee::SqrtFunction).unwrap();
+            context.add_function(ieee::LnFunction).unwrap();
+            context.add_function(ieee::LogFunction).unwrap();
+            context.add_function(ieee::Log2Function).unwrap();
+            context.add_function(ieee::Log10Function).unwrap();
+            context.add_function(ieee::ExpFunction).unwrap();
+        } else {
+            context.add_function(SqrtFunction).unwrap();
+            context.add_function(LnFunction).unwrap();
+            context.add_function(LogFunction).unwrap();
+            context.add_function(Log2Function).unwrap();
+            context.add_function(Log10Function).unwrap();
+            context.add_function(ExpFunction).unwrap();
+        }
+        context.add_function(CbrtFunction).unwrap();
+        context.add_function(LogBFunction).unwrap();
+        context.add_function(Exp2Function).unwrap();
+        context.add_function(Pow10Function).unwrap();
         context.add_function(FloorFunction).unwrap();
         context.add_function(CeilFunction).unwrap();
         context.add_function(TruncateFunction).unwrap();
@@ -432,6 +1380,12 @@ impl<'a, N: CheckedNum> DefaultContext<'a, N> {
         context.add_function(ACschFunction).unwrap();
         context.add_function(ASechFunction).unwrap();
         context.add_function(ACothFunction).unwrap();
+
+        if is_float::<N>() {
+            for name in ["&", "|"] {
+                context.add_binary_function(UnsupportedOperator::new(name, "floating point numbers", Precedence::LOW)).unwrap();
+            }
+        }
         context
     }
 }
@@ -446,118 +1400,561 @@ impl<'a, N: UncheckedNum> DefaultContext<'a, N> {
         Self::with_config_unchecked(Config::new())
     }
 
-    /// Constructs a new `Context` using the given `Config` with unchecked functions.
+    /// Constructs a new `Context` using the given `Config` with unchecked functions.
+    ///
+    /// # Remarks
+    /// Functions of this context may panic when the value overflows.
+    pub fn with_config_unchecked(config: Config) -> Self {
+        use crate::ops::unchecked::*;
+
+        let mut context = Self::with_config(config);
+        context.add_constant("PI", N::from_f64(std::f64::consts::PI).unwrap()).unwrap();
+        context.add_constant("E", N::from_f64(std::f64::consts::E).unwrap()).unwrap();
+        context.add_binary_function(AddOperator).unwrap();
+        context.add_binary_function(SubOperator).unwrap();
+        context.add_binary_function(MulOperator).unwrap();
+        context.add_binary_function(DivOperator).unwrap();
+        context.add_binary_function(PowOperator).unwrap();
+        context.add_binary_function(ModOperator).unwrap();
+        context.add_operator_unless_grouping(EqOperator).unwrap();
+        context.add_operator_unless_grouping(NeOperator).unwrap();
+        context.add_operator_unless_grouping(GtOperator).unwrap();
+        context.add_operator_unless_grouping(LtOperator).unwrap();
+        context.add_operator_unless_grouping(GteOperator).unwrap();
+        context.add_operator_unless_grouping(LteOperator).unwrap();
+        context.add_unary_function(UnaryPlus).unwrap();
+        context.add_unary_function(UnaryMinus).unwrap();
+        context.add_unary_function(Factorial).unwrap();
+        context.add_function(CombinationFunction).unwrap();
+        context.add_function(PermutationFunction).unwrap();
+        context.add_function(SumFunction).unwrap();
+        context.add_function(AvgFunction).unwrap();
+        context.add_function(GeoMeanFunction).unwrap();
+        context.add_function(HarMeanFunction).unwrap();
+        context.add_function(GcdFunction).unwrap();
+        context.add_function(LcmFunction).unwrap();
+        context.add_function(ProdFunction).unwrap();
+        context.add_function(MaxFunction).unwrap();
+        context.add_function(IfFunction).unwrap();
+        context.add_function(AndFunction).unwrap();
+        context.add_function(OrFunction).unwrap();
+        context.add_function(NotFunction).unwrap();
+        context.add_function(MinFunction).unwrap();
+        context.add_function(MaxIgnoreNanFunction).unwrap();
+        context.add_function(MinIgnoreNanFunction).unwrap();
+        context.add_function(PercentileFunction).unwrap();
+        context.add_function(WrapFunction).unwrap();
+        context.add_function(ClampFunction).unwrap();
+        context.add_function(Clamp01Function).unwrap();
+        context.add_function(StepFunction).unwrap();
+        context.add_function(SmoothStepFunction).unwrap();
+        context.add_function(SqrtFunction).unwrap();
+        context.add_function(CbrtFunction).unwrap();
+        context.add_function(LnFunction).unwrap();
+        context.add_function(LogFunction).unwrap();
+        context.add_function(Log2Function).unwrap();
+        context.add_function(Log10Function).unwrap();
+        context.add_function(LogBFunction).unwrap();
+        context.add_function(RandFunction).unwrap();
+        context.add_function(ToRadiansFunction).unwrap();
+        context.add_function(ToDegreesFunction).unwrap();
+        context.add_function(ExpFunction).unwrap();
+        context.add_function(Exp2Function).unwrap();
+        context.add_function(Pow10Function).unwrap();
+        context.add_function(SinFunction).unwrap();
+        context.add_function(CosFunction).unwrap();
+        context.add_function(TanFunction).unwrap();
+        context.add_function(CscFunction).unwrap();
+        context.add_function(SecFunction).unwrap();
+        context.add_function(CotFunction).unwrap();
+        context.add_function(ASinFunction).unwrap();
+        context.add_function(ACosFunction).unwrap();
+        context.add_function(ATanFunction).unwrap();
+        context.add_function(ACscFunction).unwrap();
+        context.add_function(ASecFunction).unwrap();
+        context.add_function(ACotFunction).unwrap();
+        context.add_function(SinhFunction).unwrap();
+        context.add_function(CoshFunction).unwrap();
+        context.add_function(TanhFunction).unwrap();
+        context.add_function(CschFunction).unwrap();
+        context.add_function(SechFunction).unwrap();
+        context.add_function(CothFunction).unwrap();
+        context.add_function(ASinhFunction).unwrap();
+        context.add_function(ACoshFunction).unwrap();
+        context.add_function(ATanhFunction).unwrap();
+        context.add_function(ACschFunction).unwrap();
+        context.add_function(ASechFunction).unwrap();
+        context.add_function(ACothFunction).unwrap();
+
+        if is_float::<N>() {
+            for name in ["&", "|"] {
+                context.add_binary_function(UnsupportedOperator::new(name, "floating point numbers", Precedence::LOW)).unwrap();
+            }
+        }
+        context
+    }
+}
+
+/// Checks if the numeric type can represent fractional values, eg: `f64` but not `i64`.
+#[inline]
+fn is_float<N: FromPrimitive + Zero>() -> bool {
+    N::from_f64(0.5).is_some_and(|n| !n.is_zero())
+}
+
+/// Represents the configuration used by a `Context`.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct Config {
+    /// Allows implicit multiplication, enables all the `implicit_mul_*` cases.
+    pub implicit_mul: bool,
+    /// Allows implicit multiplication between a number and a name, eg: `2x`, `2PI`, `2Max(1, 2)`.
+    pub implicit_mul_number_name: bool,
+    /// Allows implicit multiplication between a number and a grouping symbol, eg: `2(4)`, `(4)2`, `Tan(45)2`.
+    pub implicit_mul_number_group: bool,
+    /// Allows implicit multiplication between grouping symbols, eg: `(2)(4)`.
+    pub implicit_mul_group_group: bool,
+    /// Allows implicit multiplication between a grouping symbol and a name, eg: `(2)x`, `Sin(30)Cos(30)`.
+    pub implicit_mul_group_name: bool,
+    /// Allows implicit multiplication between numbers separated by whitespaces, eg: `3 2`,
+    /// this is not enabled by `implicit_mul`.
+    pub implicit_mul_number_number: bool,
+    /// Allows complex numbers.
+    pub complex_number: bool,
+    /// Allows using custom grouping symbols for function calls, eg: `Max[1,2,3]`, `Sum{2,4,6}`
+    pub custom_function_call: bool,
+    /// Rounds half-way values away from zero, by default half-way values are rounded
+    /// to the nearest even number (banker's rounding).
+    pub round_half_away_from_zero: bool,
+    /// Evaluates the binary operators from left to right ignoring its precedence.
+    pub left_to_right: bool,
+    /// Allows non-finite float results following IEEE 754, eg: `1/0 = inf`.
+    pub float_ieee: bool,
+    /// Requires the functions to be followed by a grouping symbol, eg: `random()` instead of `random`.
+    pub strict_function_call: bool,
+    /// Evaluates the valid part of an expression before an unknown token, eg: `2 + 2 xyz` is `4`.
+    pub lenient_trailing: bool,
+    /// Looks up the names of constants and functions with the exact case, eg: `sin` but not `SIN`.
+    pub case_sensitive: bool,
+    /// The separator used for the thousands when formatting a result, eg: `1,000,000`.
+    pub thousands_output_separator: Option<char>,
+    /// The max number of operators and function calls evaluated in an expression.
+    pub max_steps: Option<usize>,
+    /// The max number of arguments that can be passed to a function.
+    pub max_args: Option<usize>,
+    /// The max number of characters of an expression.
+    pub max_expression_length: Option<usize>,
+    /// The max number of iterations of the decimal square root, by default it iterates
+    /// until the result converges, see `DecimalExt::checked_sqrt_prec`.
+    pub decimal_sqrt_iterations: Option<u32>,
+    /// The separator of the function arguments, by default `,`.
+    arg_separator: Option<char>,
+    /// Stores the grouping symbols as: `(`, `)`, `[`, `]`.
+    grouping: HashSet<Grouping>,
+    /// The names of the units that can follow a number, eg: `km` in `5km`.
+    units: HashSet<String>,
+    /// The characters that can join the parts of a function name, eg: `.` in `math.sin`.
+    function_name_chars: HashSet<char>,
+    /// The names of the functions disabled in the contexts using this config.
+    disabled_functions: HashSet<String>,
+}
+
+impl Config {
+    /// Constructs a new `Config` using the default grouping symbol: `(`, `)`,
+    /// if is need an empty `Config` use `Default` instead.
+    #[inline]
+    pub fn new() -> Self {
+        Config::default().with_grouping(Grouping::Parenthesis)
+    }
+
+    /// Constructs a `Config` for a scientific calculator, it allows implicit multiplication,
+    /// eg: `2PI`, `3(4)`, and grouping with parentheses and brackets.
+    #[inline]
+    pub fn scientific() -> Self {
+        Config::new()
+            .with_grouping(Grouping::Bracket)
+            .with_implicit_mul(true)
+    }
+
+    /// Constructs a `Config` for a programmer calculator, it allows grouping with parentheses,
+    /// brackets and braces but not implicit multiplication, so names are never split, eg: `2ab`.
+    #[inline]
+    pub fn programmer() -> Self {
+        Config::new()
+            .with_grouping(Grouping::Bracket)
+            .with_grouping(Grouping::Brace)
+    }
+
+    /// Constructs a `Config` for a simple calculator, where the operators are evaluated
+    /// from left to right, eg: `2 + 3 * 4 = 20`, see [`with_left_to_right`].
+    ///
+    /// [`with_left_to_right`]: #method.with_left_to_right
+    #[inline]
+    pub fn simple() -> Self {
+        Config::new().with_left_to_right(true)
+    }
+
+    /// Constructs a `Config` for evaluating untrusted expressions, eg: from a public server.
+    ///
+    /// It uses the grouping symbol `(`, `)` and the following limits:
+    /// - `max_steps`: `1000` operators and function calls.
+    /// - `max_args`: `100` arguments per function call.
+    /// - `max_expression_length`: `1000` characters.
+    /// - The `random` function is disabled, so the results are reproducible.
+    ///
+    /// # Example
+    /// ```
+    /// use prexel::context::{Config, DefaultContext};
+    /// use prexel::evaluator::Evaluator;
+    ///
+    /// let context = DefaultContext::with_config_checked(Config::public_safe());
+    /// let evaluator : Evaluator<i64> = Evaluator::with_context(context);
+    /// assert_eq!(evaluator.eval("2 + 3"), Ok(5));
+    /// assert!(evaluator.eval("random()").is_err());
+    /// ```
+    #[inline]
+    pub fn public_safe() -> Self {
+        Config::new()
+            .with_max_steps(1000)
+            .with_max_args(100)
+            .with_max_expression_length(1000)
+            .with_disabled_function("random")
+    }
+
+    /// Enables implicit multiplication for this `Config`.
+    ///
+    /// # Remarks
+    /// This enables all the implicit multiplication cases, to enable only some of them use
+    /// `with_implicit_mul_number_name`, `with_implicit_mul_number_group`,
+    /// `with_implicit_mul_group_group` or `with_implicit_mul_group_name` instead.
+    #[inline]
+    pub fn with_implicit_mul(mut self, enable: bool) -> Config {
+        self.implicit_mul = enable;
+        self
+    }
+
+    /// Enables implicit multiplication between a number and a name, eg: `2x`, `2PI`, `2Max(1, 2)`.
+    #[inline]
+    pub fn with_implicit_mul_number_name(mut self, enable: bool) -> Config {
+        self.implicit_mul_number_name = enable;
+        self
+    }
+
+    /// Enables implicit multiplication between a number and a grouping symbol, eg: `2(4)`, `(4)2`.
+    #[inline]
+    pub fn with_implicit_mul_number_group(mut self, enable: bool) -> Config {
+        self.implicit_mul_number_group = enable;
+        self
+    }
+
+    /// Enables implicit multiplication between grouping symbols, eg: `(2)(4)`.
+    #[inline]
+    pub fn with_implicit_mul_group_group(mut self, enable: bool) -> Config {
+        self.implicit_mul_group_group = enable;
+        self
+    }
+
+    /// Enables implicit multiplication between a grouping symbol and a name, eg: `(2)x`, `Sin(30)Cos(30)`.
+    #[inline]
+    pub fn with_implicit_mul_group_name(mut self, enable: bool) -> Config {
+        self.implicit_mul_group_name = enable;
+        self
+    }
+
+    /// Enables implicit multiplication between numbers separated by whitespaces, eg: `3 2` is `3 * 2`,
+    /// used for calculator-style input.
+    ///
+    /// # Remarks
+    /// This is not enabled by `with_implicit_mul`, so an expression like `3 2Sin(50)` is still
+    /// an error unless this is enabled.
+    ///
+    /// # Example
+    /// ```
+    /// use prexel::context::{Config, DefaultContext};
+    /// use prexel::evaluator::Evaluator;
+    ///
+    /// let config = Config::new().with_implicit_mul_number_number(true);
+    /// let evaluator : Evaluator<i64> = Evaluator::with_context(DefaultContext::with_config_checked(config));
+    /// assert_eq!(evaluator.eval("3 2"), Ok(6));
+    /// ```
+    #[inline]
+    pub fn with_implicit_mul_number_number(mut self, enable: bool) -> Config {
+        self.implicit_mul_number_number = enable;
+        self
+    }
+
+    /// Enables complex number usage for this `Config`.
+    ///
+    /// # Remarks
+    /// [`Tokenizer`] checks for this value when parsing expressions.
+    ///
+    /// [`Tokenizer`]: ../tokenizer/struct.Tokenizer.html
+    #[inline]
+    pub fn with_complex_number(mut self, enable: bool) -> Config {
+        self.complex_number = enable;
+        self
+    }
+
+    /// Enables custom function calls groping symbols.
+    ///
+    /// # Remarks
+    /// Function calls are only allowed within parentheses, eg: `Product(3, 6, 6)`,
+    /// but `with_custom_function_call` allow to use others, eg: `Max[1,2,3]`, `Sum<2,4,6>`.
+    #[inline]
+    pub fn with_custom_function_call(mut self, enable: bool) -> Config {
+        self.custom_function_call = enable;
+        self
+    }
+
+    /// Enables rounding half-way values away from zero for this `Config`, eg: `round(2.5) = 3`
+    /// and `round(-2.5) = -3`.
+    ///
+    /// # Remarks
+    /// By default half-way values are rounded to the nearest even number (banker's rounding),
+    /// eg: `round(2.5) = 2` and `round(3.5) = 4`. Currently only the decimal context checks for this value.
+    #[inline]
+    pub fn with_round_half_away_from_zero(mut self, enable: bool) -> Config {
+        self.round_half_away_from_zero = enable;
+        self
+    }
+
+    /// Enables evaluating the binary operators strictly from left to right, as a simple calculator.
+    ///
+    /// # Remarks
+    /// All the binary operators are treated as having the same precedence and left associativity,
+    /// so `2 + 3 * 4` results in `20` instead of `14`, grouping symbols still can be used to change the order.
+    #[inline]
+    pub fn with_left_to_right(mut self, enable: bool) -> Config {
+        self.left_to_right = enable;
+        self
+    }
+
+    /// Enables IEEE 754 semantics for floats, where non-finite values are returned
+    /// instead of an error, eg: `1/0 = inf`, `0/0 = NaN` and `ln(0) = -inf`.
+    ///
+    /// # Remarks
+    /// The non-finite values are also accepted by `^`, `sqrt`, `exp`, `ln` and the logarithms,
+    /// eg: `exp(1/0) = inf`, other functions still fails with a non-finite argument.
+    /// Types that cannot represent the result still returns an error, eg: `1/0` for integers.
+    /// Currently only the checked context checks for this value.
+    #[inline]
+    pub fn with_float_ieee(mut self, enable: bool) -> Config {
+        self.float_ieee = enable;
+        self
+    }
+
+    /// Requires all the functions to be followed by a grouping symbol, so a function name
+    /// can't be confused with a variable, eg: `random` is an error and `random()` is not.
+    #[inline]
+    pub fn with_strict_function_call(mut self, enable: bool) -> Config {
+        self.strict_function_call = enable;
+        self
+    }
+
+    /// Evaluates the part of an expression before the first unknown token ignoring the rest,
+    /// eg: `2 + 2 xyz` is `4`, otherwise the unknown token is an error with its position.
+    ///
+    /// # Example
+    /// ```
+    /// use prexel::context::{Config, DefaultContext};
+    /// use prexel::evaluator::Evaluator;
+    ///
+    /// let config = Config::new().with_lenient_trailing(true);
+    /// let evaluator : Evaluator<i64> = Evaluator::with_context(DefaultContext::with_config_checked(config));
+    /// assert_eq!(evaluator.eval("2 + 2 xyz"), Ok(4));
+    /// ```
+    #[inline]
+    pub fn with_lenient_trailing(mut self, enable: bool) -> Config {
+        self.lenient_trailing = enable;
+        self
+    }
+
+    /// Looks up the names of constants and functions with the exact case they were added,
+    /// so a variable `n` and a constant `N` can be used in the same context.
+    ///
+    /// # Remarks
+    /// Names that only differ in case still cannot be added as the same kind,
+    /// eg: the functions `foo` and `Foo`.
+    ///
+    /// # Example
+    /// ```
+    /// use prexel::context::{Config, Context, DefaultContext};
+    /// use prexel::evaluator::Evaluator;
+    ///
+    /// let mut context = DefaultContext::with_config_checked(Config::new().with_case_sensitive(true));
+    /// context.add_constant("N", 10).unwrap();
+    /// context.set_variable("n", 2).unwrap();
+    ///
+    /// let evaluator : Evaluator<i64> = Evaluator::with_context(context);
+    /// assert_eq!(evaluator.eval("N * n + max(1, 2)"), Ok(22));
+    /// assert!(evaluator.eval("MAX(1, 2)").is_err());
+    /// ```
+    #[inline]
+    pub fn with_case_sensitive(mut self, enable: bool) -> Config {
+        self.case_sensitive = enable;
+        self
+    }
+
+    /// Sets the max number of operators and function calls evaluated in an expression,
+    /// including the ones in the arguments of functions like `if` that are evaluated.
+    #[inline]
+    pub fn with_max_steps(mut self, max_steps: usize) -> Config {
+        self.max_steps = Some(max_steps);
+        self
+    }
+
+    /// Sets the max number of arguments that can be passed to a function, eg: `sum(1, 2, 3)`.
+    #[inline]
+    pub fn with_max_args(mut self, max_args: usize) -> Config {
+        self.max_args = Some(max_args);
+        self
+    }
+
+    /// Sets the max number of characters of an expression, longer expressions are an error.
+    #[inline]
+    pub fn with_max_expression_length(mut self, max_length: usize) -> Config {
+        self.max_expression_length = Some(max_length);
+        self
+    }
+
+    /// Sets the max number of Newton iterations used by the `sqrt` of the decimal context,
+    /// fewer iterations are faster but less precise, `0` has the precision of a `f64`.
+    ///
+    /// # Remarks
+    /// By default it iterates until the result converges to the full precision of a `Decimal`.
+    #[inline]
+    pub fn with_decimal_sqrt_iterations(mut self, iterations: u32) -> Config {
+        self.decimal_sqrt_iterations = Some(iterations);
+        self
+    }
+
+    /// Disables the function with the given name, which returns an error when called
+    /// using its name or any of its aliases.
+    ///
+    /// # Remarks
+    /// The function is disabled when is added to a `DefaultContext` using this config,
+    /// as [`DefaultContext::set_function_enabled`] does, so it can be enabled again.
+    /// The name is compared using the case sensitivity of this config.
+    ///
+    /// [`DefaultContext::set_function_enabled`]: struct.DefaultContext.html#method.set_function_enabled
+    #[inline]
+    pub fn with_disabled_function(mut self, name: &str) -> Config {
+        self.disabled_functions.insert(name.to_string());
+        self
+    }
+
+    /// Checks if the function with the given name was disabled with [`with_disabled_function`].
+    ///
+    /// [`with_disabled_function`]: #method.with_disabled_function
+    #[inline]
+    pub fn is_function_disabled(&self, name: &str) -> bool {
+        if self.case_sensitive {
+            self.disabled_functions.contains(name)
+        } else {
+            self.disabled_functions.iter().any(|s| eq_ignore_case(s, name))
+        }
+    }
+
+    /// Sets the separator used for the thousands when formatting a result with [`format_output`].
+    ///
+    /// [`format_output`]: #method.format_output
+    #[inline]
+    pub fn with_thousands_output_separator(mut self, separator: char) -> Config {
+        self.thousands_output_separator = Some(separator);
+        self
+    }
+
+    /// Formats the given result using this `Config`, the value itself is not modified.
     ///
-    /// # Remarks
-    /// Functions of this context may panic when the value overflows.
-    pub fn with_config_unchecked(config: Config) -> Self {
-        use crate::ops::unchecked::*;
-
-        let mut context = Self::with_config(config);
-        context.add_constant("PI", N::from_f64(std::f64::consts::PI).unwrap()).unwrap();
-        context.add_constant("E", N::from_f64(std::f64::consts::E).unwrap()).unwrap();
-        context.add_binary_function(AddOperator).unwrap();
-        context.add_binary_function(SubOperator).unwrap();
-        context.add_binary_function(MulOperator).unwrap();
-        context.add_binary_function(DivOperator).unwrap();
-        context.add_binary_function(PowOperator).unwrap();
-        context.add_binary_function(ModOperator).unwrap();
-        context.add_unary_function(UnaryPlus).unwrap();
-        context.add_unary_function(UnaryMinus).unwrap();
-        context.add_unary_function(Factorial).unwrap();
-        context.add_function(SumFunction).unwrap();
-        context.add_function(AvgFunction).unwrap();
-        context.add_function(ProdFunction).unwrap();
-        context.add_function(MaxFunction).unwrap();
-        context.add_function(MinFunction).unwrap();
-        context.add_function(SqrtFunction).unwrap();
-        context.add_function(LnFunction).unwrap();
-        context.add_function(LogFunction).unwrap();
-        context.add_function(RandFunction).unwrap();
-        context.add_function(ToRadiansFunction).unwrap();
-        context.add_function(ToDegreesFunction).unwrap();
-        context.add_function(ExpFunction).unwrap();
-        context.add_function(SinFunction).unwrap();
-        context.add_function(CosFunction).unwrap();
-        context.add_function(TanFunction).unwrap();
-        context.add_function(CscFunction).unwrap();
-        context.add_function(SecFunction).unwrap();
-        context.add_function(CotFunction).unwrap();
-        context.add_function(ASinFunction).unwrap();
-        context.add_function(ACosFunction).unwrap();
-        context.add_function(ATanFunction).unwrap();
-        context.add_function(ACscFunction).unwrap();
-        context.add_function(ASecFunction).unwrap();
-        context.add_function(ACotFunction).unwrap();
-        context.add_function(SinhFunction).unwrap();
-        context.add_function(CoshFunction).unwrap();
-        context.add_function(TanhFunction).unwrap();
-        context.add_function(CschFunction).unwrap();
-        context.add_function(SechFunction).unwrap();
-        context.add_function(CothFunction).unwrap();
-        context.add_function(ASinhFunction).unwrap();
-        context.add_function(ACoshFunction).unwrap();
-        context.add_function(ATanhFunction).unwrap();
-        context.add_function(ACschFunction).unwrap();
-        context.add_function(ASechFunction).unwrap();
-        context.add_function(ACothFunction).unwrap();
-        context
+    /// # Example
+    /// ```
+    /// use prexel::context::Config;
+    ///
+    /// let config = Config::new().with_thousands_output_separator(',');
+    /// assert_eq!(config.format_output(&1234567.89_f64), "1,234,567.89");
+    /// assert_eq!(Config::new().format_output(&1234567.89_f64), "1234567.89");
+    /// ```
+    pub fn format_output<T: Display>(&self, value: &T) -> String {
+        match self.thousands_output_separator {
+            Some(separator) => group_thousands(&value.to_string(), separator),
+            None => value.to_string(),
+        }
     }
-}
-
-/// Represents the configuration used by a `Context`.
-#[derive(Debug, Default, Clone, Eq, PartialEq)]
-pub struct Config {
-    /// Allows implicit multiplication.
-    pub implicit_mul: bool,
-    /// Allows complex numbers.
-    pub complex_number: bool,
-    /// Allows using custom grouping symbols for function calls, eg: `Max[1,2,3]`, `Sum{2,4,6}`
-    pub custom_function_call: bool,
-    /// Stores the grouping symbols as: `(`, `)`, `[`, `]`.
-    grouping: HashSet<Grouping>,
-}
 
-impl Config {
-    /// Constructs a new `Config` using the default grouping symbol: `(`, `)`,
-    /// if is need an empty `Config` use `Default` instead.
+    /// Sets the separator of the function arguments, by default `,`.
+    ///
+    /// # Example
+    /// ```
+    /// use prexel::context::{Config, DefaultContext};
+    /// use prexel::evaluator::Evaluator;
+    ///
+    /// let config = Config::new().with_arg_separator(';');
+    /// let evaluator : Evaluator<i64> = Evaluator::with_context(DefaultContext::with_config_checked(config));
+    /// assert_eq!(evaluator.eval("Max(1; 2; 3)"), Ok(3));
+    /// ```
     #[inline]
-    pub fn new() -> Self {
-        Config::default().with_grouping(Grouping::Parenthesis)
+    pub fn with_arg_separator(mut self, separator: char) -> Config {
+        self.arg_separator = Some(separator);
+        self
     }
 
-    /// Enables implicit multiplication for this `Config`.
+    /// Gets the separator of the function arguments.
     #[inline]
-    pub fn with_implicit_mul(mut self, enable: bool) -> Config {
-        self.implicit_mul = enable;
-        self
+    pub fn arg_separator(&self) -> char {
+        self.arg_separator.unwrap_or(',')
     }
 
-    /// Enables complex number usage for this `Config`.
+    /// Adds the name of a unit that can follow a number, eg: `km` in `5km`.
     ///
     /// # Remarks
-    /// [`Tokenizer`] checks for this value when parsing expressions.
+    /// The [`Tokenizer`] converts a number followed by an unit into a `Token::NumberWithUnit`,
+    /// those tokens cannot be evaluated so should be converted before, eg: to a `Token::Number`.
     ///
     /// [`Tokenizer`]: ../tokenizer/struct.Tokenizer.html
     #[inline]
-    pub fn with_complex_number(mut self, enable: bool) -> Config {
-        self.complex_number = enable;
+    pub fn with_unit(mut self, unit: &str) -> Config {
+        self.units.insert(unit.to_string());
         self
     }
 
-    /// Enables custom function calls groping symbols.
+    /// Checks if the given name is an unit of this `Config`.
+    #[inline]
+    pub fn is_unit(&self, name: &str) -> bool {
+        self.units.contains(name)
+    }
+
+    /// Allows the given characters to join the parts of a function name, eg: `.` for `math.sin`.
     ///
     /// # Remarks
-    /// Function calls are only allowed within parentheses, eg: `Product(3, 6, 6)`,
-    /// but `with_custom_function_call` allow to use others, eg: `Max[1,2,3]`, `Sum<2,4,6>`.
+    /// The parts are only joined if there is no whitespace between them and the resulting
+    /// name is a function of the context, so `math . sin` is not read as `math.sin`.
+    ///
+    /// # Example
+    /// ```
+    /// use prexel::context::Config;
+    ///
+    /// let config = Config::new().with_function_name_chars(&['.']);
+    /// assert!(config.is_function_name_char('.'));
+    /// assert!(!config.is_function_name_char(':'));
+    /// ```
     #[inline]
-    pub fn with_custom_function_call(mut self, enable: bool) -> Config {
-        self.custom_function_call = enable;
+    pub fn with_function_name_chars(mut self, chars: &[char]) -> Config {
+        self.function_name_chars.extend(chars);
         self
     }
 
+    /// Checks if the given `char` can join the parts of a function name.
+    #[inline]
+    pub fn is_function_name_char(&self, c: char) -> bool {
+        self.function_name_chars.contains(&c)
+    }
+
     /// Adds a pair of grouping symbols to this `Config`.
     ///
+    /// # Remarks
+    /// The grouping symbols are used exclusively for grouping, so adding an operator with
+    /// one of these names to a context using this `Config` fails,
+    /// eg: `Grouping::Angle` conflicts with the comparison operators `<` and `>`,
+    /// those are not included in the default contexts using this `Config`.
+    ///
     /// # Example
     /// ```
     /// use prexel::context::{Config, Grouping};
@@ -583,7 +1980,24 @@ impl Config {
     /// ```
     #[inline]
     pub fn get_group_symbol(&self, symbol: char) -> Option<(char, char)> {
-        Grouping::new(symbol).map(|g| g.symbols())
+        self.get_grouping(symbol).map(|g| g.symbols())
+    }
+
+    /// Gets the `Grouping` of the given symbol, angle brackets are only a grouping
+    /// if `Grouping::Angle` is in this `Config`.
+    #[inline]
+    fn get_grouping(&self, symbol: char) -> Option<Grouping> {
+        Grouping::new(symbol).filter(|g| *g != Grouping::Angle || self.grouping.contains(g))
+    }
+
+    /// Checks if the given name is a grouping symbol of this `Config`, eg: `(` or `)`.
+    #[inline]
+    pub fn is_grouping_symbol(&self, name: &str) -> bool {
+        let mut chars = name.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => self.get_grouping(c).is_some(),
+            _ => false,
+        }
     }
 
     /// Gets the grouping close for the specified grouping open.
@@ -602,9 +2016,8 @@ impl Config {
     /// ```
     #[inline]
     pub fn get_group_open_for(&self, group_close: char) -> Option<char> {
-        Grouping::new(group_close)
-            .map(|g| g.open_for(group_close))
-            .flatten()
+        self.get_grouping(group_close)
+            .and_then(|g| g.open_for(group_close))
     }
 
     /// Gets the grouping close for the specified grouping open.
@@ -623,15 +2036,14 @@ impl Config {
     /// ```
     #[inline]
     pub fn get_group_close_for(&self, group_open: char) -> Option<char> {
-        Grouping::new(group_open)
-            .map(|g| g.close_for(group_open))
-            .flatten()
+        self.get_grouping(group_open)
+            .and_then(|g| g.close_for(group_open))
     }
 
     /// Checks a value indicating if the given `char` is a group close symbol.
     #[inline]
     pub fn is_group_close(&self, group_close: char) -> bool {
-        Grouping::new(group_close)
+        self.get_grouping(group_close)
             .map(|g| g.is_close(group_close))
             .unwrap_or(false)
     }
@@ -639,12 +2051,29 @@ impl Config {
     /// Checks a value indicating if the given `char` is a group open symbol.
     #[inline]
     pub fn is_group_open(&self, group_open: char) -> bool {
-        Grouping::new(group_open)
+        self.get_grouping(group_open)
             .map(|g| g.is_open(group_open))
             .unwrap_or(false)
     }
 }
 
+/// How the variables not found in a context are evaluated,
+/// see `DefaultContext::with_unknown_variable_policy`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnknownVariablePolicy<N> {
+    /// An unknown variable is an error.
+    Error,
+    /// An unknown variable evaluates to the given value.
+    DefaultTo(N),
+}
+
+impl<N> Default for UnknownVariablePolicy<N> {
+    #[inline]
+    fn default() -> Self {
+        UnknownVariablePolicy::Error
+    }
+}
+
 /// Represents a grouping symbol pair.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Grouping {
@@ -654,6 +2083,9 @@ pub enum Grouping {
     Bracket,
     /// Grouping using curly braces: `{` and `}`.
     Brace,
+    /// Grouping using angle brackets: `<` and `>`, unlike the others is only used
+    /// if is added to the `Config`, so `<` and `>` can be operators by default.
+    Angle,
 }
 
 impl Grouping {
@@ -663,6 +2095,7 @@ impl Grouping {
             '(' | ')' => Some(Grouping::Parenthesis),
             '[' | ']' => Some(Grouping::Bracket),
             '{' | '}' => Some(Grouping::Brace),
+            '<' | '>' => Some(Grouping::Angle),
             _ => None,
         }
     }
@@ -677,6 +2110,7 @@ impl Grouping {
             Grouping::Parenthesis => '(',
             Grouping::Bracket => '[',
             Grouping::Brace => '{',
+            Grouping::Angle => '<',
         }
     }
 
@@ -685,6 +2119,7 @@ impl Grouping {
             Grouping::Parenthesis => ')',
             Grouping::Bracket => ']',
             Grouping::Brace => '}',
+            Grouping::Angle => '>',
         }
     }
 
@@ -860,16 +2295,229 @@ mod tests {
         assert!(context.get_function("min").is_some());
     }
 
+    #[test]
+    fn single_char_operators_lookup_test() {
+        let mut context: DefaultContext<f64> = DefaultContext::new_checked();
+        context.add_binary_function(Dummy("x".to_string())).unwrap();
+        context.add_unary_function(Dummy("√".to_string())).unwrap();
+
+        for (name, f) in context.binary_functions() {
+            let other = context.get_binary_function(name.as_str()).unwrap();
+            assert!(Rc::ptr_eq(f, other), "binary function `{}`", name);
+        }
+
+        for (name, f) in context.unary_functions() {
+            let other = context.get_unary_function(name.as_str()).unwrap();
+            assert!(Rc::ptr_eq(f, other), "unary function `{}`", name);
+        }
+
+        assert!(context.is_binary_function("x"));
+        assert!(context.is_binary_function("X"));
+        assert!(context.is_binary_function("mod"));
+        assert!(context.is_unary_function("!"));
+        assert!(context.is_unary_function("√"));
+
+        assert!(!context.is_binary_function("@"));
+        assert!(!context.is_binary_function("y"));
+        assert!(!context.is_unary_function("~"));
+    }
+
+    #[test]
+    fn single_char_operators_eval_test() {
+        use crate::evaluator::Evaluator;
+        use crate::ops::unchecked::MulOperator;
+
+        let mut context: DefaultContext<f64> = DefaultContext::new_unchecked();
+        context.add_binary_function_as(MulOperator, "x").unwrap();
+
+        let evaluator = Evaluator::with_context(context);
+        assert_eq!(evaluator.eval("2 + 3 * 4 - 8 / 2 ^ 2"), Ok(12_f64));
+        assert_eq!(evaluator.eval("-3 + 2!"), Ok(-1_f64));
+        assert_eq!(evaluator.eval("10 mod 4"), Ok(2_f64));
+        assert_eq!(evaluator.eval("3 x 4"), Ok(12_f64));
+        assert_eq!(evaluator.eval("3 X 4"), Ok(12_f64));
+    }
+
+    #[test]
+    fn disable_function_test() {
+        use crate::evaluator::Evaluator;
+
+        let mut context: DefaultContext<f64> = DefaultContext::new_checked();
+        context.set_function_enabled("random", false).unwrap();
+        assert!(!context.is_function_enabled("random"));
+        assert!(context.is_function_enabled("max"));
+        assert!(context.set_function_enabled("unknown", false).is_err());
+
+        let mut evaluator = Evaluator::with_context(context);
+        let error = evaluator.eval("random()").unwrap_err();
+        assert_eq!(error.to_string(), "Function `random` is disabled");
+        assert_eq!(evaluator.eval("Random(1, 1)").unwrap_err().kind(), ErrorKind::InvalidInput);
+        assert_eq!(evaluator.eval("max(1, 3) + sqrt(4)"), Ok(5_f64));
+
+        evaluator.mut_context().set_function_enabled("RANDOM", true).unwrap();
+        assert!(evaluator.context().is_function_enabled("random"));
+        assert!(evaluator.eval("random()").is_ok());
+    }
+
+    #[test]
+    fn config_disabled_function_test() {
+        use crate::evaluator::Evaluator;
+
+        let config = Config::new().with_disabled_function("Random");
+        let mut context: DefaultContext<f64> = DefaultContext::with_config_checked(config);
+        assert!(!context.is_function_enabled("random"));
+        assert!(!context.functions_with_arity(1).contains(&"random"));
+
+        context.set_function_enabled("random", true).unwrap();
+        assert!(context.is_function_enabled("random"));
+
+        let config = Config::new().with_case_sensitive(true).with_disabled_function("Random");
+        let context: DefaultContext<f64> = DefaultContext::with_config_checked(config);
+        assert!(context.is_function_enabled("random"));
+
+        let config = Config::new().with_case_sensitive(true).with_disabled_function("random");
+        let evaluator = Evaluator::with_context(DefaultContext::<f64>::with_config_checked(config));
+        let error = evaluator.eval("random()").unwrap_err();
+        assert_eq!(error.to_string(), "Function `random` is disabled");
+    }
+
+    #[test]
+    fn functions_with_arity_test() {
+        let mut context: DefaultContext<f64> = DefaultContext::new_checked();
+
+        let unary = context.functions_with_arity(1);
+        assert!(unary.contains(&"sin"));
+        assert!(unary.contains(&"log"));
+        assert!(unary.contains(&"random"));
+        assert!(!unary.contains(&"max"));
+
+        let binary = context.functions_with_arity(2);
+        assert!(binary.contains(&"log"));
+        assert!(binary.contains(&"max"));
+        assert!(!binary.contains(&"sin"));
+
+        assert!(context.functions_with_arity(3).contains(&"max"));
+        assert!(context.function_names().contains(&"sin"));
+
+        context.set_function_enabled("log", false).unwrap();
+        assert!(!context.functions_with_arity(1).contains(&"log"));
+    }
+
+    #[test]
+    fn export_import_state_test() {
+        use crate::evaluator::Evaluator;
+
+        let mut context: DefaultContext<f64> = DefaultContext::new_checked();
+        context.set_variable("x", 10_f64).unwrap();
+        context.set_variable("y", 3_f64).unwrap();
+        context.add_constant("G", 9.8_f64).unwrap();
+
+        let state = context.export_state();
+        assert_eq!(state.variables.get("x"), Some(&10_f64));
+        assert_eq!(state.constants.get("G"), Some(&9.8_f64));
+
+        // A new context doesn't contains the values
+        let evaluator = Evaluator::with_context(DefaultContext::<f64>::new_checked());
+        assert!(evaluator.eval("x * y + G").is_err());
+
+        let mut context: DefaultContext<f64> = DefaultContext::new_checked();
+        context.import_state(state).unwrap();
+
+        let evaluator = Evaluator::with_context(context);
+        assert_eq!(evaluator.eval("x * y + G"), Ok(39.8_f64));
+        assert_eq!(evaluator.eval("PI"), Ok(std::f64::consts::PI));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_state_test() {
+        let mut context: DefaultContext<f64> = DefaultContext::new();
+        context.set_variable("x", 10_f64).unwrap();
+        context.add_constant("G", 9.8_f64).unwrap();
+
+        let json = serde_json::to_string(&context.export_state()).unwrap();
+        assert_eq!(json, r#"{"variables":{"x":10.0},"constants":{"G":9.8}}"#);
+
+        let state: ContextState<f64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(state, context.export_state());
+    }
+
+    #[test]
+    fn format_output_test() {
+        let config = Config::new().with_thousands_output_separator(',');
+        assert_eq!(config.format_output(&1000000), "1,000,000");
+        assert_eq!(config.format_output(&1234567.89_f64), "1,234,567.89");
+        assert_eq!(config.format_output(&-100), "-100");
+
+        assert_eq!(Config::new().format_output(&1000000), "1000000");
+    }
+
+    #[test]
+    fn fluent_registration_test() -> Result<()> {
+        use crate::evaluator::Evaluator;
+        use crate::ops::checked::{AddOperator, MulOperator, UnaryMinus};
+        use crate::ops::math::MaxFunction;
+
+        let context: DefaultContext<i64> = DefaultContext::new()
+            .with_binary_function(AddOperator)?
+            .with_binary_function(MulOperator)?
+            .with_unary_function(UnaryMinus)?
+            .with_function(MaxFunction)?
+            .with_constant("TEN", 10)?
+            .with_variable("x", 3)?;
+
+        let evaluator = Evaluator::with_context(context);
+        assert_eq!(evaluator.eval("Max(x, 2) * TEN + 1"), Ok(31));
+        assert_eq!(evaluator.eval("-x"), Ok(-3));
+        assert!(evaluator.eval("x - 1").is_err());
+
+        // Errors are returned instead of the context
+        let result = DefaultContext::<i64>::new()
+            .with_variable("x", 1)?
+            .with_constant("x", 2);
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn config_presets_test() {
+        use crate::evaluator::Evaluator;
+
+        let config = Config::scientific();
+        assert!(config.implicit_mul);
+        assert!(config.get_group_symbol('(').is_some());
+        assert!(config.get_group_symbol('[').is_some());
+        assert!(!config.left_to_right);
+
+        let config = Config::programmer();
+        assert!(!config.implicit_mul);
+        assert!(!config.left_to_right);
+
+        let config = Config::simple();
+        assert!(!config.implicit_mul);
+        assert!(config.left_to_right);
+
+        let evaluator = Evaluator::with_context(DefaultContext::with_config_checked(Config::scientific()));
+        assert_eq!(evaluator.eval("2[3 + 1](2)"), Ok(16_i64));
+
+        let evaluator = Evaluator::with_context(DefaultContext::with_config_checked(Config::simple()));
+        assert_eq!(evaluator.eval("2 + 3 * 4"), Ok(20_i64));
+    }
+
     #[test]
     fn config_test() {
         let config = Config::default()
             .with_grouping(Grouping::Parenthesis)
-            .with_grouping(Grouping::Bracket);
+            .with_grouping(Grouping::Bracket)
+            .with_grouping(Grouping::Brace);
 
         assert_eq!(config.get_group_symbol('(').unwrap(), ('(', ')'));
         assert_eq!(config.get_group_symbol(')').unwrap(), ('(', ')'));
         assert_eq!(config.get_group_symbol('[').unwrap(), ('[', ']'));
         assert_eq!(config.get_group_symbol(']').unwrap(), ('[', ']'));
+        assert_eq!(config.get_group_symbol('{').unwrap(), ('{', '}'));
+        assert_eq!(config.get_group_symbol('}').unwrap(), ('{', '}'));
     }
 
     #[test]
@@ -921,6 +2569,81 @@ mod tests {
         assert!(context.get_function("∑").is_some());
     }
 
+    #[test]
+    fn unsupported_bitwise_test() {
+        use crate::evaluator::Evaluator;
+
+        let evaluator: Evaluator<f64> = Evaluator::with_context(DefaultContext::new_checked());
+        let error = evaluator.eval("6 & 3").unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::Unsupported);
+        assert_eq!(error.to_string(), "`&` is not supported for floating point numbers");
+
+        let evaluator: Evaluator<f64> = Evaluator::with_context(DefaultContext::new_unchecked());
+        assert_eq!(evaluator.eval("6 | 3").unwrap_err().kind(), ErrorKind::Unsupported);
+
+        let context: DefaultContext<i64> = DefaultContext::new_checked();
+        assert!(!context.is_binary_function("&"));
+    }
+
+    #[test]
+    fn add_functions_test() {
+        use crate::evaluator::Evaluator;
+
+        let functions: Vec<(String, Box<dyn Function<f64>>)> = vec![
+            ("largest".to_string(), Box::new(MaxFunction)),
+            ("smallest".to_string(), Box::new(MinFunction)),
+            ("total".to_string(), Box::new(crate::ops::checked::SumFunction)),
+        ];
+
+        let mut context: DefaultContext<f64> = DefaultContext::new_checked();
+        context.add_functions(functions).unwrap();
+
+        let evaluator = Evaluator::with_context(context);
+        assert_eq!(evaluator.eval("total(largest(1, 5), smallest(2, 3), 10)"), Ok(17_f64));
+
+        let mut context: DefaultContext<f64> = DefaultContext::new_checked();
+        let functions: Vec<(String, Box<dyn Function<f64>>)> = vec![("max".to_string(), Box::new(MaxFunction))];
+        assert!(context.add_functions(functions).is_err());
+    }
+
+    #[test]
+    fn function_overload_test() {
+        use crate::evaluator::Evaluator;
+
+        struct Foo(Arity);
+        impl Function<f64> for Foo {
+            fn name(&self) -> &str {
+                "foo"
+            }
+
+            fn arity(&self) -> Arity {
+                self.0
+            }
+
+            fn call(&self, args: &[f64]) -> Result<f64> {
+                match args {
+                    [x] => Ok(x * 10.0),
+                    [x, y] => Ok(x + y),
+                    _ => Err(Error::from(ErrorKind::InvalidArgumentCount)),
+                }
+            }
+        }
+
+        let mut context: DefaultContext<f64> = DefaultContext::new();
+        context.add_function(Foo(Arity::Exactly(1))).unwrap();
+        context.add_function(Foo(Arity::Exactly(2))).unwrap();
+        assert!(context.add_function(Foo(Arity::Range(2, 3))).is_err());
+
+        assert!(context.functions_with_arity(1).contains(&"foo"));
+        assert!(context.functions_with_arity(2).contains(&"foo"));
+        assert_eq!(context.get_function_with_arity("foo", 2).unwrap().arity(), Arity::Exactly(2));
+
+        let evaluator = Evaluator::with_context(context);
+        assert_eq!(evaluator.eval("foo(3)"), Ok(30_f64));
+        assert_eq!(evaluator.eval("foo(3, 4)"), Ok(7_f64));
+        assert!(evaluator.eval("foo(1, 2, 3)").is_err());
+    }
+
     #[test]
     fn binary_function_alias_test() {
         struct AddFunction;
@@ -988,4 +2711,188 @@ mod tests {
         assert!(context.get_unary_function("not").is_some());
         assert!(context.get_unary_function("¬").is_some());
     }
+
+    #[test]
+    fn remove_test() {
+        let mut context: DefaultContext<f64> = DefaultContext::new_checked();
+        context.set_variable("x", 2_f64).unwrap();
+        assert_eq!(context.remove_variable("x"), Some(2_f64));
+        assert_eq!(context.remove_variable("x"), None);
+
+        // `clamp01` has the alias `saturate`
+        assert!(context.remove_function("saturate"));
+        assert!(!context.is_function("clamp01"));
+        assert!(!context.is_function("saturate"));
+        assert!(!context.remove_function("clamp01"));
+
+        assert!(context.remove_binary_function("+"));
+        assert!(!context.is_binary_function("+"));
+        assert!(context.remove_unary_function("!"));
+        assert!(!context.is_unary_function("!"));
+        assert!(!context.remove_unary_function("foo"));
+
+        context.add_function_as(crate::ops::checked::SumFunction, "clamp01").unwrap();
+        assert!(context.is_function("clamp01"));
+    }
+
+    #[test]
+    fn remove_overload_test() {
+        struct Foo(Arity);
+        impl Function<f64> for Foo {
+            fn name(&self) -> &str {
+                "foo"
+            }
+
+            fn aliases(&self) -> Option<&[&str]> {
+                Some(&["bar"])
+            }
+
+            fn arity(&self) -> Arity {
+                self.0
+            }
+
+            fn call(&self, _: &[f64]) -> Result<f64> {
+                Ok(0_f64)
+            }
+        }
+
+        let mut context: DefaultContext<f64> = DefaultContext::new();
+        context.add_function(Foo(Arity::Exactly(1))).unwrap();
+        context.add_function(Foo(Arity::Exactly(2))).unwrap();
+        context.set_function_enabled("foo", false).unwrap();
+
+        assert!(context.remove_function("foo"));
+        assert!(!context.is_function("foo"));
+        assert!(!context.is_function("bar"));
+        assert!(context.get_function_with_arity("foo", 2).is_none());
+
+        context.add_function(Foo(Arity::Exactly(2))).unwrap();
+        assert!(context.is_function_enabled("foo"));
+        assert!(context.is_function_enabled("bar"));
+    }
+
+    #[test]
+    fn angle_grouping_test() {
+        use crate::evaluator::Evaluator;
+
+        let config = Config::new().with_grouping(Grouping::Angle);
+        assert!(config.is_grouping_symbol("<"));
+        assert!(!Config::new().is_grouping_symbol("<"));
+
+        let mut context: DefaultContext<f64> = DefaultContext::with_config(config.clone());
+        let error = context.add_binary_function(Dummy(">".to_string())).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+        assert_eq!(error.to_string(), "Invalid operator name, `>` is a grouping symbol of the config");
+        assert!(context.add_binary_function(Dummy(">=".to_string())).is_ok());
+
+        let mut context: DefaultContext<f64> = DefaultContext::new();
+        assert!(context.add_binary_function(Dummy(">".to_string())).is_ok());
+
+        let evaluator: Evaluator<i64> = Evaluator::with_context(DefaultContext::with_config_checked(config));
+        assert_eq!(evaluator.eval("2 * <3 + 1>"), Ok(8));
+        assert!(evaluator.eval("2 * <3 + 1)").is_err());
+    }
+
+    #[test]
+    fn case_sensitive_test() {
+        let mut context: DefaultContext<f64> = DefaultContext::with_config_checked(Config::new().with_case_sensitive(true));
+        context.add_constant("N", 10_f64).unwrap();
+        context.set_variable("n", 2_f64).unwrap();
+        assert_eq!(context.get_constant("N"), Some(&10_f64));
+        assert_eq!(context.get_constant("n"), None);
+        assert!(context.is_function("sin"));
+        assert!(!context.is_function("SIN"));
+        assert!(!context.remove_function("Sin"));
+        assert!(context.add_binary_function(Dummy("X".to_string())).is_ok());
+        assert!(context.is_binary_function("X"));
+        assert!(!context.is_binary_function("x"));
+
+        let mut context: DefaultContext<f64> = DefaultContext::new_checked();
+        context.add_constant("N", 10_f64).unwrap();
+        assert!(context.set_variable("n", 2_f64).is_err());
+        assert!(context.is_function("SIN"));
+    }
+
+    #[test]
+    fn case_sensitive_same_map_test() {
+        use crate::evaluator::Evaluator;
+        use crate::ops::math::MaxFunction;
+
+        let mut context: DefaultContext<f64> = DefaultContext::with_config_checked(Config::new().with_case_sensitive(true));
+        context.add_constant("K", 10_f64).unwrap();
+        context.add_constant("k", 2_f64).unwrap();
+        context.set_variable("x", 1_f64).unwrap();
+        context.set_variable("X", 3_f64).unwrap();
+        context.add_function_as(MaxFunction, "SIN").unwrap();
+
+        assert_eq!(context.get_constant("K"), Some(&10_f64));
+        assert_eq!(context.get_constant("k"), Some(&2_f64));
+        assert_eq!(context.get_variable("x"), Some(&1_f64));
+        assert_eq!(context.get_variable("X"), Some(&3_f64));
+        assert_eq!(context.get_function("sin").unwrap().name(), "sin");
+        assert_eq!(context.get_function("SIN").unwrap().name(), "max");
+
+        // Disabling a function don't disable other with the same name in other case
+        context.set_function_enabled("sin", false).unwrap();
+        assert!(!context.is_function_enabled("sin"));
+        assert!(context.is_function_enabled("SIN"));
+
+        assert!(context.remove_function("SIN"));
+        assert!(context.is_function("sin"));
+
+        let evaluator = Evaluator::with_context(context);
+        assert_eq!(evaluator.eval("K + k"), Ok(12_f64));
+        assert_eq!(evaluator.eval("x * X"), Ok(3_f64));
+        assert!(evaluator.eval("sin(0)").is_err());
+    }
+
+    #[test]
+    fn iter_test() {
+        let mut context: DefaultContext<f64> = DefaultContext::new_checked();
+        context.set_variable("x", 2_f64).unwrap();
+
+        assert_eq!(context.iter_variables().collect::<Vec<_>>(), vec![("x", &2_f64)]);
+        assert!(context.iter_constants().any(|(name, _)| name == "PI"));
+        assert_eq!(context.iter_functions().count(), context.functions().len());
+        assert!(context.iter_functions().any(|(name, f)| name == "saturate" && f.name() == "clamp01"));
+        assert!(context.iter_unary_functions().any(|(name, _)| name == "!"));
+        assert!(context.iter_binary_functions().any(|(name, f)| name == "+" && f.name() == "+"));
+    }
+
+    #[test]
+    fn classify_test() {
+        let mut context: DefaultContext<f64> = DefaultContext::new_unchecked();
+        context.set_variable("x", 2_f64).unwrap();
+
+        assert_eq!(context.classify("sin"), Some(NameKind::Function));
+        assert_eq!(context.classify("+"), Some(NameKind::BinaryOperator));
+        assert_eq!(context.classify("-"), Some(NameKind::BinaryOperator));
+        assert_eq!(context.classify("!"), Some(NameKind::UnaryOperator));
+        assert_eq!(context.classify("PI"), Some(NameKind::Constant));
+        assert_eq!(context.classify("x"), Some(NameKind::Variable));
+        assert_eq!(context.classify("y"), None);
+    }
+
+    #[test]
+    fn fork_test() {
+        let context: DefaultContext<f64> = DefaultContext::new_unchecked();
+        let mut first = context.fork();
+        let mut second = context.fork();
+
+        first.set_variable("x", 1_f64).unwrap();
+        second.set_variable("x", 2_f64).unwrap();
+
+        assert_eq!(first.get_variable("x"), Some(&1_f64));
+        assert_eq!(second.get_variable("x"), Some(&2_f64));
+        assert_eq!(context.get_variable("x"), None);
+
+        assert!(Rc::ptr_eq(first.get_function("sin").unwrap(), second.get_function("sin").unwrap()));
+        assert!(Rc::ptr_eq(&first.functions, &second.functions));
+        assert_eq!(first.get_constant("PI"), context.get_constant("PI"));
+
+        // Adding a function only affects the fork that adds it
+        first.add_function_as(MaxFunction, "largest").unwrap();
+        assert!(first.get_function("largest").is_some());
+        assert!(second.get_function("largest").is_none());
+    }
 }
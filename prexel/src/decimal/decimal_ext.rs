@@ -12,15 +12,28 @@ pub trait DecimalExt {
     fn to_degrees(&self) -> Decimal;
     fn inv(self) -> Decimal;
     fn checked_inv(self) -> Option<Decimal>;
+    /// Divides by `other` returning the quotient and whether the division was exact,
+    /// or `false` if the quotient was truncated at the decimal scale.
+    fn checked_div_exact(self, other: Decimal) -> Option<(Decimal, bool)>;
     fn checked_powd(self, exponent: Decimal) -> Option<Decimal>;
     fn checked_powi(self, exponent: i64) -> Option<Decimal>;
+    /// Gets the square root iterating until the result converges, which is the full precision
+    /// of a `Decimal` (28 digits) for most values, see `checked_sqrt_prec`.
     fn checked_sqrt(self) -> Option<Decimal>;
+    /// Gets the square root using at most the given number of Newton iterations starting from
+    /// the `f64` square root, each iteration doubles the correct digits, so `0` iterations has
+    /// the precision of a `f64` and `2` are usually enough for the full precision.
+    fn checked_sqrt_prec(self, iterations: u32) -> Option<Decimal>;
     fn checked_cbrt(self) -> Option<Decimal>;
     fn checked_log(self, exponent: Decimal) -> Option<Decimal>;
     fn checked_ln(self) -> Option<Decimal>;
     fn checked_exp(self) -> Option<Decimal>;
     fn checked_factorial(self) -> Option<Decimal>;
+    /// Gets the sine of a value in radians, the angle is reduced to `[-π, π]` before the
+    /// series is computed, so angles of up to millions of degrees are accurate to 15 decimal places.
     fn checked_sin(self) -> Option<Decimal>;
+    /// Gets the cosine of a value in radians, the angle is reduced to `[-π, π]` before the
+    /// series is computed, so angles of up to millions of degrees are accurate to 15 decimal places.
     fn checked_cos(self) -> Option<Decimal>;
     fn checked_tan(self) -> Option<Decimal>;
     fn asin(self) -> Option<Decimal>;
@@ -65,6 +78,12 @@ impl DecimalExt for Decimal {
         }
     }
 
+    fn checked_div_exact(self, other: Decimal) -> Option<(Decimal, bool)> {
+        let quotient = self.checked_div(other)?;
+        let is_exact = exact_quotient(self, other) == Some(quotient.abs());
+        Some((quotient, is_exact))
+    }
+
     fn checked_powd(self, exponent: Decimal) -> Option<Decimal> {
         if exponent.is_integer() {
             return self.checked_powi(exponent.to_i64().unwrap());
@@ -119,7 +138,12 @@ impl DecimalExt for Decimal {
         Some(result)
     }
 
+    #[inline]
     fn checked_sqrt(self) -> Option<Decimal> {
+        self.checked_sqrt_prec(consts::SQRT_MAX_ITERATIONS)
+    }
+
+    fn checked_sqrt_prec(self, iterations: u32) -> Option<Decimal> {
         if self.is_sign_negative() {
             return None;
         }
@@ -131,7 +155,11 @@ impl DecimalExt for Decimal {
         let mut result = Decimal::from_f64(self.to_f64()?.sqrt())?;
         let mut x = Decimal::zero();
 
-        while x != result {
+        for _ in 0..iterations {
+            if x == result {
+                break;
+            }
+
             x = result;
 
             // result = 0.5 * ( value / x + result )
@@ -167,13 +195,20 @@ impl DecimalExt for Decimal {
 
     fn checked_log(self, base: Decimal) -> Option<Decimal> {
         let a = Self::checked_ln(self)?;
-        if base == consts::TEN {
-            return a.checked_div(consts::LN_10);
-        }
+        let result = if base == consts::TEN {
+            a.checked_div(consts::LN_10)?
+        } else {
+            let b = Self::checked_ln(base)?;
+            a.checked_div(b)?
+        };
 
-        let b = Self::checked_ln(base)?;
-        let result = a.checked_div(b)?;
-        Some(result)
+        // The natural logarithm is an approximation, so exact powers of the base
+        // are corrected to an integer, eg: log(8, 2) is 3 instead of 2.999...
+        let rounded = result.round();
+        match rounded.to_i64().and_then(|n| base.checked_powi(n)) {
+            Some(n) if n == self => Some(rounded),
+            _ => Some(result),
+        }
     }
 
     fn checked_ln(self) -> Option<Decimal> {
@@ -262,6 +297,8 @@ impl DecimalExt for Decimal {
     fn checked_factorial(self) -> Option<Decimal> {
         // To reduce errors
         const MAX_DECIMAL_PLACES: u32 = 15;
+        // 28! is greater than `Decimal::MAX`
+        const MAX_FACTORIAL_VALUE: Decimal = dec!(28);
 
         if self.is_sign_negative() {
             return None;
@@ -275,6 +312,10 @@ impl DecimalExt for Decimal {
             return gamma(self + consts::ONE).map(|d| d.round_dp(MAX_DECIMAL_PLACES));
         }
 
+        if self > MAX_FACTORIAL_VALUE {
+            return None;
+        }
+
         let mut result = self;
         let mut n = self - Decimal::one();
 
@@ -291,7 +332,7 @@ impl DecimalExt for Decimal {
     }
 
     fn checked_sin(self) -> Option<Decimal> {
-        let radians: Decimal = self % consts::PI_2;
+        let radians: Decimal = reduce_angle(self);
 
         if radians == Decimal::zero()
             || ApproxEq::approx_eq(&radians.abs(), &consts::PI, &consts::PRECISION)
@@ -326,13 +367,13 @@ impl DecimalExt for Decimal {
     }
 
     fn checked_cos(self) -> Option<Decimal> {
-        let radians: Decimal = self % consts::PI_2;
+        let radians: Decimal = reduce_angle(self);
 
         if radians.is_zero() {
             return Some(Decimal::one());
         }
 
-        if ApproxEq::approx_eq(&radians, &consts::PI_FRACT_2, &consts::PRECISION) {
+        if ApproxEq::approx_eq(&radians.abs(), &consts::PI_FRACT_2, &consts::PRECISION) {
             return Some(Decimal::zero());
         }
 
@@ -589,6 +630,80 @@ fn gamma(mut x: Decimal) -> Option<Decimal> {
     }
 }
 
+/// Gets the absolute value of `a / b` without rounding, or `None` if it has more digits
+/// than a `Decimal` can hold, eg: `1 / 3`.
+///
+/// `|a / b|` is `(A / B) * 10^(sb - sa)` for the mantissas `A`, `B` and scales `sa`, `sb`,
+/// which is a finite decimal only if the reduced `B` has no prime factors other than 2 and 5.
+fn exact_quotient(a: Decimal, b: Decimal) -> Option<Decimal> {
+    fn gcd(mut a: u128, mut b: u128) -> u128 {
+        while b != 0 {
+            let r = a % b;
+            a = b;
+            b = r;
+        }
+        a
+    }
+
+    let (numerator, denominator) = (a.mantissa().unsigned_abs(), b.mantissa().unsigned_abs());
+    if numerator == 0 {
+        return Some(Decimal::ZERO);
+    }
+
+    let divisor = gcd(numerator, denominator);
+    let (mut numerator, mut denominator) = (numerator / divisor, denominator / divisor);
+
+    // `numerator / denominator` is `numerator * 2^(k - twos) * 5^(k - fives) / 10^k`
+    let (mut twos, mut fives) = (0, 0);
+    while denominator % 2 == 0 {
+        denominator /= 2;
+        twos += 1;
+    }
+    while denominator % 5 == 0 {
+        denominator /= 5;
+        fives += 1;
+    }
+
+    if denominator != 1 {
+        return None;
+    }
+
+    let k = twos.max(fives);
+    for _ in twos..k {
+        numerator = numerator.checked_mul(2)?;
+    }
+    for _ in fives..k {
+        numerator = numerator.checked_mul(5)?;
+    }
+
+    let mut scale = k as i64 + a.scale() as i64 - b.scale() as i64;
+    while scale < 0 {
+        numerator = numerator.checked_mul(10)?;
+        scale += 1;
+    }
+
+    // A `Decimal` holds 96 bits of mantissa and a scale of at most 28
+    if numerator >= 1 << 96 || scale > 28 {
+        return None;
+    }
+
+    Some(Decimal::from_i128_with_scale(numerator as i128, scale as u32))
+}
+
+/// Reduces an angle in radians to `[-π, π]`, the Taylor series converges faster
+/// the closer the value is to zero.
+fn reduce_angle(radians: Decimal) -> Decimal {
+    let radians = radians % consts::PI_2;
+
+    if radians > consts::PI {
+        radians - consts::PI_2
+    } else if radians < -consts::PI {
+        radians + consts::PI_2
+    } else {
+        radians
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -660,6 +775,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn checked_sqrt_prec_test() {
+        let full = decimal!(2).checked_sqrt().unwrap();
+        assert_eq!(full, decimal!(1.4142135623730950488016887242));
+
+        let low = decimal!(2).checked_sqrt_prec(0).unwrap();
+        assert_ne!(low, full);
+        assert!((low - full).abs() < decimal!(0.000000000000001));
+
+        assert_eq!(decimal!(2).checked_sqrt_prec(5).unwrap(), full);
+        assert_eq!(decimal!(25).checked_sqrt_prec(0).unwrap(), decimal!(5));
+        assert!(decimal!(-2).checked_sqrt_prec(5).is_none());
+    }
+
     #[test]
     fn checked_cbrt_test() {
         assert_almost_eq!(
@@ -731,6 +860,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn checked_div_exact_test() {
+        assert_eq!(decimal!(1).checked_div_exact(decimal!(4)), Some((decimal!(0.25), true)));
+        assert_eq!(decimal!(10).checked_div_exact(decimal!(-2)), Some((decimal!(-5), true)));
+
+        let (quotient, is_exact) = decimal!(1).checked_div_exact(decimal!(3)).unwrap();
+        assert_almost_eq!(quotient, decimal!(0.3333333333333333333333333333));
+        assert!(!is_exact);
+
+        // The product of the quotient and the divisor is rounded back to the dividend
+        for dividend in &[decimal!(10000000000), decimal!(100000000000000000000), decimal!(1000000000000000000000000000)] {
+            let (_, is_exact) = dividend.checked_div_exact(decimal!(3)).unwrap();
+            assert!(!is_exact);
+        }
+
+        assert_eq!(decimal!(1.5).checked_div_exact(decimal!(0.25)), Some((decimal!(6), true)));
+        assert_eq!(decimal!(-7).checked_div_exact(decimal!(0.0008)), Some((decimal!(-8750), true)));
+        assert_eq!(decimal!(0).checked_div_exact(decimal!(3)), Some((decimal!(0), true)));
+        assert_eq!(decimal!(1).checked_div_exact(decimal!(1024)).map(|(_, exact)| exact), Some(true));
+        assert_eq!(decimal!(1).checked_div_exact(decimal!(0)), None);
+    }
+
     #[test]
     fn checked_factorial_test() {
         assert_almost_eq!(decimal!(10).checked_factorial().unwrap(), decimal!(3628800));
@@ -744,6 +895,10 @@ mod tests {
             decimal!(1871.254305797788346476077053),
             10,
         );
+
+        assert!(decimal!(27).checked_factorial().is_some());
+        assert!(decimal!(28).checked_factorial().is_none());
+        assert!(decimal!(1000000).checked_factorial().is_none());
     }
 
     #[test]
@@ -790,6 +945,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn sin_cos_large_angle_test() {
+        const ERROR: f64 = 1e-10;
+
+        for degrees in &[270_f64, 1000_f64, -1000_f64, 12345.5_f64, 36123_f64] {
+            let radians = Decimal::from_f64(*degrees).unwrap().to_radians();
+            let expected = degrees.to_radians();
+
+            let sin = radians.checked_sin().unwrap().to_f64().unwrap();
+            let cos = radians.checked_cos().unwrap().to_f64().unwrap();
+
+            assert!((sin - expected.sin()).abs() < ERROR, "sin({}°) = {}", degrees, sin);
+            assert!((cos - expected.cos()).abs() < ERROR, "cos({}°) = {}", degrees, cos);
+        }
+
+        // Beyond the precision of `f64`
+        assert_almost_eq_by!(
+            decimal!(360000123).to_radians().checked_sin().unwrap(),
+            decimal!(123).to_radians().checked_sin().unwrap(),
+            15
+        );
+
+        assert_almost_eq!(decimal!(1000).checked_sin().unwrap(), decimal!(0.8268795405320025602558874291));
+        assert_almost_eq!(decimal!(1000).checked_cos().unwrap(), decimal!(0.5623790762907029910782492266));
+    }
+
     #[test]
     fn tan_test() {
         assert_almost_eq!(
@@ -0,0 +1,171 @@
+use crate::error::{Error, ErrorKind};
+use crate::Result;
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+/// The SI prefixes and its exponents, from `yocto` to `yotta`.
+const PREFIXES: [(&str, i32); 17] = [
+    ("y", -24),
+    ("z", -21),
+    ("a", -18),
+    ("f", -15),
+    ("p", -12),
+    ("n", -9),
+    ("µ", -6),
+    ("m", -3),
+    ("", 0),
+    ("k", 3),
+    ("M", 6),
+    ("G", 9),
+    ("T", 12),
+    ("P", 15),
+    ("E", 18),
+    ("Z", 21),
+    ("Y", 24),
+];
+
+const MIN_EXPONENT: i32 = -24;
+const MAX_EXPONENT: i32 = 24;
+
+/// Formats a `Decimal` in engineering notation using an exponent multiple of 3
+/// written as a SI prefix, eg: `1500` to `1.5k` and `0.0022` to `2.2m`.
+///
+/// # Remarks
+/// Values out of the range of the prefixes keep the `yocto` or `yotta` prefix,
+/// eg: `1e27` is formatted as `1000Y`.
+///
+/// # Example
+/// ```
+/// use prexel::decimal::engineering::to_engineering;
+/// use rust_decimal_macros::dec;
+///
+/// assert_eq!(to_engineering(dec!(1500)), "1.5k");
+/// assert_eq!(to_engineering(dec!(-2200000)), "-2.2M");
+/// assert_eq!(to_engineering(dec!(0.047)), "47m");
+/// ```
+pub fn to_engineering(value: Decimal) -> String {
+    if value.is_zero() {
+        return String::from("0");
+    }
+
+    let thousand = Decimal::from(1000);
+    let mut mantissa = value.abs();
+    let mut exponent = 0;
+
+    while mantissa >= thousand && exponent < MAX_EXPONENT {
+        mantissa /= thousand;
+        exponent += 3;
+    }
+
+    while mantissa < Decimal::ONE && exponent > MIN_EXPONENT {
+        mantissa *= thousand;
+        exponent -= 3;
+    }
+
+    let prefix = PREFIXES
+        .iter()
+        .find(|(_, e)| *e == exponent)
+        .map(|(prefix, _)| *prefix)
+        .unwrap();
+
+    let sign = if value.is_sign_negative() { "-" } else { "" };
+    format!("{}{}{}", sign, mantissa.normalize(), prefix)
+}
+
+/// Parses a number with an optional SI prefix, eg: `2.2k` to `2200`.
+///
+/// The prefix `u` is also accepted for `micro`, prefixes are case sensitive
+/// so `m` is `milli` and `M` is `mega`.
+///
+/// # Example
+/// ```
+/// use prexel::decimal::engineering::parse_engineering;
+/// use rust_decimal_macros::dec;
+///
+/// assert_eq!(parse_engineering("2.2k"), Ok(dec!(2200)));
+/// assert_eq!(parse_engineering("10u"), Ok(dec!(0.00001)));
+/// assert_eq!(parse_engineering("42"), Ok(dec!(42)));
+/// ```
+pub fn parse_engineering(s: &str) -> Result<Decimal> {
+    let s = s.trim();
+    let (number, exponent) = match s.chars().last() {
+        Some('u') => (&s[..s.len() - 1], -6),
+        Some(c) if !c.is_ascii_digit() && c != '.' => {
+            let prefix = &s[s.len() - c.len_utf8()..];
+            let exponent = PREFIXES
+                .iter()
+                .find(|(p, _)| *p == prefix)
+                .map(|(_, e)| *e)
+                .ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("Unknown SI prefix `{}` in `{}`", prefix, s),
+                    )
+                })?;
+
+            (&s[..s.len() - c.len_utf8()], exponent)
+        }
+        _ => (s, 0),
+    };
+
+    let value = Decimal::from_str(number).map_err(|_| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            format!("Invalid number `{}`", s),
+        )
+    })?;
+
+    // The scale is at most `10^24`, which doesn't fit in a `u64`
+    let result = if exponent < 0 {
+        value.checked_mul(Decimal::new(1, exponent.unsigned_abs()))
+    } else {
+        value.checked_mul(Decimal::from_i128_with_scale(10_i128.pow(exponent as u32), 0))
+    };
+
+    result.ok_or_else(|| Error::from(ErrorKind::Overflow))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn parse_engineering_test() {
+        assert_eq!(parse_engineering("1.5k"), Ok(dec!(1500)));
+        assert_eq!(parse_engineering("3M"), Ok(dec!(3000000)));
+        assert_eq!(parse_engineering("2.2m"), Ok(dec!(0.0022)));
+        assert_eq!(parse_engineering("-4.7µ"), Ok(dec!(-0.0000047)));
+        assert_eq!(parse_engineering(" 12 "), Ok(dec!(12)));
+        assert_eq!(parse_engineering("1Y"), Ok(dec!(1000000000000000000000000)));
+        assert_eq!(parse_engineering("2Z"), Ok(dec!(2000000000000000000000)));
+        assert_eq!(parse_engineering("1y"), Ok(dec!(0.000000000000000000000001)));
+        assert_eq!(parse_engineering("3z"), Ok(dec!(0.000000000000000000003)));
+        assert_eq!(parse_engineering("100000Y").unwrap_err().kind(), ErrorKind::Overflow);
+
+        assert_eq!(parse_engineering("1.5q").unwrap_err().kind(), ErrorKind::InvalidInput);
+        assert_eq!(parse_engineering("k").unwrap_err().kind(), ErrorKind::InvalidInput);
+        assert_eq!(parse_engineering("").unwrap_err().kind(), ErrorKind::InvalidInput);
+        assert_eq!(parse_engineering("79228162514264337593543950335k").unwrap_err().kind(), ErrorKind::Overflow);
+    }
+
+    #[test]
+    fn to_engineering_test() {
+        assert_eq!(to_engineering(dec!(1500)), "1.5k");
+        assert_eq!(to_engineering(dec!(1500.00)), "1.5k");
+        assert_eq!(to_engineering(dec!(3000000)), "3M");
+        assert_eq!(to_engineering(dec!(999)), "999");
+        assert_eq!(to_engineering(dec!(1)), "1");
+        assert_eq!(to_engineering(dec!(0)), "0");
+        assert_eq!(to_engineering(dec!(-0.0022)), "-2.2m");
+        assert_eq!(to_engineering(dec!(0.000001)), "1µ");
+        assert_eq!(to_engineering(dec!(1e27)), "1000Y");
+    }
+
+    #[test]
+    fn round_trip_test() {
+        for value in &[dec!(1500), dec!(0.047), dec!(-2200000), dec!(123.456)] {
+            assert_eq!(parse_engineering(&to_engineering(*value)), Ok(*value));
+        }
+    }
+}
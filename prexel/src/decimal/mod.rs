@@ -1,14 +1,25 @@
 /// The decimal type.
 pub type Decimal = rust_decimal::Decimal;
 
-///
+impl crate::evaluator::ResultType for Decimal {
+    #[inline]
+    fn result_info(&self) -> crate::evaluator::ResultInfo {
+        crate::evaluator::ResultInfo::Real
+    }
+}
+
+/// Extension methods for `Decimal`.
 pub mod decimal_ext;
 
+/// Formatting and parsing of `Decimal` in engineering notation with SI prefixes.
+pub mod engineering;
+
 pub mod consts {
     use rust_decimal::Decimal;
     use rust_decimal_macros::*;
 
     pub(crate) const TAYLOR_SERIES_ITERATIONS: u32 = 100;
+    pub(crate) const SQRT_MAX_ITERATIONS: u32 = 100;
     pub(crate) const PRECISION: Decimal = dec!(0.0000000000000001);
 
     //////////////////////// Constants ////////////////////////
@@ -73,6 +84,10 @@ macro_rules! forward_checked_func_impl {
                 stringify!($name)
             }
 
+            fn arity(&self) -> Arity {
+                Arity::Exactly(1)
+            }
+
             #[inline]
             fn call(&self, args: &[Decimal]) -> Result<Decimal> {
                 match args.len() {
@@ -99,6 +114,10 @@ macro_rules! forward_checked_func_inv_impl {
                 stringify!($name)
             }
 
+            fn arity(&self) -> Arity {
+                Arity::Exactly(1)
+            }
+
             #[inline]
             fn call(&self, args: &[Decimal]) -> Result<Decimal> {
                 match args.len() {
@@ -127,6 +146,10 @@ macro_rules! forward_func_impl {
                 stringify!($name)
             }
 
+            fn arity(&self) -> Arity {
+                Arity::Exactly(1)
+            }
+
             #[inline]
             fn call(&self, args: &[Decimal]) -> Result<Decimal> {
                 match args.len() {
@@ -151,6 +174,10 @@ macro_rules! forward_func_inv_impl {
                 stringify!($name)
             }
 
+            fn arity(&self) -> Arity {
+                Arity::Exactly(1)
+            }
+
             #[inline]
             fn call(&self, args: &[Decimal]) -> Result<Decimal> {
                 match args.len() {
@@ -175,6 +202,10 @@ macro_rules! forward_checked_func_impl {
                 stringify!($name)
             }
 
+            fn arity(&self) -> Arity {
+                Arity::Exactly(1)
+            }
+
             #[inline]
             fn call(&self, args: &[Decimal]) -> Result<Decimal> {
                 match args.len() {
@@ -188,6 +219,10 @@ macro_rules! forward_checked_func_impl {
             fn description(&self) -> Option<&str> {
                 Some($description.into())
             }
+
+            fn category(&self) -> Option<&str> {
+                Some($description.category().into())
+            }
         }
     };
 
@@ -205,6 +240,10 @@ macro_rules! forward_checked_func_inv_impl {
                 stringify!($name)
             }
 
+            fn arity(&self) -> Arity {
+                Arity::Exactly(1)
+            }
+
             #[inline]
             fn call(&self, args: &[Decimal]) -> Result<Decimal> {
                 match args.len() {
@@ -220,6 +259,10 @@ macro_rules! forward_checked_func_inv_impl {
             fn description(&self) -> Option<&str> {
                 Some($description.into())
             }
+
+            fn category(&self) -> Option<&str> {
+                Some($description.category().into())
+            }
         }
     };
 
@@ -237,6 +280,10 @@ macro_rules! forward_func_impl {
                 stringify!($name)
             }
 
+            fn arity(&self) -> Arity {
+                Arity::Exactly(1)
+            }
+
             #[inline]
             fn call(&self, args: &[Decimal]) -> Result<Decimal> {
                 match args.len() {
@@ -248,6 +295,10 @@ macro_rules! forward_func_impl {
             fn description(&self) -> Option<&str> {
                 Some($description.into())
             }
+
+            fn category(&self) -> Option<&str> {
+                Some($description.category().into())
+            }
         }
     };
 
@@ -265,6 +316,10 @@ macro_rules! forward_func_inv_impl {
                 stringify!($name)
             }
 
+            fn arity(&self) -> Arity {
+                Arity::Exactly(1)
+            }
+
             #[inline]
             fn call(&self, args: &[Decimal]) -> Result<Decimal> {
                 match args.len() {
@@ -276,6 +331,10 @@ macro_rules! forward_func_inv_impl {
             fn description(&self) -> Option<&str> {
                 Some($description.into())
             }
+
+            fn category(&self) -> Option<&str> {
+                Some($description.category().into())
+            }
         }
     };
 
@@ -291,14 +350,15 @@ pub mod ops {
 
 mod math_ops {
     use crate::decimal::consts;
-    use num_traits::FromPrimitive;
-    use rust_decimal::Decimal;
+    use num_traits::{FromPrimitive, One, ToPrimitive, Zero};
+    use rust_decimal::{Decimal, RoundingStrategy};
 
     use crate::decimal::decimal_ext::DecimalExt;
     use crate::error::*;
     use crate::function::{
-        Associativity, BinaryFunction, Function, Notation, Precedence, UnaryFunction,
+        Arity, Associativity, BinaryFunction, Function, Notation, Precedence, UnaryFunction,
     };
+    use crate::ops::negative_base_pow_sign;
     use crate::Result;
 
     #[cfg(feature = "docs")]
@@ -331,6 +391,11 @@ mod math_ops {
         fn description(&self) -> Option<&str> {
             Some(Description::Add.into())
         }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Add.category().into())
+        }
     }
 
     pub struct SubOperator;
@@ -360,6 +425,11 @@ mod math_ops {
         fn description(&self) -> Option<&str> {
             Some(Description::Sub.into())
         }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Sub.category().into())
+        }
     }
 
     pub struct MulOperator;
@@ -389,6 +459,11 @@ mod math_ops {
         fn description(&self) -> Option<&str> {
             Some(Description::Mul.into())
         }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Mul.category().into())
+        }
     }
 
     pub struct DivOperator;
@@ -418,6 +493,11 @@ mod math_ops {
         fn description(&self) -> Option<&str> {
             Some(Description::Div.into())
         }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Div.category().into())
+        }
     }
 
     pub struct ModOperator;
@@ -447,6 +527,11 @@ mod math_ops {
         fn description(&self) -> Option<&str> {
             Some(Description::Mod.into())
         }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Mod.category().into())
+        }
     }
 
     pub struct PowOperator;
@@ -468,6 +553,18 @@ mod math_ops {
 
         #[inline]
         fn call(&self, left: Decimal, right: Decimal) -> Result<Decimal> {
+            // Computes the real root for negative bases with fractional exponents, eg: (-8)^(1/3)
+            if left.is_sign_negative() && !right.is_integer() {
+                let exponent = right.to_f64().ok_or_else(|| Error::from(ErrorKind::Overflow))?;
+                let sign = negative_base_pow_sign(exponent)?;
+
+                return left
+                    .abs()
+                    .checked_powd(right)
+                    .map(|n| if sign < 0_f64 { -n } else { n })
+                    .ok_or_else(|| Error::from(ErrorKind::Overflow));
+            }
+
             left.checked_powd(right)
                 .ok_or_else(|| Error::from(ErrorKind::Overflow))
         }
@@ -476,8 +573,86 @@ mod math_ops {
         fn description(&self) -> Option<&str> {
             Some(Description::Pow.into())
         }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Pow.category().into())
+        }
+    }
+
+    macro_rules! comparison_operator_impl {
+        ($struct_name:ident, $name:expr, [$($alias:expr),*], $op:tt, $description:expr) => {
+            impl BinaryFunction<Decimal> for $struct_name {
+                #[inline]
+                fn name(&self) -> &str {
+                    $name
+                }
+
+                #[inline]
+                fn aliases(&self) -> Option<&[&str]> {
+                    Some(&[$($alias),*])
+                }
+
+                #[inline]
+                fn precedence(&self) -> Precedence {
+                    Precedence::VERY_LOW
+                }
+
+                #[inline]
+                fn associativity(&self) -> Associativity {
+                    Associativity::Left
+                }
+
+                #[inline]
+                fn call(&self, left: Decimal, right: Decimal) -> Result<Decimal> {
+                    if left $op right {
+                        Ok(Decimal::one())
+                    } else {
+                        Ok(Decimal::zero())
+                    }
+                }
+
+                #[cfg(feature = "docs")]
+                fn description(&self) -> Option<&str> {
+                    Some($description.into())
+                }
+
+                #[cfg(feature = "docs")]
+                fn category(&self) -> Option<&str> {
+                    Some($description.category().into())
+                }
+            }
+        };
     }
 
+    /// Returns `1` if the values are equal, `0` otherwise.
+    ///
+    /// # Remarks
+    /// The values are compared exactly, results of inexact operations as `sqrt(2)^2 == 2`
+    /// may not be equal due the precision of the decimal numbers.
+    pub struct EqOperator;
+    comparison_operator_impl!(EqOperator, "==", ["eq"], ==, Description::Eq);
+
+    /// Returns `1` if the values are not equal, `0` otherwise.
+    pub struct NeOperator;
+    comparison_operator_impl!(NeOperator, "!=", ["ne"], !=, Description::Ne);
+
+    /// Returns `1` if the left value is greater than the right value, `0` otherwise.
+    pub struct GtOperator;
+    comparison_operator_impl!(GtOperator, ">", ["gt"], >, Description::Gt);
+
+    /// Returns `1` if the left value is less than the right value, `0` otherwise.
+    pub struct LtOperator;
+    comparison_operator_impl!(LtOperator, "<", ["lt"], <, Description::Lt);
+
+    /// Returns `1` if the left value is greater than or equal to the right value, `0` otherwise.
+    pub struct GteOperator;
+    comparison_operator_impl!(GteOperator, ">=", ["gte"], >=, Description::Gte);
+
+    /// Returns `1` if the left value is less than or equal to the right value, `0` otherwise.
+    pub struct LteOperator;
+    comparison_operator_impl!(LteOperator, "<=", ["lte"], <=, Description::Lte);
+
     pub struct UnaryMinus;
     impl UnaryFunction<Decimal> for UnaryMinus {
         #[inline]
@@ -499,6 +674,11 @@ mod math_ops {
         fn description(&self) -> Option<&str> {
             Some(Description::Neg.into())
         }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Neg.category().into())
+        }
     }
 
     pub struct Factorial;
@@ -524,6 +704,11 @@ mod math_ops {
         fn description(&self) -> Option<&str> {
             Some(Description::Factorial.into())
         }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Factorial.category().into())
+        }
     }
 
     pub struct SumFunction;
@@ -532,6 +717,10 @@ mod math_ops {
             "sum"
         }
 
+        fn arity(&self) -> Arity {
+            Arity::AtLeast(1)
+        }
+
         fn call(&self, args: &[Decimal]) -> Result<Decimal> {
             let mut result = None;
 
@@ -539,7 +728,8 @@ mod math_ops {
                 match result {
                     None => result = Some(*cur),
                     Some(ref n) => {
-                        result = Some(*n + *cur);
+                        result = Some(n.checked_add(*cur)
+                            .ok_or_else(|| Error::from(ErrorKind::Overflow))?);
                     }
                 }
             }
@@ -551,6 +741,11 @@ mod math_ops {
         fn description(&self) -> Option<&str> {
             Some(Description::Sum.into())
         }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Sum.category().into())
+        }
     }
 
     pub struct ProdFunction;
@@ -559,6 +754,10 @@ mod math_ops {
             "product"
         }
 
+        fn arity(&self) -> Arity {
+            Arity::AtLeast(1)
+        }
+
         fn call(&self, args: &[Decimal]) -> Result<Decimal> {
             let mut result = None;
 
@@ -566,7 +765,8 @@ mod math_ops {
                 match result {
                     None => result = Some(*cur),
                     Some(ref n) => {
-                        result = Some(n * cur);
+                        result = Some(n.checked_mul(*cur)
+                            .ok_or_else(|| Error::from(ErrorKind::Overflow))?);
                     }
                 }
             }
@@ -578,6 +778,11 @@ mod math_ops {
         fn description(&self) -> Option<&str> {
             Some(Description::Prod.into())
         }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Prod.category().into())
+        }
     }
 
     pub struct AvgFunction;
@@ -586,6 +791,10 @@ mod math_ops {
             "avg"
         }
 
+        fn arity(&self) -> Arity {
+            Arity::AtLeast(1)
+        }
+
         fn call(&self, args: &[Decimal]) -> Result<Decimal> {
             let mut sum = None;
 
@@ -593,7 +802,8 @@ mod math_ops {
                 match sum {
                     None => sum = Some(*cur),
                     Some(ref n) => {
-                        sum = Some(*n + *cur);
+                        sum = Some(n.checked_add(*cur)
+                            .ok_or_else(|| Error::from(ErrorKind::Overflow))?);
                     }
                 }
             }
@@ -608,159 +818,1058 @@ mod math_ops {
         fn description(&self) -> Option<&str> {
             Some(Description::Avg.into())
         }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Avg.category().into())
+        }
     }
 
-    pub struct FloorFunction;
-    #[cfg(not(feature = "docs"))]
-    forward_func_impl!(FloorFunction, floor);
-    #[cfg(feature = "docs")]
-    forward_func_impl!(FloorFunction, floor, Description::Floor);
+    pub struct GeoMeanFunction;
+    impl Function<Decimal> for GeoMeanFunction {
+        fn name(&self) -> &str {
+            "geomean"
+        }
 
-    pub struct CeilFunction;
-    #[cfg(not(feature = "docs"))]
-    forward_func_impl!(CeilFunction, ceil);
-    #[cfg(feature = "docs")]
-    forward_func_impl!(CeilFunction, ceil, Description::Ceil);
+        fn arity(&self) -> Arity {
+            Arity::AtLeast(1)
+        }
 
-    pub struct TruncateFunction;
-    #[cfg(not(feature = "docs"))]
-    forward_func_impl!(TruncateFunction, trunc, truncate);
-    #[cfg(feature = "docs")]
-    forward_func_impl!(TruncateFunction, trunc, truncate, Description::Truncate);
+        fn call(&self, args: &[Decimal]) -> Result<Decimal> {
+            if args.is_empty() {
+                return Err(Error::from(ErrorKind::InvalidArgumentCount));
+            }
 
-    pub struct RoundFunction;
-    #[cfg(not(feature = "docs"))]
-    forward_func_impl!(RoundFunction, round);
-    #[cfg(feature = "docs")]
-    forward_func_impl!(RoundFunction, round, Description::Round);
+            // The geometric mean is only defined for positive values
+            let mut product = Decimal::one();
 
-    pub struct SqrtFunction;
-    #[cfg(not(feature = "docs"))]
-    forward_checked_func_impl!(SqrtFunction, checked_sqrt, sqrt);
-    #[cfg(feature = "docs")]
-    forward_checked_func_impl!(SqrtFunction, checked_sqrt, sqrt, Description::Sqrt);
+            for n in args {
+                if n.is_zero() {
+                    return Err(Error::from(ErrorKind::Zero));
+                }
 
-    pub struct CbrtFunction;
-    #[cfg(not(feature = "docs"))]
-    forward_checked_func_impl!(CbrtFunction, checked_cbrt, cbrt);
-    #[cfg(feature = "docs")]
-    forward_checked_func_impl!(CbrtFunction, checked_cbrt, cbrt, Description::Cbrt);
+                if n.is_sign_negative() {
+                    return Err(Error::from(ErrorKind::NegativeValue));
+                }
 
-    pub struct ExpFunction;
-    #[cfg(not(feature = "docs"))]
-    forward_checked_func_impl!(ExpFunction, checked_exp, exp);
-    #[cfg(feature = "docs")]
-    forward_checked_func_impl!(ExpFunction, checked_exp, exp, Description::Exp);
+                product = product
+                    .checked_mul(*n)
+                    .ok_or_else(|| Error::from(ErrorKind::Overflow))?;
+            }
 
-    pub struct LnFunction;
-    #[cfg(not(feature = "docs"))]
-    forward_checked_func_impl!(LnFunction, checked_ln, ln);
-    #[cfg(feature = "docs")]
-    forward_checked_func_impl!(LnFunction, checked_ln, ln, Description::Ln);
+            let result = match args.len() {
+                1 => Some(product),
+                2 => product.checked_sqrt(),
+                len => product.checked_powd(Decimal::one() / Decimal::from(len)),
+            };
 
-    pub struct LogFunction;
-    impl Function<Decimal> for LogFunction {
-        fn name(&self) -> &str {
-            "log"
+            result.ok_or_else(|| Error::from(ErrorKind::Overflow))
         }
 
-        fn call(&self, args: &[Decimal]) -> Result<Decimal> {
-            match args.len() {
-                1 => args[0]
-                    .checked_log(consts::TEN)
-                    .ok_or_else(|| Error::from(ErrorKind::Overflow)),
-                2 => args[0]
-                    .checked_log(args[1])
-                    .ok_or_else(|| Error::from(ErrorKind::Overflow)),
-                _ => Err(Error::from(ErrorKind::InvalidArgumentCount)),
-            }
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::GeoMean.into())
         }
 
         #[cfg(feature = "docs")]
-        fn description(&self) -> Option<&str> {
-            Some(Description::Log.into())
+        fn category(&self) -> Option<&str> {
+            Some(Description::GeoMean.category().into())
         }
     }
 
-    pub struct ToRadiansFunction;
-    #[cfg(not(feature = "docs"))]
-    forward_func_impl!(ToRadiansFunction, to_radians, to_radians);
-    #[cfg(feature = "docs")]
-    forward_func_impl!(ToRadiansFunction, to_radians, to_radians, Description::ToRadians);
+    pub struct HarMeanFunction;
+    impl Function<Decimal> for HarMeanFunction {
+        fn name(&self) -> &str {
+            "harmean"
+        }
 
-    pub struct ToDegreesFunction;
-    #[cfg(not(feature = "docs"))]
-    forward_func_impl!(ToDegreesFunction, to_degrees, to_degrees);
-    #[cfg(feature = "docs")]
-    forward_func_impl!(ToDegreesFunction, to_degrees, to_degrees, Description::ToDegrees);
-}
+        fn arity(&self) -> Arity {
+            Arity::AtLeast(1)
+        }
 
-mod trig_ops {
-    use crate::decimal::decimal_ext::DecimalExt;
-    use crate::error::*;
-    use crate::function::Function;
-    use crate::Result;
-    use rust_decimal::Decimal;
+        fn call(&self, args: &[Decimal]) -> Result<Decimal> {
+            if args.is_empty() {
+                return Err(Error::from(ErrorKind::InvalidArgumentCount));
+            }
 
-    #[cfg(feature = "docs")]
-    use crate::descriptions::Description;
+            let mut sum = Decimal::zero();
 
-    #[cfg(not(feature = "docs"))]
-    macro_rules! impl_checked_trig {
-        ($struct_name:ident, $method_name:ident, $name:ident) => {
-            impl Function<Decimal> for $struct_name {
-                #[inline]
-                fn name(&self) -> &str {
-                    stringify!($name)
-                }
+            for n in args {
+                let inv = n
+                    .checked_inv()
+                    .ok_or_else(|| Error::from(ErrorKind::DivisionByZero))?;
 
-                #[inline]
-                fn call(&self, args: &[Decimal]) -> Result<Decimal> {
-                    match args.len() {
-                        1 => args[0]
-                            .to_radians()
-                            .$method_name()
-                            .ok_or_else(|| Error::from(ErrorKind::Overflow)),
-                        _ => Err(Error::from(ErrorKind::InvalidArgumentCount)),
-                    }
-                }
+                sum = sum
+                    .checked_add(inv)
+                    .ok_or_else(|| Error::from(ErrorKind::Overflow))?;
             }
-        };
 
-        ($struct_name:ident, $method_name:ident) => {
-            impl_checked_trig!($struct_name, $method_name, $method_name);
-        };
+            Decimal::from(args.len())
+                .checked_div(sum)
+                .ok_or_else(|| Error::from(ErrorKind::DivisionByZero))
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::HarMean.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::HarMean.category().into())
+        }
     }
 
-    #[cfg(not(feature = "docs"))]
-    macro_rules! impl_checked_trig_rec {
-        ($struct_name:ident, $method_name:ident, $name:ident) => {
-            impl Function<Decimal> for $struct_name {
-                #[inline]
-                fn name(&self) -> &str {
-                    stringify!($name)
-                }
+    pub struct PercentileFunction;
+    impl Function<Decimal> for PercentileFunction {
+        fn name(&self) -> &str {
+            "percentile"
+        }
 
-                #[inline]
-                fn call(&self, args: &[Decimal]) -> Result<Decimal> {
-                    match args.len() {
-                        1 => args[0]
-                            .to_radians()
-                            .$method_name()
-                            //.map(Decimal::checked_inv)
-                            .ok_or_else(|| Error::from(ErrorKind::Overflow)),
-                        _ => Err(Error::from(ErrorKind::InvalidArgumentCount)),
-                    }
+        fn arity(&self) -> Arity {
+            Arity::AtLeast(2)
+        }
+
+        fn call(&self, args: &[Decimal]) -> Result<Decimal> {
+            let (percentile, values) = match args {
+                [percentile, values @ ..] if !values.is_empty() => (*percentile, values),
+                _ => return Err(Error::from(ErrorKind::InvalidArgumentCount)),
+            };
+
+            if percentile < Decimal::zero() || percentile > Decimal::ONE_HUNDRED {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The percentile must be in the range [0, 100]",
+                ));
+            }
+
+            let overflow = || Error::from(ErrorKind::Overflow);
+            let mut sorted = values.to_vec();
+            sorted.sort();
+
+            // The rank of the percentile between `0` and `len - 1`
+            let rank = percentile
+                .checked_mul(Decimal::from(sorted.len() - 1))
+                .and_then(|n| n.checked_div(Decimal::ONE_HUNDRED))
+                .ok_or_else(overflow)?;
+
+            let index = rank.floor();
+            let fraction = rank - index;
+            let index = index.to_usize().ok_or_else(overflow)?.min(sorted.len() - 1);
+
+            match sorted.get(index + 1) {
+                Some(upper) if !fraction.is_zero() => {
+                    let lower = sorted[index];
+                    upper
+                        .checked_sub(lower)
+                        .and_then(|n| n.checked_mul(fraction))
+                        .and_then(|n| n.checked_add(lower))
+                        .ok_or_else(overflow)
                 }
+                _ => Ok(sorted[index]),
             }
-        };
+        }
 
-        ($struct_name:ident, $method_name:ident) => {
-            impl_checked_trig_rec!($struct_name, $method_name, $method_name);
-        };
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::Percentile.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Percentile.category().into())
+        }
     }
 
-    #[cfg(not(feature = "docs"))]
+    pub struct PercentChangeFunction;
+    impl Function<Decimal> for PercentChangeFunction {
+        fn name(&self) -> &str {
+            "pctchange"
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::Exactly(2)
+        }
+
+        fn call(&self, args: &[Decimal]) -> Result<Decimal> {
+            if args.len() != 2 {
+                return Err(Error::from(ErrorKind::InvalidArgumentCount));
+            }
+
+            // (new - old) / old * 100
+            let (old, new) = (args[0], args[1]);
+            if old.is_zero() {
+                return Err(Error::from(ErrorKind::DivisionByZero));
+            }
+
+            new.checked_sub(old)
+                .and_then(|diff| diff.checked_div(old))
+                .and_then(|n| n.checked_mul(Decimal::ONE_HUNDRED))
+                .ok_or_else(|| Error::from(ErrorKind::Overflow))
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::PercentChange.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::PercentChange.category().into())
+        }
+    }
+
+    pub struct PercentOfFunction;
+    impl Function<Decimal> for PercentOfFunction {
+        fn name(&self) -> &str {
+            "pctof"
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::Exactly(2)
+        }
+
+        fn call(&self, args: &[Decimal]) -> Result<Decimal> {
+            if args.len() != 2 {
+                return Err(Error::from(ErrorKind::InvalidArgumentCount));
+            }
+
+            // part / base * 100
+            let (part, base) = (args[0], args[1]);
+            if base.is_zero() {
+                return Err(Error::from(ErrorKind::DivisionByZero));
+            }
+
+            part.checked_div(base)
+                .and_then(|n| n.checked_mul(Decimal::ONE_HUNDRED))
+                .ok_or_else(|| Error::from(ErrorKind::Overflow))
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::PercentOf.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::PercentOf.category().into())
+        }
+    }
+
+    pub struct ExactDivFunction;
+    impl Function<Decimal> for ExactDivFunction {
+        fn name(&self) -> &str {
+            "exactdiv"
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::Exactly(2)
+        }
+
+        fn call(&self, args: &[Decimal]) -> Result<Decimal> {
+            if args.len() != 2 {
+                return Err(Error::from(ErrorKind::InvalidArgumentCount));
+            }
+
+            let (left, right) = (args[0], args[1]);
+            if right.is_zero() {
+                return Err(Error::from(ErrorKind::DivisionByZero));
+            }
+
+            match left.checked_div_exact(right) {
+                Some((quotient, true)) => Ok(quotient),
+                Some((_, false)) => Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("Division of {} by {} is not exact", left, right),
+                )),
+                None => Err(Error::from(ErrorKind::Overflow)),
+            }
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::ExactDiv.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::ExactDiv.category().into())
+        }
+    }
+
+    pub struct SmoothStepFunction;
+    impl Function<Decimal> for SmoothStepFunction {
+        fn name(&self) -> &str {
+            "smoothstep"
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::Exactly(3)
+        }
+
+        fn call(&self, args: &[Decimal]) -> Result<Decimal> {
+            if args.len() != 3 {
+                return Err(Error::from(ErrorKind::InvalidArgumentCount));
+            }
+
+            let (edge0, edge1, value) = (args[0], args[1], args[2]);
+            if edge0 == edge1 {
+                return Err(Error::from(ErrorKind::DivisionByZero));
+            }
+
+            let overflow = || Error::from(ErrorKind::Overflow);
+            let t = value
+                .checked_sub(edge0)
+                .and_then(|n| n.checked_div(edge1.checked_sub(edge0)?))
+                .ok_or_else(overflow)?
+                .clamp(Decimal::zero(), Decimal::one());
+
+            // t * t * (3 - 2 * t)
+            consts::THREE
+                .checked_sub(consts::TWO * t)
+                .and_then(|n| n.checked_mul(t * t))
+                .ok_or_else(overflow)
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::SmoothStep.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::SmoothStep.category().into())
+        }
+    }
+
+    pub struct PowModFunction;
+    impl Function<Decimal> for PowModFunction {
+        fn name(&self) -> &str {
+            "powmod"
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::Exactly(3)
+        }
+
+        fn call(&self, args: &[Decimal]) -> Result<Decimal> {
+            if args.len() != 3 {
+                return Err(Error::from(ErrorKind::InvalidArgumentCount));
+            }
+
+            if !args.iter().all(|n| n.is_integer()) {
+                return Err(Error::new(ErrorKind::InvalidInput, "powmod expects integer values"));
+            }
+
+            let (base, exponent, modulus) = (args[0], args[1], args[2]);
+            if modulus <= Decimal::zero() {
+                return Err(Error::new(ErrorKind::InvalidInput, "powmod expects a positive modulus"));
+            }
+
+            if exponent.is_sign_negative() {
+                return Err(Error::from(ErrorKind::NegativeValue));
+            }
+
+            let reduce = |n: Decimal| {
+                let r = n % modulus;
+                if r.is_sign_negative() { r + modulus } else { r }
+            };
+
+            // Exponentiation by squaring, reducing at each step to avoid overflow
+            let mut exponent = exponent.to_u128().ok_or_else(|| Error::from(ErrorKind::Overflow))?;
+            let mut base = reduce(base);
+            let mut result = reduce(Decimal::one());
+
+            while exponent > 0 {
+                if exponent & 1 == 1 {
+                    result = reduce(result.checked_mul(base).ok_or_else(|| Error::from(ErrorKind::Overflow))?);
+                }
+
+                exponent >>= 1;
+                if exponent > 0 {
+                    base = reduce(base.checked_mul(base).ok_or_else(|| Error::from(ErrorKind::Overflow))?);
+                }
+            }
+
+            Ok(result)
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::PowMod.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::PowMod.category().into())
+        }
+    }
+
+    pub struct FloorFunction;
+    #[cfg(not(feature = "docs"))]
+    forward_func_impl!(FloorFunction, floor);
+    #[cfg(feature = "docs")]
+    forward_func_impl!(FloorFunction, floor, Description::Floor);
+
+    pub struct CeilFunction;
+    #[cfg(not(feature = "docs"))]
+    forward_func_impl!(CeilFunction, ceil);
+    #[cfg(feature = "docs")]
+    forward_func_impl!(CeilFunction, ceil, Description::Ceil);
+
+    pub struct TruncateFunction;
+    #[cfg(not(feature = "docs"))]
+    forward_func_impl!(TruncateFunction, trunc, truncate);
+    #[cfg(feature = "docs")]
+    forward_func_impl!(TruncateFunction, trunc, truncate, Description::Truncate);
+
+    #[inline]
+    fn round_with_strategy(args: &[Decimal], strategy: RoundingStrategy) -> Result<Decimal> {
+        match args.len() {
+            1 => Ok(args[0].round_dp_with_strategy(0, strategy)),
+            _ => Err(Error::from(ErrorKind::InvalidArgumentCount)),
+        }
+    }
+
+    /// Rounds a value to the nearest integer, half-way values are rounded to the nearest
+    /// even number (banker's rounding), eg: `round(2.5) = 2`, `round(3.5) = 4`.
+    pub struct RoundFunction;
+    impl Function<Decimal> for RoundFunction {
+        #[inline]
+        fn name(&self) -> &str {
+            "round"
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::Exactly(1)
+        }
+
+        #[inline]
+        fn call(&self, args: &[Decimal]) -> Result<Decimal> {
+            round_with_strategy(args, RoundingStrategy::MidpointNearestEven)
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::Round.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Round.category().into())
+        }
+    }
+
+    /// Rounds a value to the nearest integer, half-way values are rounded toward positive infinity,
+    /// eg: `round_up(2.5) = 3`, `round_up(-2.5) = -2`.
+    pub struct RoundUpFunction;
+    impl Function<Decimal> for RoundUpFunction {
+        #[inline]
+        fn name(&self) -> &str {
+            "round_up"
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::Exactly(1)
+        }
+
+        #[inline]
+        fn call(&self, args: &[Decimal]) -> Result<Decimal> {
+            match args.first() {
+                Some(n) if n.is_sign_negative() => {
+                    round_with_strategy(args, RoundingStrategy::MidpointTowardZero)
+                }
+                _ => round_with_strategy(args, RoundingStrategy::MidpointAwayFromZero),
+            }
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::RoundUp.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::RoundUp.category().into())
+        }
+    }
+
+    /// Rounds a value to the nearest integer, half-way values are rounded away from zero,
+    /// eg: `round(2.5) = 3`, `round(-2.5) = -3`.
+    pub struct RoundHalfAwayFromZeroFunction;
+    impl Function<Decimal> for RoundHalfAwayFromZeroFunction {
+        #[inline]
+        fn name(&self) -> &str {
+            "round"
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::Exactly(1)
+        }
+
+        #[inline]
+        fn call(&self, args: &[Decimal]) -> Result<Decimal> {
+            round_with_strategy(args, RoundingStrategy::MidpointAwayFromZero)
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::Round.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Round.category().into())
+        }
+    }
+
+    /// Rounds a value to the nearest integer, half-way values are always rounded away from zero
+    /// regardless of the `Config`, eg: `round_away(2.5) = 3`, `round_away(-2.5) = -3`.
+    pub struct RoundAwayFunction;
+    impl Function<Decimal> for RoundAwayFunction {
+        #[inline]
+        fn name(&self) -> &str {
+            "round_away"
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::Exactly(1)
+        }
+
+        #[inline]
+        fn call(&self, args: &[Decimal]) -> Result<Decimal> {
+            round_with_strategy(args, RoundingStrategy::MidpointAwayFromZero)
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::RoundAway.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::RoundAway.category().into())
+        }
+    }
+
+    fn round_to_step(args: &[Decimal], strategy: RoundingStrategy) -> Result<Decimal> {
+        if args.len() != 2 {
+            return Err(Error::from(ErrorKind::InvalidArgumentCount));
+        }
+
+        let (value, step) = (args[0], args[1]);
+
+        if step.is_zero() {
+            return Err(Error::new(ErrorKind::Zero, "The step cannot be zero"));
+        }
+
+        if step.is_sign_negative() {
+            return Err(Error::new(ErrorKind::NegativeValue, "The step cannot be negative"));
+        }
+
+        value
+            .checked_div(step)
+            .map(|n| n.round_dp_with_strategy(0, strategy))
+            .and_then(|n| n.checked_mul(step))
+            .ok_or_else(|| Error::from(ErrorKind::Overflow))
+    }
+
+    /// Rounds a value to the nearest multiple of the given step, half-way values are rounded
+    /// away from zero, eg: `round_to(1.23, 0.05) = 1.25`.
+    pub struct RoundToFunction;
+    impl Function<Decimal> for RoundToFunction {
+        #[inline]
+        fn name(&self) -> &str {
+            "round_to"
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::Exactly(2)
+        }
+
+        #[inline]
+        fn call(&self, args: &[Decimal]) -> Result<Decimal> {
+            round_to_step(args, RoundingStrategy::MidpointAwayFromZero)
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::RoundTo.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::RoundTo.category().into())
+        }
+    }
+
+    /// Rounds a value down to the nearest multiple of the given step, eg: `floor_to(1.29, 0.1) = 1.2`.
+    pub struct FloorToFunction;
+    impl Function<Decimal> for FloorToFunction {
+        #[inline]
+        fn name(&self) -> &str {
+            "floor_to"
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::Exactly(2)
+        }
+
+        #[inline]
+        fn call(&self, args: &[Decimal]) -> Result<Decimal> {
+            round_to_step(args, RoundingStrategy::ToNegativeInfinity)
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::FloorTo.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::FloorTo.category().into())
+        }
+    }
+
+    /// Rounds a value up to the nearest multiple of the given step, eg: `ceil_to(1.21, 0.25) = 1.25`.
+    pub struct CeilToFunction;
+    impl Function<Decimal> for CeilToFunction {
+        #[inline]
+        fn name(&self) -> &str {
+            "ceil_to"
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::Exactly(2)
+        }
+
+        #[inline]
+        fn call(&self, args: &[Decimal]) -> Result<Decimal> {
+            round_to_step(args, RoundingStrategy::ToPositiveInfinity)
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::CeilTo.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::CeilTo.category().into())
+        }
+    }
+
+    /// Rounds a value to the given number of significant figures,
+    /// eg: `sigfig(12345, 2) = 12000`, `sigfig(0.0012345, 2) = 0.0012`.
+    pub struct SigFigFunction;
+    impl Function<Decimal> for SigFigFunction {
+        #[inline]
+        fn name(&self) -> &str {
+            "sigfig"
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::Exactly(2)
+        }
+
+        fn call(&self, args: &[Decimal]) -> Result<Decimal> {
+            if args.len() != 2 {
+                return Err(Error::from(ErrorKind::InvalidArgumentCount));
+            }
+
+            let (value, figures) = (args[0], args[1]);
+            if !figures.is_integer() || figures <= Decimal::zero() {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("Expected a positive integer number of significant figures but was {}", figures),
+                ));
+            }
+
+            if value.is_zero() {
+                return Ok(value);
+            }
+
+            let figures = figures.to_i64().ok_or_else(|| Error::from(ErrorKind::Overflow))?;
+            let abs = value.abs();
+            let pow10 = |exponent: i64| {
+                consts::TEN
+                    .checked_powi(exponent)
+                    .ok_or_else(|| Error::from(ErrorKind::Overflow))
+            };
+
+            // The magnitude of the value: floor(log10(|value|)), the logarithm is an approximation
+            // so is corrected to ensure: 10^magnitude <= |value| < 10^(magnitude + 1)
+            let mut magnitude = abs
+                .checked_log(consts::TEN)
+                .and_then(|n| n.floor().to_i64())
+                .ok_or_else(|| Error::from(ErrorKind::Overflow))?;
+
+            if pow10(magnitude)? > abs {
+                magnitude -= 1;
+            } else if pow10(magnitude + 1)? <= abs {
+                magnitude += 1;
+            }
+
+            let decimal_places = figures - 1 - magnitude;
+            if decimal_places >= 0 {
+                let decimal_places = decimal_places
+                    .to_u32()
+                    .ok_or_else(|| Error::from(ErrorKind::Overflow))?;
+
+                Ok(value.round_dp_with_strategy(decimal_places, RoundingStrategy::MidpointAwayFromZero))
+            } else {
+                let scale = pow10(-decimal_places)?;
+                let scaled = (value / scale).round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero);
+                scaled.checked_mul(scale).ok_or_else(|| Error::from(ErrorKind::Overflow))
+            }
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::SigFig.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::SigFig.category().into())
+        }
+    }
+
+    /// Rounds a value to 2 decimal places or the given number of places, half-way values are
+    /// rounded away from zero, eg: `money(1.005) = 1.01`, `money(2.3456, 3) = 2.346`.
+    ///
+    /// # Remarks
+    /// This only rounds the value, it's not aware of any currency.
+    pub struct MoneyFunction;
+    impl Function<Decimal> for MoneyFunction {
+        #[inline]
+        fn name(&self) -> &str {
+            "money"
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::Range(1, 2)
+        }
+
+        fn call(&self, args: &[Decimal]) -> Result<Decimal> {
+            const DEFAULT_PLACES: u32 = 2;
+
+            let places = match args.len() {
+                1 => DEFAULT_PLACES,
+                2 => {
+                    let places = args[1];
+                    if !places.is_integer() || places.is_sign_negative() {
+                        return Err(Error::new(
+                            ErrorKind::InvalidInput,
+                            format!("Expected a non-negative integer number of decimal places but was {}", places),
+                        ));
+                    }
+
+                    places.to_u32().ok_or_else(|| Error::from(ErrorKind::Overflow))?
+                }
+                _ => return Err(Error::from(ErrorKind::InvalidArgumentCount)),
+            };
+
+            Ok(args[0].round_dp_with_strategy(places, RoundingStrategy::MidpointAwayFromZero))
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::Money.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Money.category().into())
+        }
+    }
+
+    pub struct SqrtFunction;
+    #[cfg(not(feature = "docs"))]
+    forward_checked_func_impl!(SqrtFunction, checked_sqrt, sqrt);
+    #[cfg(feature = "docs")]
+    forward_checked_func_impl!(SqrtFunction, checked_sqrt, sqrt, Description::Sqrt);
+
+    /// The square root using at most the given number of Newton iterations,
+    /// used instead of `SqrtFunction` when `Config::decimal_sqrt_iterations` is set.
+    pub struct SqrtPrecFunction(pub u32);
+    impl Function<Decimal> for SqrtPrecFunction {
+        #[inline]
+        fn name(&self) -> &str {
+            "sqrt"
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::Exactly(1)
+        }
+
+        #[inline]
+        fn call(&self, args: &[Decimal]) -> Result<Decimal> {
+            match args.len() {
+                1 => args[0]
+                    .checked_sqrt_prec(self.0)
+                    .ok_or_else(|| Error::from(ErrorKind::Overflow)),
+                _ => Err(Error::from(ErrorKind::InvalidArgumentCount)),
+            }
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::Sqrt.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Sqrt.category().into())
+        }
+    }
+
+    pub struct CbrtFunction;
+    #[cfg(not(feature = "docs"))]
+    forward_checked_func_impl!(CbrtFunction, checked_cbrt, cbrt);
+    #[cfg(feature = "docs")]
+    forward_checked_func_impl!(CbrtFunction, checked_cbrt, cbrt, Description::Cbrt);
+
+    pub struct ExpFunction;
+    #[cfg(not(feature = "docs"))]
+    forward_checked_func_impl!(ExpFunction, checked_exp, exp);
+    #[cfg(feature = "docs")]
+    forward_checked_func_impl!(ExpFunction, checked_exp, exp, Description::Exp);
+
+    macro_rules! base_pow_func_impl {
+        ($func_name:ident, $name:expr, $base:expr, $description:expr) => {
+            impl Function<Decimal> for $func_name {
+                fn name(&self) -> &str {
+                    $name
+                }
+
+                fn arity(&self) -> Arity {
+                    Arity::Exactly(1)
+                }
+
+                fn call(&self, args: &[Decimal]) -> Result<Decimal> {
+                    if args.len() != 1 {
+                        return Err(Error::from(ErrorKind::InvalidArgumentCount));
+                    }
+
+                    Decimal::from($base)
+                        .checked_powd(args[0])
+                        .ok_or_else(|| Error::from(ErrorKind::Overflow))
+                }
+
+                #[cfg(feature = "docs")]
+                fn description(&self) -> Option<&str> {
+                    Some($description.into())
+                }
+
+                #[cfg(feature = "docs")]
+                fn category(&self) -> Option<&str> {
+                    Some($description.category().into())
+                }
+            }
+        };
+    }
+
+    pub struct Pow10Function;
+    base_pow_func_impl!(Pow10Function, "pow10", 10, Description::Pow10);
+
+    pub struct Exp2Function;
+    base_pow_func_impl!(Exp2Function, "exp2", 2, Description::Exp2);
+
+    pub struct LnFunction;
+    #[cfg(not(feature = "docs"))]
+    forward_checked_func_impl!(LnFunction, checked_ln, ln);
+    #[cfg(feature = "docs")]
+    forward_checked_func_impl!(LnFunction, checked_ln, ln, Description::Ln);
+
+    pub struct LogFunction;
+    impl Function<Decimal> for LogFunction {
+        fn name(&self) -> &str {
+            "log"
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::Range(1, 2)
+        }
+
+        fn call(&self, args: &[Decimal]) -> Result<Decimal> {
+            match args.len() {
+                1 => args[0]
+                    .checked_log(consts::TEN)
+                    .ok_or_else(|| Error::from(ErrorKind::Overflow)),
+                2 => args[0]
+                    .checked_log(args[1])
+                    .ok_or_else(|| Error::from(ErrorKind::Overflow)),
+                _ => Err(Error::from(ErrorKind::InvalidArgumentCount)),
+            }
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::Log.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Log.category().into())
+        }
+    }
+
+    macro_rules! base_log_func_impl {
+        ($func_name:ident, $name:literal, $base:expr, $description:expr) => {
+            pub struct $func_name;
+            impl Function<Decimal> for $func_name {
+                fn name(&self) -> &str {
+                    $name
+                }
+
+                fn arity(&self) -> Arity {
+                    Arity::Exactly(1)
+                }
+
+                fn call(&self, args: &[Decimal]) -> Result<Decimal> {
+                    match args {
+                        [value] => value
+                            .checked_log($base)
+                            .ok_or_else(|| Error::from(ErrorKind::Overflow)),
+                        _ => Err(Error::from(ErrorKind::InvalidArgumentCount)),
+                    }
+                }
+
+                #[cfg(feature = "docs")]
+                fn description(&self) -> Option<&str> {
+                    Some($description.into())
+                }
+
+                #[cfg(feature = "docs")]
+                fn category(&self) -> Option<&str> {
+                    Some($description.category().into())
+                }
+            }
+        };
+    }
+
+    base_log_func_impl!(Log2Function, "log2", consts::TWO, Description::Log2);
+    base_log_func_impl!(Log10Function, "log10", consts::TEN, Description::Log10);
+
+    /// Logarithm in the given base, which goes first as in `log_b(x)`: `logb(base, x)`.
+    pub struct LogBFunction;
+    impl Function<Decimal> for LogBFunction {
+        fn name(&self) -> &str {
+            "logb"
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::Exactly(2)
+        }
+
+        fn call(&self, args: &[Decimal]) -> Result<Decimal> {
+            match args {
+                [base, value] => value
+                    .checked_log(*base)
+                    .ok_or_else(|| Error::from(ErrorKind::Overflow)),
+                _ => Err(Error::from(ErrorKind::InvalidArgumentCount)),
+            }
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::LogB.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::LogB.category().into())
+        }
+    }
+
+    pub struct ToRadiansFunction;
+    #[cfg(not(feature = "docs"))]
+    forward_func_impl!(ToRadiansFunction, to_radians, to_radians);
+    #[cfg(feature = "docs")]
+    forward_func_impl!(ToRadiansFunction, to_radians, to_radians, Description::ToRadians);
+
+    pub struct ToDegreesFunction;
+    #[cfg(not(feature = "docs"))]
+    forward_func_impl!(ToDegreesFunction, to_degrees, to_degrees);
+    #[cfg(feature = "docs")]
+    forward_func_impl!(ToDegreesFunction, to_degrees, to_degrees, Description::ToDegrees);
+}
+
+mod trig_ops {
+    use crate::decimal::decimal_ext::DecimalExt;
+    use crate::error::*;
+    use crate::function::{Arity, Function};
+    use crate::Result;
+    use rust_decimal::Decimal;
+
+    #[cfg(feature = "docs")]
+    use crate::descriptions::Description;
+
+    #[cfg(not(feature = "docs"))]
+    macro_rules! impl_checked_trig {
+        ($struct_name:ident, $method_name:ident, $name:ident) => {
+            impl Function<Decimal> for $struct_name {
+                #[inline]
+                fn name(&self) -> &str {
+                    stringify!($name)
+                }
+
+                fn arity(&self) -> Arity {
+                    Arity::Exactly(1)
+                }
+
+                #[inline]
+                fn call(&self, args: &[Decimal]) -> Result<Decimal> {
+                    match args.len() {
+                        1 => args[0]
+                            .to_radians()
+                            .$method_name()
+                            .ok_or_else(|| Error::from(ErrorKind::Overflow)),
+                        _ => Err(Error::from(ErrorKind::InvalidArgumentCount)),
+                    }
+                }
+            }
+        };
+
+        ($struct_name:ident, $method_name:ident) => {
+            impl_checked_trig!($struct_name, $method_name, $method_name);
+        };
+    }
+
+    #[cfg(not(feature = "docs"))]
+    macro_rules! impl_checked_trig_rec {
+        ($struct_name:ident, $method_name:ident, $name:ident) => {
+            impl Function<Decimal> for $struct_name {
+                #[inline]
+                fn name(&self) -> &str {
+                    stringify!($name)
+                }
+
+                fn arity(&self) -> Arity {
+                    Arity::Exactly(1)
+                }
+
+                #[inline]
+                fn call(&self, args: &[Decimal]) -> Result<Decimal> {
+                    match args.len() {
+                        1 => args[0]
+                            .to_radians()
+                            .$method_name()
+                            //.map(Decimal::checked_inv)
+                            .ok_or_else(|| Error::from(ErrorKind::Overflow)),
+                        _ => Err(Error::from(ErrorKind::InvalidArgumentCount)),
+                    }
+                }
+            }
+        };
+
+        ($struct_name:ident, $method_name:ident) => {
+            impl_checked_trig_rec!($struct_name, $method_name, $method_name);
+        };
+    }
+
+    #[cfg(not(feature = "docs"))]
     macro_rules! impl_trig {
         ($struct_name:ident, $method_name:ident, $name:ident) => {
             impl Function<Decimal> for $struct_name {
@@ -769,6 +1878,10 @@ mod trig_ops {
                     stringify!($name)
                 }
 
+                fn arity(&self) -> Arity {
+                    Arity::Exactly(1)
+                }
+
                 #[inline]
                 fn call(&self, args: &[Decimal]) -> Result<Decimal> {
                     match args.len() {
@@ -793,6 +1906,10 @@ mod trig_ops {
                     stringify!($name)
                 }
 
+                fn arity(&self) -> Arity {
+                    Arity::Exactly(1)
+                }
+
                 #[inline]
                 fn call(&self, args: &[Decimal]) -> Result<Decimal> {
                     match args.len() {
@@ -817,6 +1934,10 @@ mod trig_ops {
                     stringify!($name)
                 }
 
+                fn arity(&self) -> Arity {
+                    Arity::Exactly(1)
+                }
+
                 #[inline]
                 fn call(&self, args: &[Decimal]) -> Result<Decimal> {
                     match args.len() {
@@ -831,6 +1952,10 @@ mod trig_ops {
                 fn description(&self) -> Option<&str> {
                     Some($description.into())
                 }
+
+                fn category(&self) -> Option<&str> {
+                    Some($description.category().into())
+                }
             }
         };
 
@@ -848,6 +1973,10 @@ mod trig_ops {
                     stringify!($name)
                 }
 
+                fn arity(&self) -> Arity {
+                    Arity::Exactly(1)
+                }
+
                 #[inline]
                 fn call(&self, args: &[Decimal]) -> Result<Decimal> {
                     match args.len() {
@@ -863,6 +1992,10 @@ mod trig_ops {
                 fn description(&self) -> Option<&str> {
                     Some($description.into())
                 }
+
+                fn category(&self) -> Option<&str> {
+                    Some($description.category().into())
+                }
             }
         };
 
@@ -917,6 +2050,10 @@ mod trig_ops {
                     stringify!($name)
                 }
 
+                fn arity(&self) -> Arity {
+                    Arity::Exactly(1)
+                }
+
                 #[inline]
                 fn call(&self, args: &[Decimal]) -> Result<Decimal> {
                     match args.len() {
@@ -941,6 +2078,10 @@ mod trig_ops {
                     stringify!($name)
                 }
 
+                fn arity(&self) -> Arity {
+                    Arity::Exactly(1)
+                }
+
                 #[inline]
                 fn call(&self, args: &[Decimal]) -> Result<Decimal> {
                     match args.len() {
@@ -968,6 +2109,10 @@ mod trig_ops {
                     stringify!($name)
                 }
 
+                fn arity(&self) -> Arity {
+                    Arity::Exactly(1)
+                }
+
                 #[inline]
                 fn call(&self, args: &[Decimal]) -> Result<Decimal> {
                     match args.len() {
@@ -979,6 +2124,10 @@ mod trig_ops {
                 fn description(&self) -> Option<&str> {
                     Some($description.into())
                 }
+
+                fn category(&self) -> Option<&str> {
+                    Some($description.category().into())
+                }
             }
         };
 
@@ -996,6 +2145,10 @@ mod trig_ops {
                     stringify!($name)
                 }
 
+                fn arity(&self) -> Arity {
+                    Arity::Exactly(1)
+                }
+
                 #[inline]
                 fn call(&self, args: &[Decimal]) -> Result<Decimal> {
                     match args.len() {
@@ -1010,6 +2163,10 @@ mod trig_ops {
                 fn description(&self) -> Option<&str> {
                     Some($description.into())
                 }
+
+                fn category(&self) -> Option<&str> {
+                    Some($description.category().into())
+                }
             }
         };
 
@@ -1037,6 +2194,10 @@ mod trig_ops {
             stringify!(atan)
         }
 
+        fn arity(&self) -> Arity {
+            Arity::Range(1, 2)
+        }
+
         #[inline]
         fn call(&self, args: &[Decimal]) -> Result<Decimal> {
             match args.len() {
@@ -1050,6 +2211,11 @@ mod trig_ops {
         fn description(&self) -> Option<&str> {
             Some(Description::ATan.into())
         }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::ATan.category().into())
+        }
     }
 
     pub struct ACscFunction;
@@ -1149,7 +2315,10 @@ pub mod context {
     use crate::context::{Config, Context, DefaultContext};
     use crate::decimal::consts;
     use crate::decimal::ops::*;
-    use crate::ops::math::{MaxFunction, MinFunction, RandFunction, UnaryPlus};
+    use crate::ops::math::{
+        AndFunction, ClampFunction, Clamp01Function, IfFunction, MaxFunction, MaxIgnoreNanFunction, MinFunction, MinIgnoreNanFunction,
+        NotFunction, OrFunction, RandFunction, StepFunction, UnaryPlus, WrapFunction,
+    };
     use rust_decimal::Decimal;
 
     impl<'a> DefaultContext<'a, Decimal> {
@@ -1168,24 +2337,67 @@ pub mod context {
             context.add_binary_function(DivOperator).unwrap();
             context.add_binary_function(PowOperator).unwrap();
             context.add_binary_function(ModOperator).unwrap();
+            context.add_operator_unless_grouping(EqOperator).unwrap();
+            context.add_operator_unless_grouping(NeOperator).unwrap();
+            context.add_operator_unless_grouping(GtOperator).unwrap();
+            context.add_operator_unless_grouping(LtOperator).unwrap();
+            context.add_operator_unless_grouping(GteOperator).unwrap();
+            context.add_operator_unless_grouping(LteOperator).unwrap();
             context.add_unary_function(UnaryPlus).unwrap();
             context.add_unary_function(UnaryMinus).unwrap();
             context.add_unary_function(Factorial).unwrap();
             context.add_function(SumFunction).unwrap();
             context.add_function(AvgFunction).unwrap();
+            context.add_function(GeoMeanFunction).unwrap();
+            context.add_function(HarMeanFunction).unwrap();
+            context.add_function(PercentChangeFunction).unwrap();
+            context.add_function(PercentOfFunction).unwrap();
+            context.add_function(ExactDivFunction).unwrap();
+            context.add_function(PowModFunction).unwrap();
             context.add_function(ProdFunction).unwrap();
             context.add_function(MaxFunction).unwrap();
             context.add_function(MinFunction).unwrap();
+            context.add_function(MaxIgnoreNanFunction).unwrap();
+            context.add_function(MinIgnoreNanFunction).unwrap();
+            context.add_function(PercentileFunction).unwrap();
+            context.add_function(IfFunction).unwrap();
+            context.add_function(AndFunction).unwrap();
+            context.add_function(OrFunction).unwrap();
+            context.add_function(NotFunction).unwrap();
+            context.add_function(WrapFunction).unwrap();
+            context.add_function(ClampFunction).unwrap();
+            context.add_function(Clamp01Function).unwrap();
+            context.add_function(StepFunction).unwrap();
+            context.add_function(SmoothStepFunction).unwrap();
             context.add_function(CbrtFunction).unwrap();
-            context.add_function(SqrtFunction).unwrap();
+            match context.config().decimal_sqrt_iterations {
+                Some(iterations) => context.add_function(SqrtPrecFunction(iterations)).unwrap(),
+                None => context.add_function(SqrtFunction).unwrap(),
+            }
             context.add_function(LnFunction).unwrap();
             context.add_function(LogFunction).unwrap();
+            context.add_function(Log2Function).unwrap();
+            context.add_function(Log10Function).unwrap();
+            context.add_function(LogBFunction).unwrap();
             context.add_function(RandFunction).unwrap();
             context.add_function(CeilFunction).unwrap();
             context.add_function(FloorFunction).unwrap();
             context.add_function(TruncateFunction).unwrap();
-            context.add_function(RoundFunction).unwrap();
+            if context.config().round_half_away_from_zero {
+                context.add_function(RoundHalfAwayFromZeroFunction).unwrap();
+            } else {
+                context.add_function(RoundFunction).unwrap();
+            }
+            context.add_function(RoundUpFunction).unwrap();
+            context.add_function(RoundAwayFunction).unwrap();
+            context.add_function(RoundToFunction).unwrap();
+            context.add_function(FloorToFunction).unwrap();
+            context.add_function(CeilToFunction).unwrap();
+            context.add_function(SigFigFunction).unwrap();
+            context.add_function(MoneyFunction).unwrap();
             context.add_function(ExpFunction).unwrap();
+            context.add_function(Exp2Function).unwrap();
+            context.add_function(Pow10Function).unwrap();
             context.add_function(ToRadiansFunction).unwrap();
             context.add_function(ToDegreesFunction).unwrap();
             context.add_function(SinFunction).unwrap();
@@ -1219,7 +2431,8 @@ pub mod context {
 
 #[cfg(test)]
 mod tests {
-    use crate::context::DefaultContext;
+    use crate::context::{Context, DefaultContext};
+    use crate::error::ErrorKind;
     use crate::evaluator::Evaluator;
     use super::*;
 
@@ -1231,4 +2444,323 @@ mod tests {
         let result = evaluator.eval(expr).unwrap();
         assert_eq!(result, Decimal::from(9));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn sum_product_overflow_test() {
+        let mut context = DefaultContext::new_decimal();
+        context.set_variable("x", Decimal::MAX).unwrap();
+
+        let evaluator = Evaluator::with_context(context);
+        assert_eq!(evaluator.eval("sum(x, -1, 1)"), Ok(Decimal::MAX));
+        assert_eq!(evaluator.eval("sum(x, 1)").unwrap_err().kind(), ErrorKind::Overflow);
+        assert_eq!(evaluator.eval("sum(-x, -1)").unwrap_err().kind(), ErrorKind::Overflow);
+        assert_eq!(evaluator.eval("avg(x, x)").unwrap_err().kind(), ErrorKind::Overflow);
+        assert_eq!(evaluator.eval("product(x, 1)"), Ok(Decimal::MAX));
+        assert_eq!(evaluator.eval("product(x, 2)").unwrap_err().kind(), ErrorKind::Overflow);
+    }
+
+    #[test]
+    fn angle_grouping_conflict_test() {
+        use crate::context::{Config, Grouping};
+        use crate::decimal::math_ops::{GtOperator, GteOperator};
+
+        let mut context = DefaultContext::with_config(Config::new().with_grouping(Grouping::Angle));
+        let error = context.add_binary_function(GtOperator).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+        assert!(context.add_binary_function(GteOperator).is_ok());
+    }
+
+    #[test]
+    fn percentile_test() {
+        let evaluator = Evaluator::with_context(DefaultContext::new_decimal());
+        assert_eq!(evaluator.eval("percentile(50, 4, 1, 3, 2)"), Ok(Decimal::new(25, 1)));
+        assert_eq!(evaluator.eval("percentile(0, 4, 1, 3, 2)"), Ok(Decimal::from(1)));
+        assert_eq!(evaluator.eval("percentile(100, 4, 1, 3, 2)"), Ok(Decimal::from(4)));
+        assert_eq!(evaluator.eval("percentile(10, 0.1, 0.2)"), Ok(Decimal::new(11, 2)));
+        assert!(evaluator.eval("percentile(150, 1, 2)").is_err());
+        assert_eq!(evaluator.eval("percentile(50, 79228162514264337593543950335, -79228162514264337593543950335)").unwrap_err().kind(), ErrorKind::Overflow);
+    }
+
+    #[test]
+    fn if_test() {
+        let evaluator = Evaluator::with_context(DefaultContext::new_decimal());
+        assert_eq!(evaluator.eval("if(1, 2, 1/0)"), Ok(Decimal::from(2)));
+        assert_eq!(evaluator.eval("ternary(2 > 3, 1/0, 3)"), Ok(Decimal::from(3)));
+        assert!(evaluator.eval("if(0, 2, 1/0)").is_err());
+    }
+
+    #[test]
+    fn constants_as_f64_test() {
+        let context = DefaultContext::new_decimal();
+        let constants = context.constants_as_f64();
+        let (_, pi) = constants.iter().find(|(name, _)| name == "PI").unwrap();
+
+        assert!((pi - std::f64::consts::PI).abs() < 0.00001);
+        assert_eq!(constants.len(), context.constants().len());
+    }
+
+    #[test]
+    fn comparison_test() {
+        let evaluator = Evaluator::with_context(DefaultContext::new_decimal());
+        let (one, zero) = (Decimal::from(1), Decimal::from(0));
+
+        assert_eq!(evaluator.eval("1.5 > 1.2"), Ok(one));
+        assert_eq!(evaluator.eval("1.5 < 1.2"), Ok(zero));
+        assert_eq!(evaluator.eval("1.5 >= 1.5"), Ok(one));
+        assert_eq!(evaluator.eval("1.2 <= 1.1"), Ok(zero));
+        assert_eq!(evaluator.eval("2 != 3"), Ok(one));
+        assert_eq!(evaluator.eval("2 ne 2"), Ok(zero));
+
+        // Decimals are exact for base 10 fractions, unlike floating point numbers
+        assert_eq!(evaluator.eval("0.1 + 0.2 == 0.3"), Ok(one));
+        assert_eq!(evaluator.eval("0.1 + 0.2 eq 0.3"), Ok(one));
+        assert_eq!(evaluator.eval("1 / 3 * 3 == 1"), Ok(zero));
+    }
+
+    #[test]
+    fn max_min_preserves_scale_test() {
+        let evaluator = Evaluator::with_context(DefaultContext::new_decimal());
+
+        let max = evaluator.eval("max(1.50, 1.5)").unwrap();
+        assert_eq!(max.to_string(), "1.50");
+        assert_eq!(max.scale(), 2);
+
+        let max = evaluator.eval("max(1.5, 1.50, 0.25)").unwrap();
+        assert_eq!(max.to_string(), "1.5");
+
+        let min = evaluator.eval("min(2.000, 3, 2)").unwrap();
+        assert_eq!(min.to_string(), "2.000");
+        assert_eq!(min.scale(), 3);
+    }
+
+    #[test]
+    fn pow_negative_base_test() {
+        let evaluator = Evaluator::with_context(DefaultContext::new_decimal());
+        let delta = Decimal::new(1, 10);
+
+        assert!((evaluator.eval("(-8)^(1/3)").unwrap() - Decimal::from(-2)).abs() < delta);
+        assert!((evaluator.eval("(-8)^(2/3)").unwrap() - Decimal::from(4)).abs() < delta);
+        assert_eq!(evaluator.eval("(-2)^3"), Ok(Decimal::from(-8)));
+        assert_eq!(evaluator.eval("(-4)^(1/2)").unwrap_err().kind(), ErrorKind::NegativeValue);
+    }
+
+    #[test]
+    fn geomean_test() {
+        let evaluator = Evaluator::with_context(DefaultContext::new_decimal());
+
+        assert_eq!(evaluator.eval("geomean(2, 8)"), Ok(Decimal::from(4)));
+        assert_eq!(evaluator.eval("geomean(3)"), Ok(Decimal::from(3)));
+        assert!((evaluator.eval("geomean(1, 4, 16)").unwrap() - Decimal::from(4)).abs() < Decimal::new(1, 10));
+
+        assert_eq!(evaluator.eval("geomean(2, 0)").unwrap_err().kind(), ErrorKind::Zero);
+        assert_eq!(evaluator.eval("geomean(2, -8)").unwrap_err().kind(), ErrorKind::NegativeValue);
+    }
+
+    #[test]
+    fn harmean_test() {
+        let evaluator = Evaluator::with_context(DefaultContext::new_decimal());
+
+        // 3 / (1/1 + 1/2 + 1/4)
+        assert_eq!(
+            evaluator.eval("harmean(1, 2, 4)"),
+            Ok(Decimal::from(3) / Decimal::new(175, 2))
+        );
+        assert_eq!(evaluator.eval("harmean(2, 0)").unwrap_err().kind(), ErrorKind::DivisionByZero);
+    }
+
+    #[test]
+    fn percent_test() {
+        let evaluator = Evaluator::with_context(DefaultContext::new_decimal());
+
+        assert_eq!(evaluator.eval("pctchange(100, 150)"), Ok(Decimal::from(50)));
+        assert_eq!(evaluator.eval("pctchange(200, 150)"), Ok(Decimal::from(-25)));
+        assert_eq!(evaluator.eval("pctof(20, 200)"), Ok(Decimal::from(10)));
+        assert_eq!(evaluator.eval("pctof(3, 8)"), Ok(Decimal::new(375, 1)));
+
+        assert_eq!(evaluator.eval("pctchange(0, 150)").unwrap_err().kind(), ErrorKind::DivisionByZero);
+        assert_eq!(evaluator.eval("pctof(20, 0)").unwrap_err().kind(), ErrorKind::DivisionByZero);
+    }
+
+    #[test]
+    fn pow10_exp2_test() {
+        let evaluator = Evaluator::with_context(DefaultContext::new_decimal());
+
+        assert_eq!(evaluator.eval("pow10(3)"), Ok(Decimal::from(1000)));
+        assert_eq!(evaluator.eval("pow10(-2)"), Ok(Decimal::new(1, 2)));
+        assert_eq!(evaluator.eval("exp2(10)"), Ok(Decimal::from(1024)));
+        assert_eq!(evaluator.eval("exp2(-1)"), Ok(Decimal::new(5, 1)));
+
+        assert_eq!(evaluator.eval("pow10(30)").unwrap_err().kind(), ErrorKind::Overflow);
+    }
+
+    #[test]
+    fn wrap_test() {
+        let evaluator = Evaluator::with_context(DefaultContext::new_decimal());
+
+        assert_eq!(evaluator.eval("wrap(370, 360)"), Ok(Decimal::from(10)));
+        assert_eq!(evaluator.eval("wrap(-30, 360)"), Ok(Decimal::from(330)));
+        assert_eq!(evaluator.eval("wrap(-7.5, 2)"), Ok(Decimal::new(5, 1)));
+        assert!(evaluator.eval("wrap(1, 0)").is_err());
+    }
+
+    #[test]
+    fn clamp01_step_smoothstep_test() {
+        let evaluator = Evaluator::with_context(DefaultContext::new_decimal());
+
+        assert_eq!(evaluator.eval("step(0.5, 0.7)"), Ok(Decimal::from(1)));
+        assert_eq!(evaluator.eval("step(0.5, 0.3)"), Ok(Decimal::from(0)));
+        assert_eq!(evaluator.eval("clamp01(1.5)"), Ok(Decimal::from(1)));
+        assert_eq!(evaluator.eval("saturate(-2)"), Ok(Decimal::from(0)));
+        assert_eq!(evaluator.eval("clamp01(0.3)"), Ok(Decimal::new(3, 1)));
+        assert_eq!(evaluator.eval("smoothstep(0, 1, 0.5)"), Ok(Decimal::new(5, 1)));
+        assert_eq!(evaluator.eval("smoothstep(0, 10, 2.5)"), Ok(Decimal::new(15625, 5)));
+        assert!(evaluator.eval("smoothstep(1, 1, 0.5)").is_err());
+    }
+
+    #[test]
+    fn log_test() {
+        let evaluator = Evaluator::with_context(DefaultContext::new_decimal());
+
+        assert_eq!(evaluator.eval("log(100)"), Ok(Decimal::from(2)));
+        assert_eq!(evaluator.eval("log(8, 2)"), Ok(Decimal::from(3)));
+        assert_eq!(evaluator.eval("log(1000)"), Ok(Decimal::from(3)));
+        assert_eq!(evaluator.eval("log(81, 3)"), Ok(Decimal::from(4)));
+        assert!(evaluator.eval("log(0)").is_err());
+
+        assert_eq!(evaluator.eval("log2(32)"), Ok(Decimal::from(5)));
+        assert_eq!(evaluator.eval("log10(1000)"), Ok(Decimal::from(3)));
+        assert_eq!(evaluator.eval("ln(1)"), Ok(Decimal::from(0)));
+
+        // The base goes first in `logb` and last in `log`
+        assert_eq!(evaluator.eval("logb(2, 8)"), Ok(Decimal::from(3)));
+        assert_eq!(evaluator.eval("logb(3, 20)"), evaluator.eval("log(20, 3)"));
+        assert!(evaluator.eval("logb(2, 0)").is_err());
+    }
+
+    #[test]
+    fn pow_mod_test() {
+        let evaluator = Evaluator::with_context(DefaultContext::new_decimal());
+
+        assert_eq!(evaluator.eval("powmod(3, 4, 5)"), Ok(Decimal::from(1)));
+        assert_eq!(evaluator.eval("powmod(2, 100, 1000000007)"), Ok(Decimal::from(976371285)));
+        assert!(evaluator.eval("powmod(2.5, 2, 5)").is_err());
+    }
+
+    #[test]
+    fn exact_div_test() {
+        let evaluator = Evaluator::with_context(DefaultContext::new_decimal());
+
+        assert_eq!(evaluator.eval("exactdiv(1, 4)"), Ok(Decimal::new(25, 2)));
+        assert_eq!(evaluator.eval("exactdiv(1, 3)").unwrap_err().kind(), ErrorKind::InvalidInput);
+        assert_eq!(evaluator.eval("exactdiv(1, 0)").unwrap_err().kind(), ErrorKind::DivisionByZero);
+    }
+
+    #[test]
+    fn round_test() {
+        let evaluator = Evaluator::with_context(DefaultContext::new_decimal());
+        assert_eq!(evaluator.eval("round(2.5)"), Ok(Decimal::from(2)));
+        assert_eq!(evaluator.eval("round(3.5)"), Ok(Decimal::from(4)));
+        assert_eq!(evaluator.eval("round(-2.5)"), Ok(Decimal::from(-2)));
+        assert_eq!(evaluator.eval("round(2.6)"), Ok(Decimal::from(3)));
+    }
+
+    #[test]
+    fn sqrt_iterations_test() {
+        use crate::context::Config;
+        use std::str::FromStr;
+
+        let evaluator = Evaluator::with_context(DefaultContext::new_decimal());
+        let full = evaluator.eval("sqrt(2)").unwrap();
+        assert_eq!(full, Decimal::from_str("1.4142135623730950488016887242").unwrap());
+
+        let config = Config::new().with_decimal_sqrt_iterations(0);
+        let evaluator = Evaluator::with_context(DefaultContext::with_config_decimal(config));
+        let low = evaluator.eval("sqrt(2)").unwrap();
+        assert_ne!(low, full);
+        assert_eq!(low.round_dp(14), full.round_dp(14));
+    }
+
+    #[test]
+    fn money_test() {
+        use std::str::FromStr;
+
+        let evaluator = Evaluator::with_context(DefaultContext::new_decimal());
+        let dec = |s: &str| Decimal::from_str(s).unwrap();
+
+        assert_eq!(evaluator.eval("money(1.005)"), Ok(dec("1.01")));
+        assert_eq!(evaluator.eval("money(1.004)"), Ok(dec("1.00")));
+        assert_eq!(evaluator.eval("money(-1.005)"), Ok(dec("-1.01")));
+        assert_eq!(evaluator.eval("money(-2.344)"), Ok(dec("-2.34")));
+        assert_eq!(evaluator.eval("money(2.3455, 3)"), Ok(dec("2.346")));
+        assert_eq!(evaluator.eval("money(2.5, 0)"), Ok(dec("3")));
+
+        assert!(evaluator.eval("money(2.5, -1)").is_err());
+        assert!(evaluator.eval("money(2.5, 1.5)").is_err());
+    }
+
+    #[test]
+    fn round_to_test() {
+        use std::str::FromStr;
+
+        let evaluator = Evaluator::with_context(DefaultContext::new_decimal());
+        let dec = |s: &str| Decimal::from_str(s).unwrap();
+
+        assert_eq!(evaluator.eval("round_to(1.23, 0.05)"), Ok(dec("1.25")));
+        assert_eq!(evaluator.eval("round_to(1.225, 0.05)"), Ok(dec("1.25")));
+        assert_eq!(evaluator.eval("round_to(1.37, 0.25)"), Ok(dec("1.25")));
+        assert_eq!(evaluator.eval("round_to(-1.23, 0.05)"), Ok(dec("-1.25")));
+        assert_eq!(evaluator.eval("floor_to(1.29, 0.1)"), Ok(dec("1.2")));
+        assert_eq!(evaluator.eval("floor_to(-1.21, 0.1)"), Ok(dec("-1.3")));
+        assert_eq!(evaluator.eval("ceil_to(1.21, 0.25)"), Ok(dec("1.25")));
+        assert_eq!(evaluator.eval("ceil_to(1.25, 0.25)"), Ok(dec("1.25")));
+
+        assert_eq!(evaluator.eval("round_to(1.23, 0)").unwrap_err().kind(), ErrorKind::Zero);
+        assert_eq!(evaluator.eval("floor_to(1.23, -0.1)").unwrap_err().kind(), ErrorKind::NegativeValue);
+        assert!(evaluator.eval("ceil_to(1.23)").is_err());
+    }
+
+    #[test]
+    fn sigfig_test() {
+        use std::str::FromStr;
+
+        let evaluator = Evaluator::with_context(DefaultContext::new_decimal());
+        let dec = |s: &str| Decimal::from_str(s).unwrap();
+
+        assert_eq!(evaluator.eval("sigfig(12345, 2)"), Ok(dec("12000")));
+        assert_eq!(evaluator.eval("sigfig(12345, 3)"), Ok(dec("12300")));
+        assert_eq!(evaluator.eval("sigfig(12345, 7)"), Ok(dec("12345")));
+        assert_eq!(evaluator.eval("sigfig(1000, 1)"), Ok(dec("1000")));
+        assert_eq!(evaluator.eval("sigfig(0.0012345, 2)"), Ok(dec("0.0012")));
+        assert_eq!(evaluator.eval("sigfig(0.001, 1)"), Ok(dec("0.001")));
+        assert_eq!(evaluator.eval("sigfig(-98765, 2)"), Ok(dec("-99000")));
+        assert_eq!(evaluator.eval("sigfig(0, 3)"), Ok(dec("0")));
+
+        assert!(evaluator.eval("sigfig(12345, 0)").is_err());
+        assert!(evaluator.eval("sigfig(12345, 1.5)").is_err());
+        assert!(evaluator.eval("sigfig(12345)").is_err());
+    }
+
+    #[test]
+    fn round_half_away_from_zero_test() {
+        use crate::context::Config;
+
+        let config = Config::new().with_round_half_away_from_zero(true);
+        let evaluator = Evaluator::with_context(DefaultContext::with_config_decimal(config));
+        assert_eq!(evaluator.eval("round(2.5)"), Ok(Decimal::from(3)));
+        assert_eq!(evaluator.eval("round(3.5)"), Ok(Decimal::from(4)));
+        assert_eq!(evaluator.eval("round(-2.5)"), Ok(Decimal::from(-3)));
+        assert_eq!(evaluator.eval("round(2.4)"), Ok(Decimal::from(2)));
+    }
+
+    #[test]
+    fn round_up_and_away_test() {
+        let evaluator = Evaluator::with_context(DefaultContext::new_decimal());
+
+        assert_eq!(evaluator.eval("round_up(2.5)"), Ok(Decimal::from(3)));
+        assert_eq!(evaluator.eval("round_up(-2.5)"), Ok(Decimal::from(-2)));
+        assert_eq!(evaluator.eval("round_up(-2.6)"), Ok(Decimal::from(-3)));
+        assert_eq!(evaluator.eval("round_away(2.5)"), Ok(Decimal::from(3)));
+        assert_eq!(evaluator.eval("round_away(-2.5)"), Ok(Decimal::from(-3)));
+        assert_eq!(evaluator.eval("round_away(-2.4)"), Ok(Decimal::from(-2)));
+    }
+}
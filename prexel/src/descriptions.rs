@@ -7,27 +7,67 @@ pub enum Description {
     Sub,
     Mul,
     Div,
+    ExactDiv,
     Mod,
+    Eq,
+    Ne,
+    Gt,
+    Lt,
+    Gte,
+    Lte,
+    If,
+    And,
+    Or,
+    Not,
     Neg,
     Plus,
     Abs,
+    Wrap,
+    Clamp,
+    Clamp01,
+    Step,
+    SmoothStep,
     Sum,
     Prod,
     Avg,
+    GeoMean,
+    Gcd,
+    Combination,
+    Permutation,
+    Lcm,
+    HarMean,
+    PercentChange,
+    PercentOf,
     Factorial,
     Pow,
+    PowMod,
     Max,
     Min,
+    MaxIgnoreNan,
+    MinIgnoreNan,
+    Percentile,
     Floor,
     Ceil,
     Truncate,
     Round,
+    RoundUp,
+    RoundAway,
+    RoundTo,
+    FloorTo,
+    CeilTo,
+    SigFig,
+    Money,
     Sign,
     Sqrt,
     Cbrt,
     Exp,
+    Exp2,
+    Pow10,
     Ln,
     Log,
+    Log2,
+    Log10,
+    LogB,
     Rand,
     ToRadians,
     ToDegrees,
@@ -67,27 +107,67 @@ impl Description {
             Sub => "Subtract two values",
             Mul => "Multiply two values",
             Div => "Divide two values",
+            ExactDiv => "Divide two values, failing if the quotient is not exact",
             Mod => "Modulo two values",
+            Eq => "Returns 1 if the values are equal, 0 otherwise",
+            Ne => "Returns 1 if the values are not equal, 0 otherwise",
+            Gt => "Returns 1 if the left value is greater than the right value, 0 otherwise",
+            Lt => "Returns 1 if the left value is less than the right value, 0 otherwise",
+            Gte => "Returns 1 if the left value is greater than or equal to the right value, 0 otherwise",
+            Lte => "Returns 1 if the left value is less than or equal to the right value, 0 otherwise",
+            If => "Returns the second value if the first is not 0, otherwise the third, only the returned value is evaluated",
+            And => "Returns 1 if all the values are not 0, 0 otherwise",
+            Or => "Returns 1 if any of the values is not 0, 0 otherwise",
+            Not => "Returns 1 if the value is 0, 0 otherwise",
             Neg => "Negate a value",
             Plus => "Applies +n to a value",
             Abs => "Absolute value of a values",
+            Wrap => "Wraps a value into the range [0, period), eg: wrap(370, 360) = 10",
+            Clamp => "Clamps a value to the range [min, max]: clamp(x, min, max)",
+            Clamp01 => "Clamps a value to the range [0, 1]",
+            Step => "Returns 0 if the value is less than the edge, 1 otherwise: step(edge, x)",
+            SmoothStep => "Smooth Hermite interpolation between 0 and 1 when the value is between the edges: smoothstep(edge0, edge1, x)",
             Sum => "Gets the sum of all the values",
             Prod => "Gets the product of all the values",
             Avg => "Gets the average of all the values",
+            GeoMean => "Gets the geometric mean of all the values",
+            Combination => "Gets the number of combinations of `r` elements from a set of `n`: nCr(n, r)",
+            Permutation => "Gets the number of permutations of `r` elements from a set of `n`: nPr(n, r)",
+            Gcd => "Gets the greatest common divisor of the integer values",
+            Lcm => "Gets the least common multiple of the integer values",
+            HarMean => "Gets the harmonic mean of all the values",
+            PercentChange => "Gets the percent change from the first value to the second",
+            PercentOf => "Gets the percent the first value is of the second",
             Factorial => "Gets the factorial of a value",
             Pow => "Gets the power of a value",
+            PowMod => "Gets the power of a value modulo other value",
             Max => "Gets the maximum of all the values",
             Min => "Gets the minimum of all the values",
+            MaxIgnoreNan => "Gets the maximum of all the finite values, skipping NaN and infinite values",
+            MinIgnoreNan => "Gets the minimum of all the finite values, skipping NaN and infinite values",
+            Percentile => "Gets the percentile of the values, where the first value is the percentile in [0, 100]: percentile(p, values...)",
             Floor => "Rounds a value down",
             Ceil => "Rounds a value up",
             Truncate => "Gets the integer part of a number",
             Round => "Gets the round of a value",
+            RoundUp => "Rounds a value to the nearest integer, half-way values are rounded up",
+            RoundAway => "Rounds a value to the nearest integer, half-way values are rounded away from zero",
+            RoundTo => "Rounds a value to the nearest multiple of a step",
+            FloorTo => "Rounds a value down to the nearest multiple of a step",
+            CeilTo => "Rounds a value up to the nearest multiple of a step",
+            SigFig => "Rounds a value to the given number of significant figures",
+            Money => "Rounds a value to 2 or the given number of decimal places, half-way values are rounded away from zero: money(x) or money(x, places)",
             Sign => "Gets the sign of a value as -1, 0, or 1",
             Sqrt => "Gets the square root of a value",
             Cbrt => "Gets the cubic root of a value",
             Exp => "Gets the exponential of a value",
+            Exp2 => "Gets 2 raised to the power of a value",
+            Pow10 => "Gets 10 raised to the power of a value",
             Ln => "Gets the natural logarithm of a value",
-            Log => "Gets the logarithm of a value",
+            Log => "Gets the logarithm of a value in base 10 or in the given base: log(x) or log(x, base)",
+            Log2 => "Gets the logarithm in base 2 of a value",
+            Log10 => "Gets the logarithm in base 10 of a value",
+            LogB => "Gets the logarithm of a value in the given base, the base goes first: logb(base, x)",
             Rand => "Gets a random value: between 0 and 1, 0..MAX or a range",
             ToRadians => "Gets the radian value of a degree value",
             ToDegrees => "Gets the degree value of a radian value",
@@ -117,6 +197,25 @@ impl Description {
             ACoth => "Gets the hyperbolic arc cotangent of a value",
         }
     }
+
+    /// Returns the category of the function with this description.
+    pub fn category(&self) -> Category {
+        use Description::*;
+
+        match self {
+            Add | Sub | Mul | Div | ExactDiv | Mod | Neg | Plus | Abs | Wrap | Clamp | Clamp01 | Step | SmoothStep | Factorial | Sign | Gcd | Lcm
+            | Combination | Permutation => Category::Arithmetic,
+            Eq | Ne | Gt | Lt | Gte | Lte | If | And | Or | Not => Category::Comparison,
+            Sum | Prod | Avg | GeoMean | HarMean | PercentChange | PercentOf | Max | Min
+            | MaxIgnoreNan | MinIgnoreNan | Percentile => Category::Statistics,
+            Floor | Ceil | Truncate | Round | RoundUp | RoundAway | RoundTo | FloorTo | CeilTo
+            | SigFig | Money => Category::Rounding,
+            Pow | PowMod | Sqrt | Cbrt | Exp | Exp2 | Pow10 | Ln | Log | Log2 | Log10 | LogB => Category::Exponential,
+            Rand => Category::Random,
+            ToRadians | ToDegrees | Sin | Cos | Tan | Csc | Sec | Cot | ASin | ACos | ATan | ACsc | ASec | ACot => Category::Trigonometry,
+            Sinh | Cosh | Tanh | Csch | Sech | Coth | ASinh | ACosh | ATanh | ACsch | ASech | ACoth => Category::Hyperbolic,
+        }
+    }
 }
 
 impl Display for Description {
@@ -135,4 +234,47 @@ impl From<Description> for String {
     fn from(description: Description) -> Self {
         description.as_str().to_string()
     }
-}
\ No newline at end of file
+}
+
+/// Collection of categories used to group prexel functions and operators.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Category {
+    Arithmetic,
+    Comparison,
+    Statistics,
+    Rounding,
+    Exponential,
+    Random,
+    Trigonometry,
+    Hyperbolic,
+    Bitwise,
+}
+
+impl Category {
+    /// Returns a string representation of the category.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Category::Arithmetic => "Arithmetic",
+            Category::Comparison => "Comparison",
+            Category::Statistics => "Statistics",
+            Category::Rounding => "Rounding",
+            Category::Exponential => "Exponential",
+            Category::Random => "Random",
+            Category::Trigonometry => "Trigonometry",
+            Category::Hyperbolic => "Hyperbolic",
+            Category::Bitwise => "Bitwise",
+        }
+    }
+}
+
+impl Display for Category {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl From<Category> for &'static str {
+    fn from(category: Category) -> Self {
+        category.as_str()
+    }
+}
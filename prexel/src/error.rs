@@ -5,6 +5,8 @@ use std::fmt::{Debug, Display, Formatter};
 pub struct Error {
     /// Detail information of the error.
     detail: Detail,
+    /// The position in the expression of the token that caused the error, if known.
+    position: Option<usize>,
 }
 
 /// The detail information of an error.
@@ -45,10 +47,20 @@ pub enum ErrorKind {
     PositiveValue,
     /// The expression is invalid.
     InvalidExpression,
+    /// The expression is not finished and may be completed with more input,
+    /// eg: `8 +` or `(1 + 2`.
+    IncompleteExpression,
     /// The expression is empty.
     Empty,
+    /// The operation is not valid for the numeric type, eg: `max` for complex numbers.
+    Unsupported,
     /// An unknown error.
     Unknown,
+    /// An user defined error, the message of the error is provided by the function which fails.
+    ///
+    /// # Remarks
+    /// Use `Error::custom` to create an error of this kind with a message.
+    Custom,
 }
 
 impl ErrorKind {
@@ -64,8 +76,11 @@ impl ErrorKind {
             ErrorKind::NegativeValue => "Value is negative",
             ErrorKind::PositiveValue => "Value is positive",
             ErrorKind::Empty => "Empty input",
+            ErrorKind::Unsupported => "Unsupported operation",
             ErrorKind::InvalidExpression => "Invalid expression",
+            ErrorKind::IncompleteExpression => "Incomplete expression",
             ErrorKind::Unknown => "Unknown error",
+            ErrorKind::Custom => "Custom error",
         }
     }
 }
@@ -109,6 +124,7 @@ impl Error {
                 kind,
                 error: error.into(),
             })),
+            position: None,
         }
     }
 
@@ -125,6 +141,24 @@ impl Error {
         Self::new(ErrorKind::Unknown, msg)
     }
 
+    /// Creates an error with the specified message and `ErrorKind::Custom`,
+    /// used by user defined functions for return its own errors.
+    ///
+    /// # Example
+    /// ```
+    /// use prexel::error::{Error, ErrorKind};
+    /// let error = Error::custom("account balance cannot be negative");
+    /// assert_eq!(ErrorKind::Custom, error.kind());
+    /// assert_eq!("account balance cannot be negative", error.to_string());
+    /// ```
+    #[inline]
+    pub fn custom<E>(error: E) -> Error
+    where
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        Self::new(ErrorKind::Custom, error)
+    }
+
     /// Gets the `ErrorKind` of this error.
     ///
     /// # Example
@@ -141,6 +175,29 @@ impl Error {
         }
     }
 
+    /// Gets the position in the expression of the token that caused this error, if known,
+    /// eg: the position `4` of the call to `asin` that fails in `2 + asin(2)`.
+    ///
+    /// # Example
+    /// ```
+    /// use prexel::evaluator::Evaluator;
+    ///
+    /// let evaluator : Evaluator<f64> = Evaluator::new();
+    /// let error = evaluator.eval("2 + asin(2)").unwrap_err();
+    /// assert_eq!(error.position(), Some(4));
+    /// ```
+    #[inline]
+    pub fn position(&self) -> Option<usize> {
+        self.position
+    }
+
+    /// Sets the position in the expression of the token that caused this error.
+    #[inline]
+    pub fn with_position(mut self, position: usize) -> Error {
+        self.position = Some(position);
+        self
+    }
+
     ///Consumes the `Error`, returning its inner error (if any).
     ///
     /// # Example
@@ -211,6 +268,7 @@ impl From<ErrorKind> for Error {
     fn from(kind: ErrorKind) -> Self {
         Error {
             detail: Detail::Simple(kind),
+            position: None,
         }
     }
 }
@@ -255,4 +313,39 @@ mod tests {
             unreachable!()
         }
     }
+
+    #[test]
+    fn custom_kind_error_test() {
+        use crate::context::{Context, DefaultContext};
+        use crate::evaluator::Evaluator;
+        use crate::function::Function;
+
+        struct WithdrawFunction;
+        impl Function<f64> for WithdrawFunction {
+            fn name(&self) -> &str {
+                "withdraw"
+            }
+
+            fn call(&self, args: &[f64]) -> crate::Result<f64> {
+                match args {
+                    [balance, amount] if amount > balance => {
+                        Err(Error::custom(format!("Insufficient funds: {} > {}", amount, balance)))
+                    }
+                    [balance, amount] => Ok(balance - amount),
+                    _ => Err(Error::from(ErrorKind::InvalidArgumentCount)),
+                }
+            }
+        }
+
+        let mut context = DefaultContext::new_checked();
+        context.add_function(WithdrawFunction).unwrap();
+
+        let evaluator = Evaluator::with_context(context);
+        assert_eq!(evaluator.eval("withdraw(100, 30)"), Ok(70_f64));
+
+        let error = evaluator.eval("withdraw(100, 120)").unwrap_err();
+        assert_eq!(ErrorKind::Custom, error.kind());
+        assert_eq!("Insufficient funds: 120 > 100", error.to_string());
+        assert_eq!("Custom error", Error::from(ErrorKind::Custom).to_string());
+    }
 }
@@ -1,13 +1,21 @@
-use std::fmt::Debug;
+use std::borrow::Cow;
+use std::cell::Cell;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 use std::str::FromStr;
 
-use crate::context::{Context, DefaultContext};
+use crate::context::{Config, Context, DefaultContext, ScopedContext};
 use crate::error::{Error, ErrorKind};
+use crate::expr::Expr;
+use crate::function::{Function, LazyArg};
 use crate::num::checked::CheckedNum;
-use crate::token::Token;
+use crate::token::{tokens_to_string, Token};
 use crate::token::Token::*;
 use crate::tokenizer::Tokenizer;
+use crate::utils::format::{group_thousands, to_scientific};
 use crate::Result;
 
 /// Represents the default `Evaluator`.
@@ -96,11 +104,631 @@ where
     ///     Err(e) => println!("{}", e)
     /// }
     /// ```
+    ///
+    /// # Remarks
+    /// An unknown token is an error with its position, eg: `xyz` at position 4 in `2+2 xyz`,
+    /// unless `Config::lenient_trailing` is enabled, then only the tokens before it are evaluated.
     #[inline]
-    pub fn eval(&'a self, expression: &str) -> Result<N> {
+    pub fn eval(&self, expression: &str) -> Result<N> {
+        self.eval_with_buffer(expression, &mut Vec::new())
+    }
+
+    /// Evaluates the given `str` expression as `eval`, using the given buffer to store
+    /// the intermediate values.
+    fn eval_with_buffer(&self, expression: &str, values: &mut Vec<N>) -> Result<N> {
         let context = self.context();
-        let tokens = self.tokenizer.tokenize(context, expression)?;
-        self.eval_tokens(&tokens)
+        let (tokens, positions, unknown) = self.tokenizer.tokenize_until(context, expression, true)?;
+
+        match unknown {
+            Some((position, value)) if !context.config().lenient_trailing || tokens.is_empty() => {
+                Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("Unknown token `{}` at position {}", value, position),
+                )
+                .with_position(position))
+            }
+            _ => rpn_eval_with_buffer(&tokens, &positions, context, &Cell::new(0), values),
+        }
+    }
+
+    /// Evaluates each of the given expressions using the same `Tokenizer` and `Context`.
+    ///
+    /// # Remarks
+    /// Each expression is evaluated independently, so an error in one of them
+    /// don't stop the evaluation of the rest. The buffer of the intermediate values
+    /// is allocated once and reused for all the expressions.
+    ///
+    /// # Example
+    /// ```
+    /// use prexel::evaluator::Evaluator;
+    ///
+    /// let evaluator : Evaluator<i64> = Evaluator::new();
+    /// let results = evaluator.eval_batch(&["1 + 2", "5 / 0", "2 * 3"]);
+    ///
+    /// assert_eq!(results[0], Ok(3));
+    /// assert!(results[1].is_err());
+    /// assert_eq!(results[2], Ok(6));
+    /// ```
+    pub fn eval_batch(&'a self, expressions: &[&str]) -> Vec<Result<N>> {
+        let mut values = Vec::new();
+        expressions
+            .iter()
+            .map(|expression| self.eval_with_buffer(expression, &mut values))
+            .collect()
+    }
+
+    /// Evaluates the given `str` expression and gets information about the type of the result,
+    /// eg: if a complex evaluation results in a real number.
+    ///
+    /// # Example
+    /// ```
+    /// use prexel::evaluator::{Evaluator, ResultInfo};
+    ///
+    /// let evaluator : Evaluator<f64> = Evaluator::new();
+    /// assert_eq!(evaluator.eval_typed("2 + 3"), Ok((5_f64, ResultInfo::Real)));
+    /// ```
+    #[inline]
+    pub fn eval_typed(&'a self, expression: &str) -> Result<(N, ResultInfo)>
+    where
+        N: ResultType,
+    {
+        let result = self.eval(expression)?;
+        let info = result.result_info();
+        Ok((result, info))
+    }
+
+    /// Parses the given expression into an `Expr` tree, which can be evaluated many times
+    /// without tokenizing it again.
+    ///
+    /// # Example
+    /// ```
+    /// use prexel::context::Context;
+    /// use prexel::evaluator::Evaluator;
+    ///
+    /// let mut evaluator : Evaluator<i64> = Evaluator::new();
+    /// evaluator.mut_context().set_variable("x", 0).unwrap();
+    ///
+    /// let expr = evaluator.parse("x * 2 + 1").unwrap();
+    /// assert_eq!(expr.eval(evaluator.context()), Ok(1));
+    /// ```
+    pub fn parse(&'a self, expression: &str) -> Result<Expr<N>> {
+        let tokens = self.tokenizer.tokenize(self.context(), expression)?;
+        let rpn = shunting_yard::infix_to_rpn(&tokens, self.context())?;
+        Expr::from_rpn(&rpn)
+    }
+
+    /// Converts the given expression to `Reverse Polish Notation` using the tokenizer of this
+    /// evaluator, so it can be evaluated many times without tokenizing it again.
+    ///
+    /// # Example
+    /// ```
+    /// use prexel::context::{Context, DefaultContext};
+    /// use prexel::evaluator::Evaluator;
+    ///
+    /// let mut context : DefaultContext<f64> = DefaultContext::new_unchecked();
+    /// context.set_variable("x", 0_f64).unwrap();
+    ///
+    /// let evaluator = Evaluator::with_context(context.clone());
+    /// let expr = evaluator.compile("x^2 + 1").unwrap();
+    ///
+    /// context.set_variable("x", 3_f64).unwrap();
+    /// assert_eq!(expr.eval(&context), Ok(10_f64));
+    /// ```
+    pub fn compile(&'a self, expression: &str) -> Result<CompiledExpr<N>> {
+        let tokens = self.tokenizer.tokenize(self.context(), expression)?;
+        CompiledExpr::from_tokens(&tokens, self.context())
+    }
+}
+
+impl<'a, N, C> Evaluator<'a, N, C>
+where
+    C: Context<'a, N>,
+    N: FromStr + Display,
+{
+    /// Converts the given expression into a canonical string, making explicit the
+    /// implicit multiplications, so equivalent expressions produces the same string.
+    ///
+    /// # Example
+    /// ```
+    /// use prexel::context::{Config, Context, DefaultContext};
+    /// use prexel::evaluator::Evaluator;
+    ///
+    /// let mut context = DefaultContext::with_config_checked(Config::new().with_implicit_mul(true));
+    /// context.set_variable("x", 5).unwrap();
+    ///
+    /// let evaluator : Evaluator<i64> = Evaluator::with_context(context);
+    /// assert_eq!(evaluator.normalize("2x+Max(3,4)"), Ok("2 * x + Max(3, 4)".to_string()));
+    /// ```
+    pub fn normalize(&self, expression: &str) -> Result<String> {
+        let tokens = self.tokenizer.tokenize(self.context(), expression)?;
+        let config = self.context().config();
+        let mut normalized = Vec::with_capacity(tokens.len());
+        let mut iter = tokens.into_iter().peekable();
+
+        while let Some(token) = iter.next() {
+            let is_implicit_mul = iter
+                .peek()
+                .is_some_and(|next| shunting_yard::is_implicit_mul(config, &token, next));
+
+            normalized.push(token);
+
+            if is_implicit_mul {
+                normalized.push(BinaryOperator('*'.to_string()));
+            }
+        }
+
+        Ok(tokens_to_string(&normalized))
+    }
+
+    /// Gets a hash of the normalized form of the given expression, so equivalent expressions
+    /// like `2x` and `2 * x` can share the same key when memoizing results.
+    ///
+    /// # Remarks
+    /// The hash is stable for the same build, but is not guaranteed to be stable
+    /// across Rust versions so shouldn't be persisted.
+    ///
+    /// # Example
+    /// ```
+    /// use prexel::context::{Config, Context, DefaultContext};
+    /// use prexel::evaluator::Evaluator;
+    ///
+    /// let mut context = DefaultContext::with_config_checked(Config::new().with_implicit_mul(true));
+    /// context.set_variable("x", 5).unwrap();
+    ///
+    /// let evaluator : Evaluator<i64> = Evaluator::with_context(context);
+    /// assert_eq!(evaluator.expr_hash("2x"), evaluator.expr_hash("2 * x"));
+    /// ```
+    pub fn expr_hash(&self, expression: &str) -> Result<u64> {
+        let normalized = self.normalize(expression)?;
+        let mut hasher = DefaultHasher::new();
+        normalized.hash(&mut hasher);
+        Ok(hasher.finish())
+    }
+
+    /// Gets the variables and functions the given expression depends on.
+    ///
+    /// # Remarks
+    /// Unknown names are considered free variables, so the expression can be checked
+    /// before its variables are set. The operators are included in the functions.
+    ///
+    /// # Example
+    /// ```
+    /// use prexel::evaluator::Evaluator;
+    ///
+    /// let evaluator : Evaluator<f64> = Evaluator::new();
+    /// let dependencies = evaluator.dependencies("sin(x) + max(y, 2)").unwrap();
+    ///
+    /// assert_eq!(dependencies.to_string(), "variables: {x, y}, functions: {+, max, sin}");
+    /// ```
+    pub fn dependencies(&self, expression: &str) -> Result<Dependencies> {
+        let tokens = self.tokenizer.tokenize(self.context(), expression)?;
+        let mut dependencies = Dependencies::default();
+
+        for token in tokens {
+            match token {
+                Variable(name) => {
+                    dependencies.variables.insert(name);
+                }
+                Unknown(name) if name.starts_with(|c: char| c.is_alphabetic() || c == '_') => {
+                    dependencies.variables.insert(name);
+                }
+                Function(name) | BinaryOperator(name) | UnaryOperator(name) => {
+                    dependencies.functions.insert(name);
+                }
+                _ => {}
+            }
+        }
+
+        Ok(dependencies)
+    }
+
+    /// Gets the variables of the given expression in the order they first appear,
+    /// without duplicates, eg: to ask the user for each value before evaluating it.
+    ///
+    /// # Remarks
+    /// Unknown names are considered free variables, constants and functions are not included.
+    ///
+    /// # Example
+    /// ```
+    /// use prexel::evaluator::Evaluator;
+    ///
+    /// let evaluator : Evaluator<f64> = Evaluator::new();
+    /// let variables = evaluator.find_variables("y * sin(x) + y * PI").unwrap();
+    ///
+    /// assert_eq!(variables, vec!["y".to_string(), "x".to_string()]);
+    /// ```
+    pub fn find_variables(&self, expression: &str) -> Result<Vec<String>> {
+        let tokens = self.tokenizer.tokenize(self.context(), expression)?;
+        let mut variables: Vec<String> = Vec::new();
+
+        for token in tokens {
+            let name = match token {
+                Variable(name) => name,
+                Unknown(name) if name.starts_with(|c: char| c.is_alphabetic() || c == '_') => name,
+                _ => continue,
+            };
+
+            if !variables.contains(&name) {
+                variables.push(name);
+            }
+        }
+
+        Ok(variables)
+    }
+}
+
+/// An object safe evaluator, so evaluators with different contexts can be stored
+/// behind the same type, eg: `Vec<Box<dyn DynEvaluator<f64>>>`.
+///
+/// # Example
+/// ```
+/// use prexel::evaluator::{DynEvaluator, Evaluator};
+///
+/// let evaluator : Box<dyn DynEvaluator<i64>> = Box::new(Evaluator::<i64>::new());
+/// assert_eq!(evaluator.eval("2 + 3 * 4"), Ok(14));
+/// ```
+pub trait DynEvaluator<N> {
+    /// Evaluates the given `str` expression.
+    fn eval(&self, expression: &str) -> Result<N>;
+}
+
+impl<'a, N, C> DynEvaluator<N> for Evaluator<'a, N, C>
+where
+    C: Context<'a, N>,
+    N: FromStr + Debug + Clone,
+{
+    #[inline]
+    fn eval(&self, expression: &str) -> Result<N> {
+        Evaluator::eval(self, expression)
+    }
+}
+
+/// The variables and functions an expression depends on, see `Evaluator::dependencies`.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct Dependencies {
+    /// The names of the variables, including the ones not found in the context.
+    pub variables: BTreeSet<String>,
+    /// The names of the functions and operators.
+    pub functions: BTreeSet<String>,
+}
+
+impl Display for Dependencies {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fn join(values: &BTreeSet<String>) -> String {
+            values.iter().map(String::as_str).collect::<Vec<&str>>().join(", ")
+        }
+
+        write!(
+            f,
+            "variables: {{{}}}, functions: {{{}}}",
+            join(&self.variables),
+            join(&self.functions)
+        )
+    }
+}
+
+/// The type of the result of an evaluation, see `Evaluator::eval_typed`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResultInfo {
+    /// A real number, including complex numbers with a zero imaginary part.
+    Real,
+    /// A complex number with a nonzero imaginary part.
+    Complex,
+}
+
+impl ResultInfo {
+    /// Checks if the result is a real number.
+    #[inline]
+    pub fn is_real(&self) -> bool {
+        matches!(self, ResultInfo::Real)
+    }
+
+    /// Checks if the result is a complex number with a nonzero imaginary part.
+    #[inline]
+    pub fn is_complex(&self) -> bool {
+        matches!(self, ResultInfo::Complex)
+    }
+}
+
+/// Provides the `ResultInfo` of a value.
+pub trait ResultType {
+    /// Gets the type of this value as the result of an evaluation.
+    fn result_info(&self) -> ResultInfo;
+}
+
+macro_rules! impl_real_result_type {
+    ($($t:ty),*) => {
+        $(
+            impl ResultType for $t {
+                #[inline]
+                fn result_info(&self) -> ResultInfo {
+                    ResultInfo::Real
+                }
+            }
+        )*
+    };
+}
+
+impl_real_result_type!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+/// How to display a result, see `Evaluator::format_result`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResultFormat {
+    /// All the digits of the value, as its `Display`.
+    Full,
+    /// Scientific notation with the given number of digits after the decimal point, eg: `1.23e5`.
+    Scientific(usize),
+    /// All the digits with the thousands separated by the given `char`, eg: `1,234,567`.
+    Grouped(char),
+}
+
+impl<'a, N, C> Evaluator<'a, N, C>
+where
+    C: Context<'a, N>,
+    N: Display,
+{
+    /// Formats a result for display, the digits are taken from the `Display` of the value
+    /// so the output is exact for numbers of any size.
+    ///
+    /// # Remarks
+    /// Values that are not plain decimal numbers, eg: complex numbers, are displayed in full
+    /// with `ResultFormat::Scientific`.
+    ///
+    /// # Example
+    /// ```
+    /// use prexel::evaluator::{Evaluator, ResultFormat};
+    ///
+    /// let evaluator : Evaluator<i128> = Evaluator::new();
+    /// let result = evaluator.eval("2 ^ 70").unwrap();
+    ///
+    /// assert_eq!(evaluator.format_result(&result, ResultFormat::Full), "1180591620717411303424");
+    /// assert_eq!(evaluator.format_result(&result, ResultFormat::Scientific(3)), "1.181e21");
+    /// assert_eq!(evaluator.format_result(&1234567, ResultFormat::Grouped(',')), "1,234,567");
+    /// ```
+    pub fn format_result(&self, value: &N, format: ResultFormat) -> String {
+        let digits = value.to_string();
+
+        match format {
+            ResultFormat::Full => digits,
+            ResultFormat::Scientific(precision) => to_scientific(&digits, precision).unwrap_or(digits),
+            ResultFormat::Grouped(separator) => group_thousands(&digits, separator),
+        }
+    }
+}
+
+impl<'a, N, C> Evaluator<'a, N, C>
+where
+    C: Context<'a, N> + 'a,
+    N: FromStr + Debug + Clone,
+{
+    /// Evaluates the given `str` expression resolving the variables, constants and functions
+    /// through the given scopes in order, and then through the context of this evaluator.
+    ///
+    /// # Remarks
+    /// The scopes are not merged, so this can be used to evaluate an expression with local
+    /// values without cloning the context of the evaluator. The `Config` of the evaluator context is used.
+    ///
+    /// # Example
+    /// ```
+    /// use prexel::context::{Context, DefaultContext};
+    /// use prexel::evaluator::Evaluator;
+    ///
+    /// let mut base : DefaultContext<f64> = DefaultContext::new_unchecked();
+    /// base.set_variable("x", 10_f64).unwrap();
+    ///
+    /// let mut local : DefaultContext<f64> = DefaultContext::new();
+    /// local.set_variable("x", 2_f64).unwrap();
+    ///
+    /// let evaluator = Evaluator::with_context(base);
+    /// assert_eq!(evaluator.eval_with_scopes("x * PI", &[&local]), Ok(2_f64 * std::f64::consts::PI));
+    /// ```
+    pub fn eval_with_scopes(&self, expression: &str, scopes: &[&dyn Context<'a, N>]) -> Result<N> {
+        let mut chain: Vec<&dyn Context<'a, N>> = Vec::with_capacity(scopes.len() + 1);
+        chain.extend_from_slice(scopes);
+        chain.push(self.context());
+
+        let context = ScopedContext::new(&chain, self.context().config());
+        let tokens = self.tokenizer.tokenize_with(&context, expression)?;
+        rpn_eval(&tokens, &context)
+    }
+
+    /// Evaluates the given `str` expression using the `resolver` to get the values of the
+    /// variables that are not in the context of this evaluator.
+    ///
+    /// # Remarks
+    /// The resolver is only called for the unknown names found in the expression, once per name,
+    /// so the values can be provided on demand, eg: from a database.
+    ///
+    /// # Example
+    /// ```
+    /// use prexel::evaluator::Evaluator;
+    ///
+    /// let evaluator : Evaluator<i64> = Evaluator::new();
+    /// let resolver = |name: &str| if name == "x" { Some(10) } else { None };
+    ///
+    /// assert_eq!(evaluator.eval_with_resolver("x * 2", resolver), Ok(20));
+    /// assert!(evaluator.eval_with_resolver("y * 2", resolver).is_err());
+    /// ```
+    pub fn eval_with_resolver<F>(&self, expression: &str, resolver: F) -> Result<N>
+    where
+        F: Fn(&str) -> Option<N>,
+    {
+        let mut tokens = self.tokenizer.tokenize(self.context(), expression)?;
+        let mut resolved: DefaultContext<'a, N> = DefaultContext::new();
+
+        for token in tokens.iter_mut() {
+            let name = match token {
+                Unknown(name) => name.clone(),
+                Variable(name) if !self.context().is_variable(name) => name.clone(),
+                _ => continue,
+            };
+
+            if resolved.get_variable(&name).is_none() {
+                match resolver(&name) {
+                    Some(value) => {
+                        resolved.set_variable(&name, value)?;
+                    }
+                    None => continue,
+                }
+            }
+
+            *token = Variable(name);
+        }
+
+        let chain: [&dyn Context<'a, N>; 2] = [&resolved, self.context()];
+        let context = ScopedContext::new(&chain, self.context().config());
+        rpn_eval(&tokens, &context)
+    }
+
+    /// Evaluates the `;` separated statements of the given `str`, where each statement can be
+    /// an expression or an assignment as `name = expression`, and returns the value of the last
+    /// statement with the variables that were assigned.
+    ///
+    /// # Remarks
+    /// The assigned variables are only visible to the statements that follow them and are not
+    /// stored in the context of this evaluator.
+    ///
+    /// # Example
+    /// ```
+    /// use prexel::evaluator::Evaluator;
+    ///
+    /// let evaluator : Evaluator<i64> = Evaluator::new();
+    /// let (result, bindings) = evaluator.eval_all_with_bindings("a = 2; b = a * 3; b + 1").unwrap();
+    ///
+    /// assert_eq!(result, 7);
+    /// assert_eq!(bindings["a"], 2);
+    /// assert_eq!(bindings["b"], 6);
+    /// ```
+    pub fn eval_all_with_bindings(&self, source: &str) -> Result<(N, HashMap<String, N>)> {
+        let statements = self.eval_statements(source)?;
+        let result = statements
+            .last()
+            .map(|statement| statement.value().clone())
+            .ok_or_else(|| Error::new(ErrorKind::Empty, "Expression is empty"))?;
+
+        let bindings = statements
+            .into_iter()
+            .filter_map(|statement| match statement {
+                StatementResult::Assignment(name, value) => Some((name, value)),
+                StatementResult::Expression(_) => None,
+            })
+            .collect();
+
+        Ok((result, bindings))
+    }
+
+    /// Evaluates the `;` separated statements of the given `str` like `eval_all_with_bindings`,
+    /// and returns the result of each statement, eg: to echo `a = 2` in a script.
+    ///
+    /// # Example
+    /// ```
+    /// use prexel::evaluator::{Evaluator, StatementResult};
+    ///
+    /// let evaluator : Evaluator<i64> = Evaluator::new();
+    /// let statements = evaluator.eval_statements("a = 2; a * 3").unwrap();
+    ///
+    /// assert_eq!(statements, vec![
+    ///     StatementResult::Assignment("a".to_string(), 2),
+    ///     StatementResult::Expression(6)
+    /// ]);
+    /// ```
+    pub fn eval_statements(&self, source: &str) -> Result<Vec<StatementResult<N>>> {
+        let mut bindings: DefaultContext<'a, N> = DefaultContext::new();
+        let mut results = Vec::new();
+
+        for statement in split_statements(source) {
+            let statement = statement.trim();
+            if statement.is_empty() {
+                continue;
+            }
+
+            match split_assignment(statement) {
+                Some((name, expression)) => {
+                    let value = self.eval_with_scopes(expression, &[&bindings])?;
+                    bindings.set_variable(name, value.clone())?;
+                    results.push(StatementResult::Assignment(name.to_string(), value));
+                }
+                None => {
+                    let value = self.eval_with_scopes(statement, &[&bindings])?;
+                    results.push(StatementResult::Expression(value));
+                }
+            }
+        }
+
+        Ok(results)
+    }
+}
+
+/// The result of a statement evaluated by `Evaluator::eval_statements`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StatementResult<N> {
+    /// An assignment as `name = expression`, with the assigned value.
+    Assignment(String, N),
+    /// An expression, with its value.
+    Expression(N),
+}
+
+impl<N> StatementResult<N> {
+    /// Gets the value of this statement.
+    #[inline]
+    pub fn value(&self) -> &N {
+        match self {
+            StatementResult::Assignment(_, value) => value,
+            StatementResult::Expression(value) => value,
+        }
+    }
+}
+
+impl<N: Display> Display for StatementResult<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StatementResult::Assignment(name, value) => write!(f, "{} = {}", name, value),
+            StatementResult::Expression(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+/// Splits the statements separated by `;` that are not inside a grouping symbol,
+/// so `;` still can be used as argument separator.
+fn split_statements(source: &str) -> Vec<&str> {
+    let mut statements = Vec::new();
+    let mut depth = 0_usize;
+    let mut start = 0;
+
+    for (index, c) in source.char_indices() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth = depth.saturating_sub(1),
+            ';' if depth == 0 => {
+                statements.push(&source[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+
+    statements.push(&source[start..]);
+    statements
+}
+
+/// Splits an assignment as `name = expression`, ignoring operators as `==`, `<=` or `>=`.
+fn split_assignment(statement: &str) -> Option<(&str, &str)> {
+    let bytes = statement.as_bytes();
+    let index = (0..bytes.len()).find(|&i| {
+        bytes[i] == b'='
+            && bytes.get(i + 1) != Some(&b'=')
+            && (i == 0 || !matches!(bytes[i - 1], b'=' | b'<' | b'>' | b'!'))
+    })?;
+
+    let name = statement[..index].trim();
+    let mut chars = name.chars();
+    let is_identifier = matches!(chars.next(), Some(c) if c.is_alphabetic() || c == '_')
+        && chars.all(|c| c.is_alphanumeric() || c == '_');
+
+    if is_identifier {
+        Some((name, &statement[index + 1..]))
+    } else {
+        None
     }
 }
 
@@ -113,6 +741,216 @@ where
     pub fn eval_tokens(&self, tokens: &[Token<N>]) -> Result<N> {
         rpn_eval(tokens, self.context())
     }
+
+    /// Evaluates the given tokens in `Reverse Polish Notation`, skipping the conversion
+    /// from infix notation, eg: tokens from [`infix_to_rpn`] that were cached.
+    ///
+    /// # Example
+    /// ```
+    /// use prexel::evaluator::Evaluator;
+    /// use prexel::token::Token::*;
+    ///
+    /// let evaluator : Evaluator<i64> = Evaluator::new();
+    /// let rpn = [Number(3), Number(2), BinaryOperator("+".to_string())];
+    /// assert_eq!(evaluator.eval_rpn(&rpn), Ok(5));
+    /// ```
+    #[inline]
+    pub fn eval_rpn(&self, rpn: &[Token<N>]) -> Result<N> {
+        eval_postfix(rpn, self.context())
+    }
+}
+
+/// An expression converted to `Reverse Polish Notation`, which can be evaluated many times
+/// without tokenizing it again, eg: for plotting `f(x)` over a range of values,
+/// see `Evaluator::compile`.
+///
+/// # Remarks
+/// The arguments of the functions that want lazy arguments, like `if`, are compiled separately
+/// so are only evaluated when the function needs them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompiledExpr<N> {
+    rpn: Vec<Token<N>>,
+    lazy_calls: Vec<LazyCall<N>>,
+}
+
+/// A call to a function that wants lazy arguments within a `CompiledExpr`.
+#[derive(Debug, Clone, PartialEq)]
+struct LazyCall<N> {
+    /// The index in the `rpn` of the token this call replaces.
+    index: usize,
+    name: String,
+    /// The tokens of each argument in infix notation, with the argument compiled.
+    args: Vec<(Vec<Token<N>>, CompiledExpr<N>)>,
+}
+
+impl<N> LazyCall<N>
+where
+    N: Debug + Clone,
+{
+    fn eval<'a, C>(&self, context: &C, steps: &Cell<usize>) -> Result<N>
+    where
+        C: Context<'a, N>,
+    {
+        count_step(steps, context.config())?;
+
+        let func = context
+            .get_function_with_arity(&self.name, self.args.len())
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("Function `{}` not found", self.name),
+                )
+            })?;
+
+        check_lazy_call(func.as_ref(), &self.name, self.args.len(), context.config())?;
+
+        let evals = self
+            .args
+            .iter()
+            .map(|(_, expr)| move |_: &[Token<N>]| expr.eval_with_steps(context, steps))
+            .collect::<Vec<_>>();
+
+        let args = self
+            .args
+            .iter()
+            .zip(&evals)
+            .map(|((tokens, _), eval)| LazyArg::new(tokens, eval))
+            .collect::<Vec<_>>();
+
+        func.call_lazy(&args)
+    }
+}
+
+impl<N> CompiledExpr<N>
+where
+    N: Debug + Clone,
+{
+    /// Tokenizes the expression using the given context and converts it to `Reverse Polish Notation`.
+    ///
+    /// # Remarks
+    /// The variables of the expression must exist in the context, so are not taken as unknown tokens.
+    pub fn new<'a, C>(expression: &str, context: &C) -> Result<Self>
+    where
+        N: FromStr + 'a,
+        C: Context<'a, N> + 'a,
+    {
+        let tokens = Tokenizer::<N, C>::new().tokenize(context, expression)?;
+        CompiledExpr::from_tokens(&tokens, context)
+    }
+
+    /// Converts the tokens in infix notation to `Reverse Polish Notation`, compiling
+    /// the arguments of each call to a function that wants lazy arguments separately.
+    fn from_tokens<'a, C>(tokens: &[Token<N>], context: &C) -> Result<Self>
+    where
+        C: Context<'a, N>,
+    {
+        let mut infix = Vec::with_capacity(tokens.len());
+        // The index in `infix` of the token that replaces each lazy call, with the compiled call.
+        let mut calls = Vec::new();
+        let mut pos = 0;
+
+        while pos < tokens.len() {
+            if !is_lazy_call(tokens, pos, context) {
+                infix.push(tokens[pos].clone());
+                pos += 1;
+                continue;
+            }
+
+            let name = match &tokens[pos] {
+                Function(name) => name.clone(),
+                _ => unreachable!(),
+            };
+
+            let (args, end) = split_lazy_args(tokens, pos)?;
+            let args = args
+                .into_iter()
+                .map(|arg| Ok((arg.to_vec(), CompiledExpr::from_tokens(arg, context)?)))
+                .collect::<Result<Vec<_>>>()?;
+
+            // The call is evaluated as an operand, so is replaced by a placeholder variable
+            calls.push((infix.len(), name.clone(), args));
+            infix.push(Variable(name));
+            pos = end + 1;
+        }
+
+        let indexed = shunting_yard::infix_to_rpn_indexed(&infix, context)?;
+        let mut lazy_calls = Vec::with_capacity(calls.len());
+
+        for (infix_index, name, args) in calls {
+            let index = indexed
+                .iter()
+                .position(|(_, i)| *i == Some(infix_index))
+                .ok_or_else(|| Error::from(ErrorKind::InvalidExpression))?;
+
+            lazy_calls.push(LazyCall { index, name, args });
+        }
+
+        lazy_calls.sort_by_key(|call| call.index);
+        let rpn = indexed.into_iter().map(|(token, _)| token).collect();
+        Ok(CompiledExpr { rpn, lazy_calls })
+    }
+
+    /// Gets the tokens of this expression in `Reverse Polish Notation`.
+    ///
+    /// # Remarks
+    /// Each call to a function that wants lazy arguments is a single `Variable` token
+    /// with the name of the function.
+    #[inline]
+    pub fn rpn(&self) -> &[Token<N>] {
+        &self.rpn
+    }
+
+    /// Evaluates this expression using the given context.
+    #[inline]
+    pub fn eval<'a, C>(&self, context: &C) -> Result<N>
+    where
+        C: Context<'a, N>,
+    {
+        self.eval_with_steps(context, &Cell::new(0))
+    }
+
+    /// Evaluates this expression counting the steps in the given counter, which is shared
+    /// with the lazy arguments of the expression.
+    fn eval_with_steps<'a, C>(&self, context: &C, steps: &Cell<usize>) -> Result<N>
+    where
+        C: Context<'a, N>,
+    {
+        eval_rpn_with_buffer(&self.rpn, &[], &self.lazy_calls, context, steps, &mut Vec::new())
+    }
+
+    /// Evaluates this expression for each of the values, setting the value to the given variable
+    /// of the context before each evaluation.
+    ///
+    /// # Example
+    /// ```
+    /// use prexel::context::{Context, DefaultContext};
+    /// use prexel::evaluator::CompiledExpr;
+    ///
+    /// let mut context : DefaultContext<i64> = DefaultContext::new_checked();
+    /// context.set_variable("x", 0).unwrap();
+    /// let expr = CompiledExpr::new("2 * x + 1", &context).unwrap();
+    ///
+    /// let results = expr.eval_over("x", 1..=3, &mut context).collect::<Vec<_>>();
+    /// assert_eq!(results, vec![Ok(3), Ok(5), Ok(7)]);
+    /// ```
+    pub fn eval_over<'a, 'i, C, I>(
+        &'i self,
+        var: &'i str,
+        values: I,
+        context: &'i mut C,
+    ) -> impl Iterator<Item = Result<N>> + 'i
+    where
+        'a: 'i,
+        C: Context<'a, N>,
+        I: IntoIterator<Item = N>,
+        I::IntoIter: 'i,
+    {
+        let mut buffer = Vec::new();
+        values.into_iter().map(move |value| {
+            context.set_variable(var, value)?;
+            eval_rpn_with_buffer(&self.rpn, &[], &self.lazy_calls, &*context, &Cell::new(0), &mut buffer)
+        })
+    }
 }
 
 /// Evaluates an array of tokens in `Reverse Polish Notation`.
@@ -127,25 +965,307 @@ where
     N: Debug + Clone,
     C: Context<'a, N>,
 {
-    // Converts the array of tokens to RPN.
-    let rpn = shunting_yard::infix_to_rpn(tokens, context)?;
-    // Stores the resulting values
-    let mut values: Vec<N> = Vec::new();
+    rpn_eval_with_positions(tokens, &[], context)
+}
+
+/// Evaluates the tokens as `rpn_eval`, where `positions` are the positions in the expression
+/// of each token, so the runtime errors have the position of the token that caused it,
+/// eg: the function call in `asin(2)`.
+pub(crate) fn rpn_eval_with_positions<'a, N, C>(
+    tokens: &[Token<N>],
+    positions: &[usize],
+    context: &C,
+) -> Result<N>
+where
+    N: Debug + Clone,
+    C: Context<'a, N>,
+{
+    rpn_eval_with_steps(tokens, positions, context, &Cell::new(0))
+}
+
+/// Evaluates the tokens as `rpn_eval_with_positions`, counting the steps in the given counter
+/// so the lazy arguments share the `max_steps` of the expression.
+fn rpn_eval_with_steps<'a, N, C>(
+    tokens: &[Token<N>],
+    positions: &[usize],
+    context: &C,
+    steps: &Cell<usize>,
+) -> Result<N>
+where
+    N: Debug + Clone,
+    C: Context<'a, N>,
+{
+    rpn_eval_with_buffer(tokens, positions, context, steps, &mut Vec::new())
+}
+
+/// Evaluates the tokens as `rpn_eval_with_steps`, using the given buffer to store
+/// the intermediate values.
+fn rpn_eval_with_buffer<'a, N, C>(
+    tokens: &[Token<N>],
+    positions: &[usize],
+    context: &C,
+    steps: &Cell<usize>,
+    values: &mut Vec<N>,
+) -> Result<N>
+where
+    N: Debug + Clone,
+    C: Context<'a, N>,
+{
+    // Calls to functions that want lazy arguments are evaluated first and replaced by its result.
+    let (tokens, positions) = eval_lazy_calls(tokens, positions, context, steps)?;
+
+    // Converts the array of tokens to RPN, keeping the position of each token.
+    let (rpn, rpn_positions): (Vec<_>, Vec<_>) = shunting_yard::infix_to_rpn_indexed(&tokens, context)?
+        .into_iter()
+        .map(|(token, index)| (token, index.and_then(|i| positions.get(i).copied())))
+        .unzip();
+
+    eval_rpn_with_buffer(&rpn, &rpn_positions, &[], context, steps, values)
+}
+
+/// Replaces each call to a function that wants lazy arguments, see [`Function::wants_lazy_args`],
+/// by the result of calling it with its unevaluated arguments.
+///
+/// [`Function::wants_lazy_args`]: crate::function::Function::wants_lazy_args
+///
+/// Returns the resulting tokens with its positions, taken from the given `positions` if any.
+#[allow(clippy::type_complexity)]
+fn eval_lazy_calls<'a, 't, N, C>(
+    tokens: &'t [Token<N>],
+    positions: &'t [usize],
+    context: &C,
+    steps: &Cell<usize>,
+) -> Result<(Cow<'t, [Token<N>]>, Cow<'t, [usize]>)>
+where
+    N: Debug + Clone,
+    C: Context<'a, N>,
+{
+    let is_lazy_call = |pos: usize| is_lazy_call(tokens, pos, context);
+
+    if !(0..tokens.len()).any(is_lazy_call) {
+        return Ok((Cow::Borrowed(tokens), Cow::Borrowed(positions)));
+    }
+
+    let eval = |arg: &[Token<N>]| rpn_eval_with_steps(arg, &[], context, steps);
+    let mut result = Vec::with_capacity(tokens.len());
+    let mut result_positions = Vec::with_capacity(positions.len());
+    let mut pos = 0;
+
+    while pos < tokens.len() {
+        result_positions.extend(positions.get(pos).copied());
+
+        if !is_lazy_call(pos) {
+            result.push(tokens[pos].clone());
+            pos += 1;
+            continue;
+        }
+
+        let name = match &tokens[pos] {
+            Function(name) => name,
+            _ => unreachable!(),
+        };
+
+        let (args, end) = split_lazy_args(tokens, pos)?;
+        let func = context.get_function_with_arity(name, args.len()).ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("Function `{}` not found", name),
+            )
+        })?;
+
+        count_step(steps, context.config())?;
+        check_lazy_call(func.as_ref(), name, args.len(), context.config())?;
+
+        let args = args
+            .into_iter()
+            .map(|arg| LazyArg::new(arg, &eval))
+            .collect::<Vec<_>>();
+
+        let value = func.call_lazy(&args).map_err(|e| match positions.get(pos) {
+            Some(position) if e.position().is_none() => e.with_position(*position),
+            _ => e,
+        })?;
+
+        result.push(Number(value));
+        pos = end + 1;
+    }
+
+    Ok((Cow::Owned(result), Cow::Owned(result_positions)))
+}
+
+/// Checks if the token at `pos` is a call to a function that wants lazy arguments.
+fn is_lazy_call<'a, N, C>(tokens: &[Token<N>], pos: usize, context: &C) -> bool
+where
+    C: Context<'a, N>,
+{
+    match (&tokens[pos], tokens.get(pos + 1)) {
+        (Function(name), Some(GroupingOpen(_))) => context
+            .get_function(name)
+            .is_some_and(|func| func.wants_lazy_args()),
+        _ => false,
+    }
+}
+
+/// Gets the arguments of the lazy call at `pos` split by the top-level commas,
+/// with the index of the grouping symbol that closes the call.
+#[allow(clippy::type_complexity)]
+fn split_lazy_args<N>(tokens: &[Token<N>], pos: usize) -> Result<(Vec<&[Token<N>]>, usize)> {
+    let start = pos + 2;
+    let mut depth = 1;
+    let mut end = start;
+    let mut args = Vec::new();
+    let mut arg_start = start;
+
+    while end < tokens.len() {
+        match &tokens[end] {
+            GroupingOpen(_) => depth += 1,
+            GroupingClose(_) => {
+                depth -= 1;
+                if depth == 0 {
+                    break;
+                }
+            }
+            Comma if depth == 1 => {
+                args.push(&tokens[arg_start..end]);
+                arg_start = end + 1;
+            }
+            _ => {}
+        }
+
+        end += 1;
+    }
+
+    if depth != 0 {
+        let name = match &tokens[pos] {
+            Function(name) => name.as_str(),
+            _ => "",
+        };
+
+        return Err(Error::new(
+            ErrorKind::IncompleteExpression,
+            format!("Function `{}` is missing its closing grouping symbol", name),
+        ));
+    }
+
+    if arg_start < end || !args.is_empty() {
+        args.push(&tokens[arg_start..end]);
+    }
+
+    Ok((args, end))
+}
+
+/// Checks the function of a lazy call can be called with the given number of arguments.
+fn check_lazy_call<N>(func: &dyn Function<N>, name: &str, arg_count: usize, config: &Config) -> Result<()> {
+    check_function_call(name, arg_count, config)?;
+    if !func.arity().accepts(arg_count) {
+        return Err(Error::new(
+            ErrorKind::InvalidArgumentCount,
+            format!("Function `{}` cannot take {} arguments", name, arg_count),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Counts an operator or function call evaluated, failing if exceeds the `max_steps` of the config.
+#[inline]
+fn count_step(steps: &Cell<usize>, config: &Config) -> Result<()> {
+    steps.set(steps.get() + 1);
+    match config.max_steps {
+        Some(max_steps) if steps.get() > max_steps => Err(Error::new(
+            ErrorKind::InvalidExpression,
+            format!("Expression exceeds the max of {} steps", max_steps),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Evaluates an array of tokens already in `Reverse Polish Notation`,
+/// eg: the result of [`infix_to_rpn`], unlike [`rpn_eval`] which takes the tokens in infix notation.
+///
+/// # Arguments
+/// - rpn: The tokens of the expression in reverse polish notation.
+/// - context: the context which contains the variables, constants and functions.
+///
+/// # Example
+/// ```
+/// use prexel::token::Token::*;
+/// use prexel::evaluator;
+/// use prexel::context::DefaultContext;
+///
+/// let rpn = [Number(5), Number(2), BinaryOperator("+".to_string())];
+/// let context = DefaultContext::new_checked();
+///
+/// assert_eq!(evaluator::eval_postfix(&rpn, &context), Ok(7));
+/// ```
+pub fn eval_postfix<'a, N, C>(rpn: &[Token<N>], context: &C) -> Result<N>
+where
+    N: Debug + Clone,
+    C: Context<'a, N>,
+{
+    eval_rpn_with_buffer(rpn, &[], &[], context, &Cell::new(0), &mut Vec::new())
+}
+
+/// Evaluates the tokens in `Reverse Polish Notation` using the given buffer to store
+/// the intermediate values, the buffer is cleared before the evaluation.
+///
+/// The `positions` are the positions in the expression of each token, if known,
+/// which are set to the errors of the calls and lookups of the tokens, and `lazy_calls`
+/// are the calls of a `CompiledExpr` replaced by a token of the `rpn`.
+fn eval_rpn_with_buffer<'a, N, C>(
+    rpn: &[Token<N>],
+    positions: &[Option<usize>],
+    lazy_calls: &[LazyCall<N>],
+    context: &C,
+    steps: &Cell<usize>,
+    values: &mut Vec<N>,
+) -> Result<N>
+where
+    N: Debug + Clone,
+    C: Context<'a, N>,
+{
+    values.clear();
     // Stores the argument count of the current function, if any.
     let mut arg_count: Option<usize> = None;
+    // The index of the next lazy call to evaluate, the calls are sorted by its index.
+    let mut next_lazy_call = 0;
+
+    let locate = |error: Error, index: usize| match positions.get(index).copied().flatten() {
+        Some(position) if error.position().is_none() => error.with_position(position),
+        _ => error,
+    };
+
+    for (index, token) in rpn.iter().enumerate() {
+        if let UnaryOperator(_) | BinaryOperator(_) | Function(_) = token {
+            count_step(steps, context.config())?;
+        }
 
-    for token in &rpn {
         match token {
             Number(n) => values.push(n.clone()),
+            NumberWithUnit(_, unit) => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("Unit `{}` cannot be evaluated, convert it to a number first", unit),
+                ));
+            }
+            Variable(_) if lazy_calls.get(next_lazy_call).is_some_and(|call| call.index == index) => {
+                let value = lazy_calls[next_lazy_call]
+                    .eval(context, steps)
+                    .map_err(|e| locate(e, index))?;
+                next_lazy_call += 1;
+                values.push(value);
+            }
             Variable(name) => {
                 let n = context
                     .get_variable(name)
+                    .or_else(|| context.unknown_variable_value())
                     .ok_or_else(|| {
                         Error::new(
                             ErrorKind::InvalidInput,
                             format!("Variable `{}` not found", name),
                         )
-                    })?
+                    })
+                    .map_err(|e| locate(e, index))?
                     .clone();
 
                 values.push(n);
@@ -177,13 +1297,13 @@ where
 
                 match values.pop() {
                     Some(n) => {
-                        let result = func.call(n)?;
+                        let result = func.call(n).map_err(|e| locate(e, index))?;
                         values.push(result);
                     }
                     _ => {
                         return Err(Error::new(
                             ErrorKind::InvalidExpression,
-                            format!("{:?}", &tokens),
+                            format!("{:?}", rpn),
                         ));
                     }
                 }
@@ -198,26 +1318,18 @@ where
 
                 match (values.pop(), values.pop()) {
                     (Some(x), Some(y)) => {
-                        let result = func.call(y, x)?;
+                        let result = func.call(y, x).map_err(|e| locate(e, index))?;
                         values.push(result);
                     }
                     _ => {
                         return Err(Error::new(
                             ErrorKind::InvalidExpression,
-                            format!("{:?}", &tokens),
+                            format!("{:?}", rpn),
                         ));
                     }
                 }
             }
             Function(name) => {
-                // A reference to the function
-                let func = context.get_function(name).ok_or_else(|| {
-                    Error::new(
-                        ErrorKind::InvalidInput,
-                        format!("Function `{}` not found", name),
-                    )
-                })?;
-
                 // The number of arguments the function takes
                 let n = arg_count.ok_or_else(|| {
                     Error::new(
@@ -229,6 +1341,17 @@ where
                     )
                 })?;
 
+                // A reference to the function, or its overload taking `n` arguments
+                let func = context.get_function_with_arity(name, n).ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("Function `{}` not found", name),
+                    )
+                })?;
+
+                check_function_call(name, n, context.config())
+                    .map_err(|e| locate(e, index))?;
+
                 // Stores the arguments to pass to the function.
                 let mut args = Vec::new();
 
@@ -248,7 +1371,7 @@ where
                 // For a function as `TakeFirst(1, 2, 3)`, values are taken from last,
                 // so `args` will contain [3, 2, 1], so reverse is needed.
                 args.reverse();
-                let result = func.call(&args)?;
+                let result = func.call(&args).map_err(|e| locate(e, index))?;
                 values.push(result);
                 arg_count = None;
             }
@@ -269,6 +1392,21 @@ where
     }
 }
 
+/// Checks the function call is allowed by the max arguments of the config.
+pub(crate) fn check_function_call(
+    name: &str,
+    arg_count: usize,
+    config: &Config,
+) -> Result<()> {
+    match config.max_args {
+        Some(max_args) if arg_count > max_args => Err(Error::new(
+            ErrorKind::InvalidArgumentCount,
+            format!("Function `{}` cannot take more than {} arguments", name, max_args),
+        )),
+        _ => Ok(()),
+    }
+}
+
 /// Converts the given array of tokens to reverse polish notation.
 ///
 /// # Arguments
@@ -299,13 +1437,18 @@ where
 mod shunting_yard {
     use std::fmt::Debug;
 
-    use crate::context::Context;
+    use crate::context::{Config, Context};
     use crate::error::{Error, ErrorKind};
     use crate::function::{Associativity, Notation};
     use crate::token::Token;
     use crate::token::Token::*;
+    use crate::tokenizer::PIPE_OPERATOR;
     use crate::Result;
 
+    /// A token paired with the index of the infix token it comes from,
+    /// or `None` if was generated during the conversion, eg: the argument count of a function.
+    pub type IndexedToken<N> = (Token<N>, Option<usize>);
+
     /// Converts an `infix` notation expression to `rpn` (Reverse Polish Notation) using
     /// the shunting yard algorithm.
     ///
@@ -319,6 +1462,22 @@ mod shunting_yard {
         N: Clone + Debug,
         C: Context<'a, N>,
     {
+        let rpn = infix_to_rpn_indexed(tokens, context)?;
+        Ok(rpn.into_iter().map(|(token, _)| token).collect())
+    }
+
+    /// Converts an `infix` notation expression to `rpn` as `infix_to_rpn`, pairing each token
+    /// of the result with the index of the infix token it comes from.
+    pub fn infix_to_rpn_indexed<'a, N, C>(
+        tokens: &[Token<N>],
+        context: &C,
+    ) -> Result<Vec<IndexedToken<N>>>
+    where
+        N: Clone + Debug,
+        C: Context<'a, N>,
+    {
+        check_incomplete_end(context, tokens)?;
+
         let mut output = Vec::new();
         let mut operators = Vec::new();
         let mut arg_count: Vec<usize> = Vec::new();
@@ -327,14 +1486,30 @@ mod shunting_yard {
         let mut token_iterator = tokens.iter().enumerate().peekable();
         while let Some((pos, token)) = token_iterator.next() {
             match token {
-                Token::Number(_) | Token::Variable(_) | Token::Constant(_) => {
-                    push_number(context, &mut output, &mut operators, token)
+                Token::Number(_)
+                | Token::NumberWithUnit(_, _)
+                | Token::Variable(_)
+                | Token::Constant(_) => push_number(context, &mut output, &mut operators, token, pos),
+                Token::BinaryOperator(name)
+                    if name == PIPE_OPERATOR && !context.is_binary_function(name) =>
+                {
+                    match token_iterator.next() {
+                        Some((func_pos, Token::Function(func))) => {
+                            push_pipe(&mut output, &mut operators, func, func_pos)?
+                        }
+                        _ => {
+                            return Err(Error::new(
+                                ErrorKind::InvalidExpression,
+                                format!("Expected a function after `{}`", PIPE_OPERATOR),
+                            ))
+                        }
+                    }
                 }
                 Token::BinaryOperator(name) => {
-                    push_binary_function(context, &mut output, &mut operators, token, name)?;
+                    push_binary_function(context, &mut output, &mut operators, token, pos, name)?;
                 }
                 Token::UnaryOperator(name) => {
-                    push_unary_function(context, &mut output, &mut operators, token, name)?
+                    push_unary_function(context, &mut output, &mut operators, token, pos, name)?
                 }
                 Token::Function(name) => {
                     if !context.config().custom_function_call {
@@ -343,7 +1518,7 @@ mod shunting_yard {
                         // with custom grouping symbols are invalid eg: Max[1,2,3], Sum<2,4,6>
                         if !token_iterator
                             .peek()
-                            .map_or(false, |t| t.1.contains_symbol('('))
+                            .is_some_and(|t| t.1.contains_symbol('('))
                         {
                             return Err(Error::new(
                                 ErrorKind::InvalidInput,
@@ -356,10 +1531,10 @@ mod shunting_yard {
                     }
 
                     arg_count.push(0);
-                    operators.push(token.clone());
+                    operators.push((token.clone(), Some(pos)));
                 }
                 Token::GroupingOpen(_) => {
-                    operators.push(token.clone());
+                    operators.push((token.clone(), Some(pos)));
                     if !arg_count.is_empty() {
                         grouping_count.push(pos);
                     }
@@ -373,7 +1548,7 @@ mod shunting_yard {
                             if context
                                 .config()
                                 .get_group_open_for(*c)
-                                .map_or(false, |v| v == s)
+                                .is_some_and(|v| v == s)
                                 && !tokens[pos - 2].is_function()
                             {
                                 return Err(Error::new(
@@ -406,47 +1581,64 @@ mod shunting_yard {
             }
 
             // If implicit multiplication
-            if context.config().implicit_mul {
-                if token.is_number() {
-                    // 2Max, 2PI, 2x, 2(4)
-                    if let Some(next_token) = token_iterator.peek() {
-                        match next_token.1 {
-                            Token::Function(_)
-                            | Token::Constant(_)
-                            | Token::Variable(_)
-                            | Token::GroupingOpen(_) => {
-                                operators.push(BinaryOperator('*'.to_string()));
-                            }
-                            _ => {}
-                        }
-                    }
-                } else if token.is_grouping_close() {
-                    //(2)2, (2)PI, (2)x, (4)(2), Sin(30)Cos(30), Tan(45)2
-                    if let Some(next_token) = token_iterator.peek() {
-                        match next_token.1 {
-                            Number(_) | Variable(_) | Constant(_) | Function(_)
-                            | GroupingOpen(_) => operators.push(BinaryOperator('*'.to_string())),
-                            _ => {}
-                        }
-                    }
+            if let Some(next_token) = token_iterator.peek() {
+                if is_implicit_mul(context.config(), token, next_token.1) {
+                    operators.push((BinaryOperator('*'.to_string()), None));
                 }
             }
         }
 
-        while let Some(t) = operators.pop() {
-            if t.is_grouping_close() || t.is_grouping_close() {
+        while let Some((t, index)) = operators.pop() {
+            if t.is_grouping_open() {
+                return Err(Error::new(
+                    ErrorKind::IncompleteExpression,
+                    "Unclosed grouping symbol",
+                ));
+            }
+
+            if t.is_grouping_close() {
                 return Err(Error::new(
                     ErrorKind::InvalidExpression,
                     "Misplace parentheses",
                 ));
             }
 
-            output.push(t)
+            output.push((t, index))
         }
 
         Ok(output)
     }
 
+    /// Checks if the expression ends with a token that expects more input after it,
+    /// eg: a binary operator as in `8 +`.
+    fn check_incomplete_end<'a, N, C>(context: &C, tokens: &[Token<N>]) -> Result<()>
+    where
+        C: Context<'a, N>,
+    {
+        let last = match tokens {
+            // A function applied by the pipe operator, eg: `16 |> sqrt`
+            [.., Token::BinaryOperator(op), Token::Function(_)] if op == PIPE_OPERATOR => return Ok(()),
+            [.., last] => last,
+            [] => return Ok(()),
+        };
+
+        let symbol = match last {
+            Token::BinaryOperator(name) | Token::Function(name) => name.clone(),
+            Token::UnaryOperator(name) => match context.get_unary_function(name) {
+                Some(func) if func.notation() == Notation::Prefix => name.clone(),
+                _ => return Ok(()),
+            },
+            Token::GroupingOpen(c) => c.to_string(),
+            Token::Comma => context.config().arg_separator().to_string(),
+            _ => return Ok(()),
+        };
+
+        Err(Error::new(
+            ErrorKind::IncompleteExpression,
+            format!("Expected more input after `{}`", symbol),
+        ))
+    }
+
     fn check_comma_position<N>(
         tokens: &[Token<N>],
         grouping_count: &[usize],
@@ -457,12 +1649,12 @@ mod shunting_yard {
             return Err(Error::new(ErrorKind::InvalidInput, "Misplaced comma"));
         }
 
-        if tokens.get(pos - 1).map_or(false, |t| t.is_grouping_open()) {
+        if tokens.get(pos - 1).is_some_and(|t| t.is_grouping_open()) {
             // Invalid expression: `(,`
             return Err(Error::new(ErrorKind::InvalidInput, "Misplaced comma: `(,`"));
         }
 
-        if tokens.get(pos + 1).map_or(false, |t| t.is_grouping_close()) {
+        if tokens.get(pos + 1).is_some_and(|t| t.is_grouping_close()) {
             // Invalid expression: `,)`
             return Err(Error::new(ErrorKind::InvalidInput, "Misplaced comma: `,)`"));
         }
@@ -472,7 +1664,7 @@ mod shunting_yard {
         if !grouping_count.is_empty()
             && !tokens
                 .get(*grouping_count.last().unwrap() - 1)
-                .map_or(false, |t| t.is_function())
+                .is_some_and(|t| t.is_function())
         {
             return Err(Error::new(ErrorKind::InvalidInput, "Misplaced comma"));
         }
@@ -482,12 +1674,13 @@ mod shunting_yard {
 
     fn push_number<'a, N: Clone + Debug>(
         context: &impl Context<'a, N>,
-        output: &mut Vec<Token<N>>,
-        operators: &mut Vec<Token<N>>,
+        output: &mut Vec<IndexedToken<N>>,
+        operators: &mut Vec<IndexedToken<N>>,
         token: &Token<N>,
+        pos: usize,
     ) {
-        output.push(token.clone());
-        if let Some(Token::UnaryOperator(op)) = operators.last() {
+        output.push((token.clone(), Some(pos)));
+        if let Some((Token::UnaryOperator(op), _)) = operators.last() {
             if context.get_unary_function(op).is_some() {
                 output.push(operators.pop().unwrap());
             }
@@ -496,21 +1689,22 @@ mod shunting_yard {
 
     fn push_unary_function<'a, N: Clone + Debug>(
         context: &impl Context<'a, N>,
-        output: &mut Vec<Token<N>>,
-        operators: &mut Vec<Token<N>>,
+        output: &mut Vec<IndexedToken<N>>,
+        operators: &mut Vec<IndexedToken<N>>,
         token: &Token<N>,
+        pos: usize,
         name: &str,
     ) -> Result<()> {
         if let Some(unary) = context.get_unary_function(name) {
             match unary.notation() {
                 Notation::Prefix => {
                     //+6
-                    operators.push(token.clone());
+                    operators.push((token.clone(), Some(pos)));
                 }
                 Notation::Postfix => {
                     // 5!
                     if !output.is_empty() {
-                        output.push(token.clone())
+                        output.push((token.clone(), Some(pos)))
                     } else {
                         return Err(Error::new(
                             ErrorKind::InvalidExpression,
@@ -531,9 +1725,10 @@ mod shunting_yard {
 
     fn push_binary_function<'a, N: Clone + Debug>(
         context: &impl Context<'a, N>,
-        output: &mut Vec<Token<N>>,
-        operators: &mut Vec<Token<N>>,
+        output: &mut Vec<IndexedToken<N>>,
+        operators: &mut Vec<IndexedToken<N>>,
         token: &Token<N>,
+        pos: usize,
         name: &str,
     ) -> Result<()> {
         let operator = context.get_binary_function(name).ok_or_else(|| {
@@ -543,7 +1738,11 @@ mod shunting_yard {
             )
         })?;
 
-        while let Some(t) = operators.last() {
+        // When evaluating from left to right all the operators have the same precedence
+        // and are left associative, eg: `2 + 3 * 4` is evaluated as `(2 + 3) * 4`
+        let left_to_right = context.config().left_to_right;
+
+        while let Some((t, _)) = operators.last() {
             if let Token::GroupingOpen(_) = t {
                 break;
             }
@@ -559,15 +1758,15 @@ mod shunting_yard {
                 };
 
                 match top_operator {
-                    Some(top) => {
+                    Some(_) if left_to_right => {
+                        output.push(operators.pop().unwrap());
+                    }
+                    Some(top)
                         if (top.precedence() > operator.precedence())
                             || (top.precedence() == operator.precedence()
-                                && top.associativity() == Associativity::Left)
-                        {
-                            output.push(operators.pop().unwrap());
-                        } else {
-                            break;
-                        }
+                                && top.associativity() == Associativity::Left) =>
+                    {
+                        output.push(operators.pop().unwrap());
                     }
                     _ => {
                         break;
@@ -576,15 +1775,43 @@ mod shunting_yard {
             }
         }
 
-        operators.push(token.clone());
+        operators.push((token.clone(), Some(pos)));
+        Ok(())
+    }
+
+    /// Applies the function to the value at the left of the pipe operator, which has the
+    /// lowest precedence, so `2 + 14 |> sqrt` is `sqrt(2 + 14)`.
+    fn push_pipe<N: Clone + Debug>(
+        output: &mut Vec<IndexedToken<N>>,
+        operators: &mut Vec<IndexedToken<N>>,
+        func: &str,
+        pos: usize,
+    ) -> Result<()> {
+        if output.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidExpression,
+                format!("Expected a value before `{}`", PIPE_OPERATOR),
+            ));
+        }
+
+        while let Some((t, _)) = operators.last() {
+            if t.is_grouping_open() {
+                break;
+            }
+
+            output.push(operators.pop().unwrap());
+        }
+
+        output.push((Token::ArgCount(1), None));
+        output.push((Token::Function(func.to_string()), Some(pos)));
         Ok(())
     }
 
     fn push_grouping_close<'a, N: Clone + Debug>(
         context: &impl Context<'a, N>,
         group_close: char,
-        output: &mut Vec<Token<N>>,
-        operators: &mut Vec<Token<N>>,
+        output: &mut Vec<IndexedToken<N>>,
+        operators: &mut Vec<IndexedToken<N>>,
         arg_count: &mut Vec<usize>,
     ) -> Result<()> {
         // Flag used for detect misplaced grouping symbol.
@@ -592,7 +1819,7 @@ mod shunting_yard {
 
         // Pop tokens from the operator stack and push then into the output stack
         // until a group close token is found.
-        while let Some(t) = operators.pop() {
+        while let Some((t, index)) = operators.pop() {
             match t {
                 Token::GroupingOpen(c) => {
                     if let Some((_, close)) = context.config().get_group_symbol(c) {
@@ -601,9 +1828,9 @@ mod shunting_yard {
                             // If `arg_count` is not empty we are inside a function.
                             // So we pop the argument count and function token into the output stack.
                             if !arg_count.is_empty() {
-                                if let Some(Token::Function(_)) = operators.last() {
+                                if let Some((Token::Function(_), _)) = operators.last() {
                                     let count = arg_count.pop().unwrap() + 1;
-                                    output.push(Token::ArgCount(count));
+                                    output.push((Token::ArgCount(count), None));
                                     output.push(operators.pop().unwrap());
                                 }
                             }
@@ -612,7 +1839,7 @@ mod shunting_yard {
 
                     break;
                 }
-                _ => output.push(t.clone()),
+                _ => output.push((t, index)),
             }
         }
 
@@ -626,10 +1853,36 @@ mod shunting_yard {
         }
     }
 
+    /// Checks if there is an implicit multiplication between the given tokens,
+    /// using the implicit multiplication cases enabled in the `Config`.
+    pub fn is_implicit_mul<N>(config: &Config, token: &Token<N>, next_token: &Token<N>) -> bool {
+        let enabled = |case: bool| config.implicit_mul || case;
+
+        match (token, next_token) {
+            // 2Max, 2PI, 2x
+            (Number(_), Function(_) | Constant(_) | Variable(_)) => {
+                enabled(config.implicit_mul_number_name)
+            }
+            // 2(4), (2)2, Tan(45)2
+            (Number(_), GroupingOpen(_)) | (GroupingClose(_), Number(_)) => {
+                enabled(config.implicit_mul_number_group)
+            }
+            // (4)(2)
+            (GroupingClose(_), GroupingOpen(_)) => enabled(config.implicit_mul_group_group),
+            // (2)PI, (2)x, Sin(30)Cos(30)
+            (GroupingClose(_), Function(_) | Constant(_) | Variable(_)) => {
+                enabled(config.implicit_mul_group_name)
+            }
+            // 3 2
+            (Number(_), Number(_)) => config.implicit_mul_number_number,
+            _ => false,
+        }
+    }
+
     fn push_comma<N: Clone + Debug>(
-        output: &mut Vec<Token<N>>,
-        operators: &mut Vec<Token<N>>,
-        arg_count: &mut Vec<usize>,
+        output: &mut Vec<IndexedToken<N>>,
+        operators: &mut Vec<IndexedToken<N>>,
+        arg_count: &mut [usize],
     ) -> Result<()> {
         match arg_count.last_mut() {
             None => {
@@ -642,7 +1895,7 @@ mod shunting_yard {
         }
 
         let mut is_group_open = false;
-        while let Some(t) = operators.last() {
+        while let Some((t, _)) = operators.last() {
             match t {
                 Token::GroupingOpen(_) => {
                     is_group_open = true;
@@ -988,6 +2241,520 @@ mod tests {
 
         // Confusing expression
         assert!(evaluator.eval("3 2Sin(50)").is_err());
+        assert!(evaluator.eval("3 2").is_err());
+    }
+
+    #[test]
+    fn eval_implicit_mul_number_number_test() {
+        let config = Config::new()
+            .with_implicit_mul(true)
+            .with_implicit_mul_number_number(true);
+        let evaluator: Evaluator<f64> = Evaluator::with_context(DefaultContext::with_config_unchecked(config));
+
+        assert_eq!(evaluator.eval("3 2"), Ok(6_f64));
+        assert_eq!(evaluator.eval("2 3 4 + 1"), Ok(25_f64));
+        assert_eq!(evaluator.eval("3 2Sin(50)"), evaluator.eval("6 * Sin(50)"));
+        assert_eq!(evaluator.eval("3 -2"), Ok(1_f64));
+
+        // Only numbers separated by whitespaces
+        let config = Config::new().with_implicit_mul_number_number(true);
+        let evaluator: Evaluator<f64> = Evaluator::with_context(DefaultContext::with_config_unchecked(config));
+        assert_eq!(evaluator.eval("3 2"), Ok(6_f64));
+        assert!(evaluator.eval("2(4)").is_err());
+    }
+
+    #[test]
+    fn eval_implicit_mul_cases_test() {
+        fn evaluator_with(config: Config) -> Evaluator<'static, i64> {
+            let mut context = DefaultContext::with_config_checked(config);
+            context.set_variable("x", 5).unwrap();
+            Evaluator::with_context(context)
+        }
+
+        let evaluator = evaluator_with(Config::new().with_implicit_mul_number_name(true));
+        assert_eq!(evaluator.eval("2x").unwrap(), 10);
+        assert_eq!(evaluator.eval("2Max(1, 3)").unwrap(), 6);
+        assert!(evaluator.eval("2(3)").is_err());
+        assert!(evaluator.eval("(2)x").is_err());
+
+        let evaluator = evaluator_with(Config::new().with_implicit_mul_number_group(true));
+        assert_eq!(evaluator.eval("2(3)").unwrap(), 6);
+        assert_eq!(evaluator.eval("(3)2").unwrap(), 6);
+        assert_eq!(evaluator.eval("Max(1, 3)2").unwrap(), 6);
+        assert!(evaluator.eval("2x").is_err());
+        assert!(evaluator.eval("(2)(3)").is_err());
+
+        let evaluator = evaluator_with(Config::new().with_implicit_mul_group_group(true));
+        assert_eq!(evaluator.eval("(2)(3)").unwrap(), 6);
+        assert!(evaluator.eval("2(3)").is_err());
+        assert!(evaluator.eval("(2)x").is_err());
+
+        let evaluator = evaluator_with(Config::new().with_implicit_mul_group_name(true));
+        assert_eq!(evaluator.eval("(2)x").unwrap(), 10);
+        assert_eq!(evaluator.eval("Max(1, 2)Max(3, 4)").unwrap(), 8);
+        assert!(evaluator.eval("(2)(3)").is_err());
+        assert!(evaluator.eval("2x").is_err());
+
+        // `implicit_mul` enables all the cases
+        let evaluator = evaluator_with(Config::new().with_implicit_mul(true));
+        assert_eq!(evaluator.eval("2(3)(4)Max(1, 2)x").unwrap(), 240);
+    }
+
+    #[test]
+    fn eval_with_arg_separator_test() {
+        let config = Config::new().with_arg_separator(';');
+        let evaluator: Evaluator<i64> =
+            Evaluator::with_context(DefaultContext::with_config_checked(config));
+
+        assert_eq!(evaluator.eval("Max(1; 2; 3)"), Ok(3));
+        assert_eq!(evaluator.eval("Sum(1;2;3) * Min(4; 5)"), Ok(24));
+        assert_eq!(evaluator.eval("Max(1; Min(2; 3))"), Ok(2));
+        assert!(evaluator.eval("Max(1, 2, 3)").is_err());
+        assert!(evaluator.eval("Max(1; )").is_err());
+    }
+
+    #[test]
+    fn eval_incomplete_expression_test() {
+        let evaluator: Evaluator<i64> = Evaluator::new();
+        let kind_of = |expr: &str| evaluator.eval(expr).unwrap_err().kind();
+
+        assert_eq!(kind_of("8+"), ErrorKind::IncompleteExpression);
+        assert_eq!(kind_of("(1+2"), ErrorKind::IncompleteExpression);
+        assert_eq!(kind_of("Max(1,"), ErrorKind::IncompleteExpression);
+        assert_eq!(kind_of("Max(1, 2"), ErrorKind::IncompleteExpression);
+        assert_eq!(kind_of("2 * -"), ErrorKind::IncompleteExpression);
+        assert_eq!(kind_of("if(1, 2"), ErrorKind::IncompleteExpression);
+
+        assert_eq!(evaluator.eval("5!"), Ok(120));
+        assert_ne!(kind_of("1+2)"), ErrorKind::IncompleteExpression);
+    }
+
+    #[test]
+    fn eval_lenient_trailing_test() {
+        let evaluator: Evaluator<i64> = Evaluator::new();
+        let error = evaluator.eval("2+2 xyz").unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+        assert_eq!(error.to_string(), "Unknown token `xyz` at position 4");
+
+        let config = Config::new().with_lenient_trailing(true);
+        let evaluator: Evaluator<i64> = Evaluator::with_context(DefaultContext::with_config_checked(config));
+        assert_eq!(evaluator.eval("2+2 xyz"), Ok(4));
+        assert_eq!(evaluator.eval("2+2 xyz 3"), Ok(4));
+        assert_eq!(evaluator.eval("(1 + 2) * 3 ??"), Ok(9));
+        assert!(evaluator.eval("xyz").is_err());
+        assert!(evaluator.eval("2 + xyz").is_err());
+    }
+
+    #[test]
+    fn compile_test() {
+        let mut evaluator: Evaluator<i64> = Evaluator::new();
+        evaluator.mut_context().set_variable("x", 2).unwrap();
+
+        let mut context = evaluator.context().clone();
+        let expr = evaluator.compile("x * (x + 1) - max(x, 3)").unwrap();
+        assert_eq!(expr.eval(&context), Ok(3));
+
+        context.set_variable("x", 5).unwrap();
+        assert_eq!(expr.eval(&context), Ok(25));
+        assert_eq!(expr.rpn().last(), Some(&BinaryOperator("-".to_string())));
+
+        assert!(evaluator.compile("x +").is_err());
+    }
+
+    #[test]
+    fn compile_lazy_args_test() {
+        let mut evaluator: Evaluator<i64> = Evaluator::new();
+        evaluator.mut_context().set_variable("x", 0).unwrap();
+
+        let expr = evaluator.compile("if(1, 2, 1/0)").unwrap();
+        assert_eq!(expr.eval(evaluator.context()), Ok(2));
+
+        let mut context = evaluator.context().clone();
+        let expr = evaluator.compile("3 * if(x, 10 / x, 0) + 1").unwrap();
+        assert_eq!(expr.eval(&context), Ok(1));
+
+        context.set_variable("x", 5).unwrap();
+        assert_eq!(expr.eval(&context), Ok(7));
+
+        let expr = evaluator.compile("if(x, if(x - 5, 1/0, 4), 1/0)").unwrap();
+        assert_eq!(expr.eval(&context), Ok(4));
+
+        let results = expr.eval_over("x", vec![5, 0], &mut context).collect::<Vec<_>>();
+        assert_eq!(results[0], Ok(4));
+        assert!(results[1].is_err());
+
+        assert!(evaluator.compile("if(1, 2").is_err());
+    }
+
+    #[test]
+    fn eval_over_test() {
+        let mut context: DefaultContext<i64> = DefaultContext::new_checked();
+        context.set_variable("x", 0).unwrap();
+        let expr = CompiledExpr::new("x^2", &context).unwrap();
+
+        let results = expr.eval_over("x", 0..=5, &mut context).collect::<Result<Vec<_>>>();
+        assert_eq!(results, Ok(vec![0, 1, 4, 9, 16, 25]));
+        assert_eq!(context.get_variable("x"), Some(&5));
+        assert_eq!(expr.eval(&context), Ok(25));
+
+        let expr = CompiledExpr::new("10 / x", &context).unwrap();
+        let results = expr.eval_over("x", vec![5, 0, 2], &mut context).collect::<Vec<_>>();
+        assert_eq!(results[0], Ok(2));
+        assert!(results[1].is_err());
+        assert_eq!(results[2], Ok(5));
+    }
+
+    #[test]
+    fn public_safe_test() {
+        let evaluator: Evaluator<i64> =
+            Evaluator::with_context(DefaultContext::with_config_checked(Config::public_safe()));
+
+        // Too long
+        let expr = vec!["1"; 600].join("+");
+        assert_eq!(evaluator.eval(&expr).unwrap_err().kind(), ErrorKind::InvalidInput);
+
+        // Too many arguments
+        let expr = format!("sum({})", vec!["1"; 101].join(","));
+        assert_eq!(evaluator.eval(&expr).unwrap_err().kind(), ErrorKind::InvalidArgumentCount);
+        let expr = format!("sum({})", vec!["1"; 100].join(","));
+        assert_eq!(evaluator.eval(&expr), Ok(100));
+
+        // Disabled, also by its alias
+        assert!(evaluator.eval("random()").is_err());
+        assert!(evaluator.eval("Random(1, 10)").is_err());
+
+        assert_eq!(evaluator.eval("2 * (3 + 4)"), Ok(14));
+    }
+
+    #[test]
+    fn max_steps_test() {
+        let config = Config::new().with_max_steps(3);
+        let evaluator: Evaluator<i64> = Evaluator::with_context(DefaultContext::with_config_checked(config));
+
+        assert_eq!(evaluator.eval("1 + 2 + 3 + 4"), Ok(10));
+        assert_eq!(evaluator.eval("1 + 2 + 3 + 4 + 5").unwrap_err().kind(), ErrorKind::InvalidExpression);
+        assert_eq!(evaluator.eval("-max(1, 2) * 3 + 1").unwrap_err().kind(), ErrorKind::InvalidExpression);
+
+        // The lazy arguments share the steps of the expression
+        assert_eq!(evaluator.eval("if(1, 2 + 3, 0)"), Ok(5));
+        assert_eq!(evaluator.eval("if(1, if(1, 1 + 2 + 3, 0), 0)").unwrap_err().kind(), ErrorKind::InvalidExpression);
+        assert_eq!(evaluator.eval("1 + if(1, 2 + 3 + 4, 0)").unwrap_err().kind(), ErrorKind::InvalidExpression);
+
+        let expr = evaluator.compile("if(1, if(1, 1 + 2 + 3, 0), 0)").unwrap();
+        assert_eq!(expr.eval(evaluator.context()).unwrap_err().kind(), ErrorKind::InvalidExpression);
+        let expr = evaluator.compile("if(1, 2 + 3, 0)").unwrap();
+        assert_eq!(expr.eval(evaluator.context()), Ok(5));
+    }
+
+    #[test]
+    fn dyn_evaluator_test() {
+        let evaluators: Vec<Box<dyn DynEvaluator<f64>>> = vec![
+            Box::new(Evaluator::<f64>::new()),
+            Box::new(Evaluator::with_context(DefaultContext::with_config_unchecked(Config::simple()))),
+        ];
+
+        let results = evaluators
+            .iter()
+            .map(|evaluator| evaluator.eval("2 + 3 * 4"))
+            .collect::<Vec<_>>();
+
+        assert_eq!(results, vec![Ok(14_f64), Ok(20_f64)]);
+        assert!(evaluators[1].eval("2 +").is_err());
+    }
+
+    #[test]
+    fn dyn_evaluator_same_as_eval_test() {
+        let config = Config::new().with_lenient_trailing(true);
+        let evaluator: Evaluator<f64> = Evaluator::with_context(DefaultContext::with_config_checked(config));
+        let dyn_evaluator: &dyn DynEvaluator<f64> = &evaluator;
+
+        for expression in &["2 + 2 xyz", "2 + asin(2)", "xyz", "2 +"] {
+            let expected = evaluator.eval(expression);
+            let result = dyn_evaluator.eval(expression);
+            assert_eq!(result, expected);
+            assert_eq!(
+                result.as_ref().err().map(|e| (e.to_string(), e.position())),
+                expected.as_ref().err().map(|e| (e.to_string(), e.position()))
+            );
+        }
+
+        assert_eq!(dyn_evaluator.eval("2 + 2 xyz"), Ok(4_f64));
+    }
+
+    #[test]
+    fn format_result_test() {
+        let evaluator: Evaluator<i128> = Evaluator::new();
+        let factorial = evaluator.eval("33!").unwrap();
+
+        assert_eq!(
+            evaluator.format_result(&factorial, ResultFormat::Full),
+            "8683317618811886495518194401280000000"
+        );
+        assert_eq!(
+            evaluator.format_result(&factorial, ResultFormat::Grouped(',')),
+            "8,683,317,618,811,886,495,518,194,401,280,000,000"
+        );
+        assert_eq!(evaluator.format_result(&factorial, ResultFormat::Scientific(3)), "8.683e36");
+        assert_eq!(evaluator.format_result(&-factorial, ResultFormat::Scientific(0)), "-9e36");
+    }
+
+    #[test]
+    fn eval_lazy_args_test() {
+        let evaluator: Evaluator<i64> = Evaluator::new();
+
+        assert_eq!(evaluator.eval("if(1, 2, 1/0)"), Ok(2));
+        assert_eq!(evaluator.eval("if(0, 1/0, 3)"), Ok(3));
+        assert_eq!(evaluator.eval("2 * if(3 - 3, 1/0, if(1, 4, 1/0)) + 1"), Ok(9));
+        assert!(evaluator.eval("if(0, 2, 1/0)").is_err());
+        assert!(evaluator.eval("if(1, 2)").is_err());
+        assert_eq!(evaluator.eval("ternary(0, 1/0, 3)"), Ok(3));
+
+        // Evaluated eagerly from RPN
+        let rpn = [Number(1), Number(2), Number(3), ArgCount(3), Function("if".to_string())];
+        assert_eq!(evaluator.eval_rpn(&rpn), Ok(2));
+    }
+
+    #[test]
+    fn dependencies_test() {
+        let mut evaluator: Evaluator<f64> = Evaluator::new();
+
+        let dependencies = evaluator.dependencies("sin(x) + max(y, 2)").unwrap();
+        assert_eq!(
+            dependencies.variables,
+            ["x", "y"].iter().map(|s| s.to_string()).collect()
+        );
+        assert_eq!(
+            dependencies.functions,
+            ["sin", "max", "+"].iter().map(|s| s.to_string()).collect()
+        );
+
+        // Constants are not dependencies
+        evaluator.mut_context().set_variable("x", 2_f64).unwrap();
+        let dependencies = evaluator.dependencies("-x * PI").unwrap();
+        assert_eq!(dependencies.to_string(), "variables: {x}, functions: {*, -}");
+    }
+
+    #[test]
+    fn eval_with_resolver_test() {
+        use std::cell::Cell;
+
+        let mut evaluator: Evaluator<f64> = Evaluator::new();
+        evaluator.mut_context().set_variable("y", 3_f64).unwrap();
+
+        let calls = Cell::new(0);
+        let resolver = |name: &str| {
+            calls.set(calls.get() + 1);
+            match name {
+                "x" => Some(2_f64 * 5_f64),
+                "y" => Some(100_f64),
+                _ => None,
+            }
+        };
+
+        // `y` is in the context, `x` is resolved once
+        assert_eq!(evaluator.eval_with_resolver("x * y + x", resolver), Ok(40_f64));
+        assert_eq!(calls.get(), 1);
+
+        assert!(evaluator.eval_with_resolver("z + 1", resolver).is_err());
+        assert!(evaluator.context().get_variable("x").is_none());
+    }
+
+    #[test]
+    fn comparison_and_logical_test() {
+        let mut evaluator: Evaluator<i64> = Evaluator::new();
+        evaluator.mut_context().set_variable("x", 7).unwrap();
+
+        assert_eq!(evaluator.eval("if(x > 5, 1, 0)"), Ok(1));
+        assert_eq!(evaluator.eval("(x == 7) * 3"), Ok(3));
+        assert_eq!(evaluator.eval("2 + 3 > 4 * 2"), Ok(0));
+        assert_eq!(evaluator.eval("x != 3!"), Ok(1));
+        assert_eq!(evaluator.eval("and(x >= 1, x <= 10, not(x < 0))"), Ok(1));
+        assert_eq!(evaluator.eval("or(x < 0, x gt 8)"), Ok(0));
+
+        let evaluator: Evaluator<f64> = Evaluator::with_context(DefaultContext::new_unchecked());
+        assert_eq!(evaluator.eval("(1.5 < 2) + (2 lte 2)"), Ok(2_f64));
+    }
+
+    #[test]
+    fn pipe_operator_test() {
+        let evaluator: Evaluator<f64> = Evaluator::new();
+
+        assert_eq!(evaluator.eval("16 |> sqrt"), evaluator.eval("sqrt(16)"));
+        assert_eq!(evaluator.eval("-16 |> abs |> sqrt"), Ok(4_f64));
+        assert_eq!(evaluator.eval("2 + 14 |> sqrt"), Ok(4_f64));
+        assert_eq!(evaluator.eval("(9 |> sqrt) * 2"), Ok(6_f64));
+        assert_eq!(evaluator.eval("max(4 |> sqrt, 3)"), Ok(3_f64));
+
+        assert!(evaluator.eval("16 |> 2").is_err());
+        assert!(evaluator.eval("|> sqrt").is_err());
+        assert_eq!(evaluator.eval("16 |>").unwrap_err().kind(), ErrorKind::IncompleteExpression);
+    }
+
+    #[test]
+    fn eval_statements_test() {
+        let evaluator: Evaluator<i64> = Evaluator::new();
+        let statements = evaluator.eval_statements("a = 2; a + 1; b = a * a; ; max(a, b)").unwrap();
+
+        assert_eq!(
+            statements,
+            vec![
+                StatementResult::Assignment("a".to_string(), 2),
+                StatementResult::Expression(3),
+                StatementResult::Assignment("b".to_string(), 4),
+                StatementResult::Expression(4),
+            ]
+        );
+        assert_eq!(statements[0].to_string(), "a = 2");
+        assert_eq!(statements[1].to_string(), "3");
+
+        assert_eq!(evaluator.eval_statements(" ; "), Ok(vec![]));
+        assert!(evaluator.eval_statements("a = 2; a == c").is_err());
+    }
+
+    #[test]
+    fn eval_all_with_bindings_test() {
+        let evaluator: Evaluator<i64> = Evaluator::new();
+
+        let (result, bindings) = evaluator.eval_all_with_bindings("a = 2; b = a*3; b+1").unwrap();
+        assert_eq!(result, 7);
+        assert_eq!(bindings.len(), 2);
+        assert_eq!(bindings["a"], 2);
+        assert_eq!(bindings["b"], 6);
+
+        let (result, bindings) = evaluator.eval_all_with_bindings("x = max(1, 5); x * 2").unwrap();
+        assert_eq!(result, 10);
+        assert_eq!(bindings["x"], 5);
+
+        assert!(evaluator.eval_all_with_bindings("a = 2; c + 1").is_err());
+        assert!(evaluator.eval_all_with_bindings(" ; ").is_err());
+        assert!(evaluator.context().get_variable("a").is_none());
+    }
+
+    #[test]
+    fn eval_scientific_notation_vs_constant_test() {
+        let evaluator: Evaluator<f64> = Evaluator::new();
+        let e = std::f64::consts::E;
+
+        assert_eq!(evaluator.eval("2e3"), Ok(2000_f64));
+        assert_eq!(evaluator.eval("2e-3"), Ok(0.002_f64));
+        assert_eq!(evaluator.eval("2*e"), Ok(2_f64 * e));
+        assert_eq!(evaluator.eval("e"), Ok(e));
+        assert_eq!(evaluator.eval("e^2"), Ok(e.powf(2_f64)));
+
+        // The constant is used when no exponent follows
+        let config = Config::new().with_implicit_mul(true);
+        let evaluator: Evaluator<f64> = Evaluator::with_context(DefaultContext::with_config_checked(config));
+        assert_eq!(evaluator.eval("2e"), Ok(2_f64 * e));
+        assert_eq!(evaluator.eval("2e3"), Ok(2000_f64));
+        assert_eq!(evaluator.eval("2e+1"), Ok(20_f64));
+    }
+
+    #[test]
+    fn eval_brace_grouping_test() {
+        let config = Config::new().with_grouping(Grouping::Brace);
+        let evaluator: Evaluator<i64> =
+            Evaluator::with_context(DefaultContext::with_config_checked(config));
+
+        assert_eq!(evaluator.eval("{1+2}*3"), Ok(9));
+        assert_eq!(evaluator.eval("{{2}}^{1+2}"), Ok(8));
+        assert!(evaluator.eval("{1+2*3").is_err());
+        assert!(evaluator.eval("{1+2)*3").is_err());
+
+        let config = Config::new()
+            .with_grouping(Grouping::Bracket)
+            .with_grouping(Grouping::Brace);
+        let evaluator: Evaluator<i64> =
+            Evaluator::with_context(DefaultContext::with_config_checked(config));
+
+        assert_eq!(evaluator.eval("([{1}])"), Ok(1));
+        assert_eq!(evaluator.eval("{[(1 + 2) * 3] - 4} * 2"), Ok(10));
+        assert_eq!(evaluator.eval("Max({1}, [2], (3))"), Ok(3));
+        assert!(evaluator.eval("([{1)]}").is_err());
+        assert!(evaluator.eval("{[1}]").is_err());
+    }
+
+    #[test]
+    fn eval_batch_test() {
+        let mut evaluator: Evaluator<f64> = Evaluator::new();
+        evaluator.mut_context().set_variable("x", 4_f64).unwrap();
+
+        let expressions = ["x * 2", "Max(x, 10) - 1", "x +", "y", "Sqrt(x)"];
+        let results = evaluator.eval_batch(&expressions);
+
+        assert_eq!(results.len(), expressions.len());
+        for (expression, result) in expressions.iter().zip(&results) {
+            assert_eq!(result, &evaluator.eval(expression));
+        }
+
+        assert_eq!(results[0], Ok(8_f64));
+        assert_eq!(results[1], Ok(9_f64));
+        assert!(results[2].is_err());
+        assert!(results[3].is_err());
+        assert_eq!(results[4], Ok(2_f64));
+        assert!(evaluator.eval_batch(&[]).is_empty());
+    }
+
+    #[test]
+    fn eval_number_with_unit_test() {
+        let config = Config::new().with_unit("km").with_unit("m");
+        let evaluator: Evaluator<i64> =
+            Evaluator::with_context(DefaultContext::with_config_checked(config));
+
+        assert_eq!(evaluator.eval("2km").unwrap_err().kind(), ErrorKind::InvalidInput);
+
+        // The host converts the units before evaluating
+        let tokens = evaluator
+            .tokenizer
+            .tokenize(evaluator.context(), "2km + 300m")
+            .unwrap()
+            .into_iter()
+            .map(|t| match t {
+                NumberWithUnit(n, unit) if unit == "km" => Number(n * 1000),
+                NumberWithUnit(n, _) => Number(n),
+                t => t,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(evaluator.eval_tokens(&tokens), Ok(2300));
+    }
+
+    #[test]
+    fn eval_typed_test() {
+        let evaluator: Evaluator<i64> = Evaluator::new();
+        assert_eq!(evaluator.eval_typed("2 * 3"), Ok((6, ResultInfo::Real)));
+        assert!(evaluator.eval_typed("2 *").is_err());
+    }
+
+    #[test]
+    fn eval_rpn_test() {
+        let evaluator: Evaluator<i64> = Evaluator::new();
+
+        // 3 2 +
+        assert_eq!(
+            evaluator.eval_rpn(&[
+                Token::Number(3),
+                Token::Number(2),
+                Token::BinaryOperator("+".to_string())
+            ]),
+            Ok(5)
+        );
+
+        // Max(1, 2, 3) * 2
+        let rpn = infix_to_rpn(
+            &evaluator.tokenizer.tokenize(evaluator.context(), "Max(1, 2, 3) * 2").unwrap(),
+            evaluator.context(),
+        )
+        .unwrap();
+        assert_eq!(evaluator.eval_rpn(&rpn), Ok(6));
+
+        // Infix tokens are not converted
+        assert!(evaluator
+            .eval_rpn(&[
+                Token::Number(3),
+                Token::BinaryOperator("+".to_string()),
+                Token::Number(2)
+            ])
+            .is_err());
     }
 
     #[test]
@@ -1045,6 +2812,182 @@ mod tests {
         assert_eq!(evaluator.eval("x + 2").unwrap(), 12);
     }
 
+    #[test]
+    fn eval_with_scopes_test() {
+        let mut base: DefaultContext<i64> = DefaultContext::new_checked();
+        base.set_variable("x", 10).unwrap();
+        base.set_variable("y", 3).unwrap();
+
+        let mut local: DefaultContext<i64> = DefaultContext::new();
+        local.set_variable("x", 2).unwrap();
+
+        let mut inner: DefaultContext<i64> = DefaultContext::new();
+        inner.set_variable("y", 5).unwrap();
+
+        let evaluator = Evaluator::with_context(base);
+        assert_eq!(evaluator.eval_with_scopes("x + y", &[]), Ok(13));
+        assert_eq!(evaluator.eval_with_scopes("x + y", &[&local]), Ok(5));
+        assert_eq!(evaluator.eval_with_scopes("Max(x, y)", &[&inner, &local]), Ok(5));
+        assert!(evaluator.eval_with_scopes("x + z", &[&local]).is_err());
+
+        // The base context is not modified
+        assert_eq!(evaluator.eval("x + y"), Ok(13));
+    }
+
+    #[test]
+    fn eval_with_operator_symbol_test() {
+        use crate::ops::checked::DivOperator;
+
+        let mut context: DefaultContext<i64> = DefaultContext::new_checked();
+        context.add_binary_function_as(DivOperator, "÷").unwrap();
+        context.add_binary_function_as(DivOperator, ":").unwrap();
+
+        let evaluator = Evaluator::with_context(context);
+        assert_eq!(evaluator.eval("6 ÷ 2"), Ok(3));
+        assert_eq!(evaluator.eval("6 / 2"), Ok(3));
+        assert_eq!(evaluator.eval("1 + 8 : 2 * 2"), Ok(9));
+        assert!(evaluator.eval("6 ÷ 0").is_err());
+    }
+
+    #[test]
+    fn eval_left_to_right_test() {
+        let evaluator: Evaluator<i64> = Evaluator::new();
+        assert_eq!(evaluator.eval("2 + 3 * 4"), Ok(14));
+        assert_eq!(evaluator.eval("2 ^ 3 ^ 2"), Ok(512));
+
+        let config = Config::new().with_left_to_right(true);
+        let evaluator: Evaluator<i64> =
+            Evaluator::with_context(DefaultContext::with_config_checked(config));
+
+        assert_eq!(evaluator.eval("2 + 3 * 4"), Ok(20));
+        assert_eq!(evaluator.eval("2 ^ 3 ^ 2"), Ok(64));
+        assert_eq!(evaluator.eval("10 - 4 / 2"), Ok(3));
+        assert_eq!(evaluator.eval("2 + (3 * 4)"), Ok(14));
+        assert_eq!(evaluator.eval("Max(1, 2 + 3 * 4)"), Ok(20));
+        assert_eq!(evaluator.eval("-2 + 3 * 4"), Ok(4));
+    }
+
+    #[test]
+    fn eval_float_ieee_test() {
+        let evaluator: Evaluator<f64> = Evaluator::new();
+        assert_eq!(evaluator.eval("1/0").unwrap_err().kind(), ErrorKind::DivisionByZero);
+
+        let config = Config::new().with_float_ieee(true);
+        let evaluator: Evaluator<f64> =
+            Evaluator::with_context(DefaultContext::with_config_checked(config));
+
+        assert_eq!(evaluator.eval("1/0"), Ok(f64::INFINITY));
+        assert_eq!(evaluator.eval("-1/0 - 2"), Ok(f64::NEG_INFINITY));
+        assert!(evaluator.eval("0/0").unwrap().is_nan());
+        assert_eq!(evaluator.eval("6/3"), Ok(2_f64));
+
+        assert_eq!(evaluator.eval("1e308 * 10"), Ok(f64::INFINITY));
+        assert_eq!(evaluator.eval("1/0 + 1"), Ok(f64::INFINITY));
+        assert!(evaluator.eval("(1/0) * 0").unwrap().is_nan());
+        assert_eq!(evaluator.eval("(1/0)^2"), Ok(f64::INFINITY));
+        assert_eq!(evaluator.eval("exp(1/0)"), Ok(f64::INFINITY));
+        assert_eq!(evaluator.eval("ln(1/0)"), Ok(f64::INFINITY));
+        assert_eq!(evaluator.eval("log(0)"), Ok(f64::NEG_INFINITY));
+        assert_eq!(evaluator.eval("sqrt(1/0)"), Ok(f64::INFINITY));
+        assert_eq!(evaluator.eval("2^10"), Ok(1024_f64));
+    }
+
+    #[test]
+    fn normalize_test() {
+        let mut context = DefaultContext::with_config_checked(Config::new().with_implicit_mul(true));
+        context.set_variable("x", 5).unwrap();
+
+        let evaluator: Evaluator<i64> = Evaluator::with_context(context);
+        let expected = Ok("2 * x".to_string());
+        assert_eq!(evaluator.normalize("2x"), expected);
+        assert_eq!(evaluator.normalize("2*x"), expected);
+        assert_eq!(evaluator.normalize("2 * x"), expected);
+
+        let expected = Ok("(2 + 1) * Max(x, 3) * PI".to_string());
+        assert_eq!(evaluator.normalize("(2+1)Max(x,3)PI"), expected);
+        assert_eq!(evaluator.normalize("(2 + 1) * Max(x, 3) * PI"), expected);
+
+        assert_eq!(evaluator.normalize("-x + 3!"), Ok("-x + 3!".to_string()));
+        assert!(evaluator.normalize("").is_err());
+    }
+
+    #[test]
+    fn expr_hash_test() {
+        let mut context = DefaultContext::with_config_checked(Config::new().with_implicit_mul(true));
+        context.set_variable("x", 5).unwrap();
+
+        let evaluator: Evaluator<i64> = Evaluator::with_context(context);
+        let hash = evaluator.expr_hash("2x + 1").unwrap();
+        assert_eq!(evaluator.expr_hash("2*x+1"), Ok(hash));
+        assert_eq!(evaluator.expr_hash("2 * x + 1"), Ok(hash));
+
+        assert_ne!(evaluator.expr_hash("2x + 2"), Ok(hash));
+        assert_ne!(evaluator.expr_hash("2x - 1"), Ok(hash));
+        assert!(evaluator.expr_hash("").is_err());
+    }
+
+    #[test]
+    fn runtime_error_position_test() {
+        let evaluator: Evaluator<f64> = Evaluator::new();
+
+        assert_eq!(evaluator.eval("2 + asin(2)").unwrap_err().position(), Some(4));
+        assert_eq!(evaluator.eval("max(1, 2) + log(-4)").unwrap_err().position(), Some(12));
+        assert_eq!(evaluator.eval("sqrt(4) * asin(-4)").unwrap_err().position(), Some(10));
+        assert_eq!(evaluator.eval("1 + 16 |> sqrt - 2 + (2 |> asin)").unwrap_err().position(), Some(27));
+        assert_eq!(evaluator.eval("2 + (-1)!").unwrap_err().position(), Some(8));
+        assert_eq!(evaluator.eval("if(1, asin(2), 2)").unwrap_err().position(), Some(0));
+        assert_eq!(evaluator.eval("2 + xyz").unwrap_err().position(), Some(4));
+
+        let evaluator: Evaluator<i64> = Evaluator::new();
+        assert_eq!(evaluator.eval("10 / (5 - 5)").unwrap_err().position(), Some(3));
+
+        // Tokens evaluated directly have no positions
+        let tokens = [Number(10), BinaryOperator("/".to_string()), Number(0)];
+        assert_eq!(evaluator.eval_tokens(&tokens).unwrap_err().position(), None);
+    }
+
+    #[test]
+    fn unknown_variable_policy_test() {
+        use crate::context::UnknownVariablePolicy;
+
+        let evaluator: Evaluator<i64> = Evaluator::new();
+        assert!(evaluator.eval("x + 1").is_err());
+        assert!(evaluator.parse("x + 1").is_err());
+
+        let context = DefaultContext::new_checked().with_unknown_variable_policy(UnknownVariablePolicy::DefaultTo(0));
+        let mut evaluator: Evaluator<i64> = Evaluator::with_context(context);
+        assert_eq!(evaluator.eval("x + 1"), Ok(1));
+        assert_eq!(evaluator.eval("x * y + 5"), Ok(5));
+
+        let expr = evaluator.parse("x + 1").unwrap();
+        assert_eq!(expr.eval(evaluator.context()), Ok(1));
+
+        // Known variables and the resolver take priority over the default
+        evaluator.mut_context().set_variable("x", 10).unwrap();
+        assert_eq!(evaluator.eval("x + 1"), Ok(11));
+        assert_eq!(evaluator.eval_with_resolver("x + y", |_| Some(2)), Ok(12));
+        assert_eq!(evaluator.eval_with_resolver("x + y", |_| None), Ok(10));
+        assert_eq!(evaluator.parse("x + y").unwrap().eval(evaluator.context()), Ok(10));
+
+        let context = DefaultContext::new_unchecked().with_unknown_variable_policy(UnknownVariablePolicy::DefaultTo(2.5));
+        let evaluator: Evaluator<f64> = Evaluator::with_context(context);
+        assert_eq!(evaluator.eval("x * 2"), Ok(5_f64));
+        assert_eq!(evaluator.parse("x * 2").unwrap().eval(evaluator.context()), Ok(5_f64));
+    }
+
+    #[test]
+    fn find_variables_test() {
+        let mut evaluator: Evaluator<f64> = Evaluator::new();
+        evaluator.mut_context().set_variable("a", 1_f64).unwrap();
+
+        assert_eq!(
+            evaluator.find_variables("b + a * max(b, c) - E"),
+            Ok(vec!["b".to_string(), "a".to_string(), "c".to_string()])
+        );
+        assert_eq!(evaluator.find_variables("sin(PI) + 2"), Ok(vec![]));
+        assert!(evaluator.find_variables("").is_err());
+    }
+
     #[test]
     fn eval_with_alias_test() {
         struct SumFunction;
@@ -0,0 +1,225 @@
+use crate::context::Context;
+use crate::error::{Error, ErrorKind};
+use crate::evaluator::check_function_call;
+use crate::function::LazyArg;
+use crate::token::Token;
+use crate::Result;
+use std::fmt::Debug;
+
+/// A parsed expression as a tree, built from its tokens in `Reverse Polish Notation`
+/// so the precedence of the operators is already resolved, see `Evaluator::parse`.
+///
+/// # Example
+/// ```
+/// use prexel::context::{Context, DefaultContext};
+/// use prexel::expr::Expr;
+/// use prexel::token::Token::*;
+///
+/// let rpn = [Variable("x".to_string()), Number(2), BinaryOperator("*".to_string())];
+/// let expr = Expr::from_rpn(&rpn).unwrap();
+///
+/// let mut context = DefaultContext::new_checked();
+/// for x in 1..=3 {
+///     context.set_variable("x", x).unwrap();
+///     assert_eq!(expr.eval(&context), Ok(x * 2));
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr<N> {
+    /// A number.
+    Number(N),
+    /// A variable, resolved when evaluated.
+    Variable(String),
+    /// A constant, resolved when evaluated.
+    Constant(String),
+    /// An unary operator and its operand, eg: `-x` or `5!`.
+    UnaryOp(String, Box<Expr<N>>),
+    /// A binary operator and its left and right operands, eg: `x + 1`.
+    BinaryOp(String, Box<Expr<N>>, Box<Expr<N>>),
+    /// A function and its arguments, eg: `max(1, 2, 3)`.
+    FunctionCall(String, Vec<Expr<N>>),
+}
+
+impl<N> Expr<N>
+where
+    N: Debug + Clone,
+{
+    /// Builds the tree of the given tokens in `Reverse Polish Notation`, eg: from `infix_to_rpn`.
+    pub fn from_rpn(rpn: &[Token<N>]) -> Result<Self> {
+        let mut stack: Vec<Expr<N>> = Vec::new();
+        let mut arg_count: Option<usize> = None;
+        let invalid_expression = || Error::new(ErrorKind::InvalidExpression, format!("{:?}", rpn));
+
+        for token in rpn {
+            let expr = match token {
+                Token::Number(n) => Expr::Number(n.clone()),
+                Token::NumberWithUnit(_, unit) => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("Unit `{}` cannot be evaluated, convert it to a number first", unit),
+                    ));
+                }
+                Token::Variable(name) => Expr::Variable(name.clone()),
+                Token::Constant(name) => Expr::Constant(name.clone()),
+                Token::ArgCount(n) => {
+                    arg_count = Some(*n);
+                    continue;
+                }
+                Token::UnaryOperator(name) => {
+                    let operand = stack.pop().ok_or_else(invalid_expression)?;
+                    Expr::UnaryOp(name.clone(), Box::new(operand))
+                }
+                Token::BinaryOperator(name) => {
+                    let right = stack.pop().ok_or_else(invalid_expression)?;
+                    let left = stack.pop().ok_or_else(invalid_expression)?;
+                    Expr::BinaryOp(name.clone(), Box::new(left), Box::new(right))
+                }
+                Token::Function(name) => {
+                    let n = arg_count.take().ok_or_else(|| {
+                        Error::new(
+                            ErrorKind::InvalidInput,
+                            format!("Cannot evaluate function `{}`, unknown number of arguments", name),
+                        )
+                    })?;
+
+                    if n > stack.len() {
+                        return Err(invalid_expression());
+                    }
+
+                    let args = stack.split_off(stack.len() - n);
+                    Expr::FunctionCall(name.clone(), args)
+                }
+                _ => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("Unknown token: `{:?}`", token),
+                    ));
+                }
+            };
+
+            stack.push(expr);
+        }
+
+        match (stack.pop(), stack.is_empty()) {
+            (Some(expr), true) => Ok(expr),
+            _ => Err(Error::from(ErrorKind::InvalidExpression)),
+        }
+    }
+
+    /// Evaluates this expression using the variables, constants and functions of the given context.
+    ///
+    /// # Remarks
+    /// The functions that want lazy arguments, like `if`, receive each argument without its tokens.
+    pub fn eval<'a, C>(&self, context: &C) -> Result<N>
+    where
+        C: Context<'a, N>,
+    {
+        match self {
+            Expr::Number(n) => Ok(n.clone()),
+            Expr::Variable(name) => context
+                .get_variable(name)
+                .or_else(|| context.unknown_variable_value())
+                .cloned()
+                .ok_or_else(|| {
+                    Error::new(ErrorKind::InvalidInput, format!("Variable `{}` not found", name))
+                }),
+            Expr::Constant(name) => context.get_constant(name).cloned().ok_or_else(|| {
+                Error::new(ErrorKind::InvalidInput, format!("Constant `{}` not found", name))
+            }),
+            Expr::UnaryOp(name, operand) => {
+                let func = context.get_unary_function(name).ok_or_else(|| {
+                    Error::new(ErrorKind::InvalidInput, format!("Unary operator `{}` not found", name))
+                })?;
+
+                func.call(operand.eval(context)?)
+            }
+            Expr::BinaryOp(name, left, right) => {
+                let func = context.get_binary_function(name).ok_or_else(|| {
+                    Error::new(ErrorKind::InvalidInput, format!("Binary operator `{}` not found", name))
+                })?;
+
+                func.call(left.eval(context)?, right.eval(context)?)
+            }
+            Expr::FunctionCall(name, args) => {
+                let func = context.get_function_with_arity(name, args.len()).ok_or_else(|| {
+                    Error::new(ErrorKind::InvalidInput, format!("Function `{}` not found", name))
+                })?;
+
+                check_function_call(name, args.len(), context.config())?;
+
+                if func.wants_lazy_args() {
+                    let evals = args
+                        .iter()
+                        .map(|arg| move |_: &[Token<N>]| arg.eval(context))
+                        .collect::<Vec<_>>();
+
+                    let lazy_args = evals
+                        .iter()
+                        .map(|eval| LazyArg::new(&[], eval))
+                        .collect::<Vec<_>>();
+
+                    func.call_lazy(&lazy_args)
+                } else {
+                    let args = args
+                        .iter()
+                        .map(|arg| arg.eval(context))
+                        .collect::<Result<Vec<_>>>()?;
+
+                    func.call(&args)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::DefaultContext;
+    use crate::evaluator::Evaluator;
+
+    #[test]
+    fn parse_test() {
+        let evaluator: Evaluator<i64> = Evaluator::new();
+        let expr = evaluator.parse("2 + 3 * -max(1, 4)").unwrap();
+
+        let number = |n: i64| Box::new(Expr::Number(n));
+        assert_eq!(
+            expr,
+            Expr::BinaryOp(
+                "+".to_string(),
+                number(2),
+                Box::new(Expr::BinaryOp(
+                    "*".to_string(),
+                    number(3),
+                    Box::new(Expr::UnaryOp(
+                        "-".to_string(),
+                        Box::new(Expr::FunctionCall(
+                            "max".to_string(),
+                            vec![Expr::Number(1), Expr::Number(4)]
+                        ))
+                    ))
+                ))
+            )
+        );
+
+        assert_eq!(expr.eval(evaluator.context()), Ok(-10));
+        assert!(evaluator.parse("2 +").is_err());
+    }
+
+    #[test]
+    fn eval_many_times_test() {
+        let mut context: DefaultContext<f64> = DefaultContext::new_checked();
+        context.set_variable("x", 0_f64).unwrap();
+
+        let evaluator = Evaluator::with_context(context.clone());
+        let expr = evaluator.parse("if(x, 10 / x, PI)").unwrap();
+
+        // The division by zero is not evaluated
+        assert_eq!(expr.eval(&context), Ok(std::f64::consts::PI));
+        context.set_variable("x", 4_f64).unwrap();
+        assert_eq!(expr.eval(&context), Ok(2.5));
+
+        assert!(Expr::<f64>::Variable("y".to_string()).eval(&context).is_err());
+    }
+}
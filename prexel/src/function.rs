@@ -1,3 +1,4 @@
+use crate::token::Token;
 use crate::Result;
 
 /// A trait for a function that take a variable number of arguments.
@@ -13,11 +14,58 @@ pub trait Function<N> {
         None
     }
 
+    /// Gets the number of arguments this function takes, by default `Arity::Any`.
+    fn arity(&self) -> Arity {
+        Arity::Any
+    }
+
     /// Gets information about the usage of this function.
     #[cfg(feature="docs")]
     fn description(&self) -> Option<&str> {
         None
     }
+
+    /// Gets the category used to group this function, eg: `Trigonometry`.
+    #[cfg(feature="docs")]
+    fn category(&self) -> Option<&str> {
+        None
+    }
+
+    /// Whether if this function receives its arguments unevaluated through [`Function::call_lazy`],
+    /// eg: `if(cond, then, else)` that only evaluates one of the branches.
+    fn wants_lazy_args(&self) -> bool {
+        false
+    }
+
+    /// Calls this function with unevaluated arguments, this is only used if [`Function::wants_lazy_args`]
+    /// returns `true`, by default evaluates all the arguments and pass them to [`Function::call`].
+    fn call_lazy(&self, args: &[LazyArg<'_, N>]) -> Result<N> {
+        let values = args.iter().map(|arg| arg.eval()).collect::<Result<Vec<N>>>()?;
+        self.call(&values)
+    }
+}
+
+/// An unevaluated argument of a function, see [`Function::call_lazy`].
+pub struct LazyArg<'e, N> {
+    tokens: &'e [Token<N>],
+    eval: &'e dyn Fn(&[Token<N>]) -> Result<N>,
+}
+
+impl<'e, N> LazyArg<'e, N> {
+    /// Constructs a new `LazyArg` from the tokens of the argument and the function used to evaluate them.
+    pub fn new(tokens: &'e [Token<N>], eval: &'e dyn Fn(&[Token<N>]) -> Result<N>) -> Self {
+        LazyArg { tokens, eval }
+    }
+
+    /// Gets the tokens of this argument, in infix notation.
+    pub fn tokens(&self) -> &[Token<N>] {
+        self.tokens
+    }
+
+    /// Evaluates this argument.
+    pub fn eval(&self) -> Result<N> {
+        (self.eval)(self.tokens)
+    }
 }
 
 /// A trait for a function that takes 2 arguments.
@@ -44,6 +92,12 @@ pub trait BinaryFunction<N> {
     fn description(&self) -> Option<&str> {
         None
     }
+
+    /// Gets the category used to group this function, eg: `Trigonometry`.
+    #[cfg(feature="docs")]
+    fn category(&self) -> Option<&str> {
+        None
+    }
 }
 
 /// A trait a function that takes 1 argument.
@@ -67,6 +121,71 @@ pub trait UnaryFunction<N> {
     fn description(&self) -> Option<&str> {
         None
     }
+
+    /// Gets the category used to group this function, eg: `Trigonometry`.
+    #[cfg(feature="docs")]
+    fn category(&self) -> Option<&str> {
+        None
+    }
+}
+
+/// Represents the number of arguments a function takes.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Arity {
+    /// Takes exactly the given number of arguments, eg: `sin(x)`.
+    Exactly(usize),
+    /// Takes between the min and max number of arguments (inclusive), eg: `log(x)` or `log(x, base)`.
+    Range(usize, usize),
+    /// Takes the given number of arguments or more, eg: `max(a, b, ...)`.
+    AtLeast(usize),
+    /// Takes any number of arguments.
+    Any,
+}
+
+impl Arity {
+    /// Checks if a function with this arity can be called with the given number of arguments.
+    ///
+    /// # Example
+    /// ```
+    /// use prexel::function::Arity;
+    ///
+    /// assert!(Arity::Range(1, 2).accepts(2));
+    /// assert!(!Arity::Exactly(1).accepts(2));
+    /// assert!(Arity::AtLeast(2).accepts(5));
+    /// ```
+    pub fn accepts(&self, arg_count: usize) -> bool {
+        match *self {
+            Arity::Exactly(n) => arg_count == n,
+            Arity::Range(min, max) => arg_count >= min && arg_count <= max,
+            Arity::AtLeast(n) => arg_count >= n,
+            Arity::Any => true,
+        }
+    }
+
+    /// Checks if exists a number of arguments accepted by both arities.
+    ///
+    /// # Example
+    /// ```
+    /// use prexel::function::Arity;
+    ///
+    /// assert!(Arity::Range(1, 3).overlaps(&Arity::Exactly(2)));
+    /// assert!(!Arity::Exactly(1).overlaps(&Arity::AtLeast(2)));
+    /// ```
+    pub fn overlaps(&self, other: &Arity) -> bool {
+        // The min and max (if any) number of arguments.
+        fn bounds(arity: &Arity) -> (usize, Option<usize>) {
+            match *arity {
+                Arity::Exactly(n) => (n, Some(n)),
+                Arity::Range(min, max) => (min, Some(max)),
+                Arity::AtLeast(n) => (n, None),
+                Arity::Any => (0, None),
+            }
+        }
+
+        let (min, max) = bounds(self);
+        let (other_min, other_max) = bounds(other);
+        max.is_none_or(|max| other_min <= max) && other_max.is_none_or(|other_max| min <= other_max)
+    }
 }
 
 /// Represents the associativity of an operator.
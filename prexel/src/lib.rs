@@ -7,6 +7,8 @@ pub mod error;
 /// Evaluator for the math expressions.
 pub mod evaluator;
 
+pub mod expr;
+
 /// Traits for functions.
 pub mod function;
 
@@ -31,6 +33,7 @@ mod numeric_traits;
 /// Reexports of the `num_traits` crate.
 pub mod num_traits {
     pub use num_traits::*;
+    #[allow(unused_imports)]
     pub use crate::numeric_traits::*;
 }
 
@@ -22,7 +22,7 @@ macro_rules! impl_unchecked_num_traits_with_field {
             fn sub(self, other: Self) -> Self {
                 let x = self.$field;
                 let y = other.$field;
-                Self::from(x + y)
+                Self::from(x - y)
             }
         }
 
@@ -163,6 +163,7 @@ macro_rules! impl_unchecked_num_traits_with_field {
 
         #[cfg(debug_assertions)]
         const _ : () = {
+          #[allow(dead_code)]
           fn assert_implements_from_type_of_type(x: $type) {
               struct AssertImplFrom<T: From<U>, U>(T, std::marker::PhantomData<U>);
 
@@ -271,4 +272,33 @@ macro_rules! impl_checked_num_traits_with_field_and_from_str {
             }
       }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    struct Number(i64);
+
+    impl From<i64> for Number {
+        fn from(n: i64) -> Self {
+            Number(n)
+        }
+    }
+
+    crate::impl_checked_num_traits_with_field!(Number => 0, i64);
+
+    #[test]
+    fn num_traits_with_field_test() {
+        use crate::num::checked::{CheckedAdd, CheckedSub};
+
+        assert_eq!(Number(5) + Number(3), Number(8));
+        assert_eq!(Number(5) - Number(3), Number(2));
+        assert_eq!(Number(5) * Number(3), Number(15));
+        assert_eq!(Number(6) / Number(3), Number(2));
+        assert_eq!(Number(5) % Number(3), Number(2));
+        assert_eq!(-Number(5), Number(-5));
+
+        assert_eq!(Number(5).checked_add(&Number(3)), Some(Number(8)));
+        assert_eq!(Number(5).checked_sub(&Number(3)), Some(Number(2)));
+        assert_eq!(Number(i64::MIN).checked_sub(&Number(1)), None);
+    }
+}
@@ -1,7 +1,7 @@
-use num_traits::{FromPrimitive, Zero};
+use num_traits::{FromPrimitive, One, ToPrimitive, Zero};
 use crate::error::*;
 use crate::function::{
-    Associativity, BinaryFunction, Function, Notation, Precedence, UnaryFunction,
+    Arity, Associativity, BinaryFunction, Function, Notation, Precedence, UnaryFunction,
 };
 use crate::num::checked::{CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub};
 use crate::Result;
@@ -32,6 +32,11 @@ impl<N: CheckedAdd> BinaryFunction<N> for AddOperator {
     fn description(&self) -> Option<&str> {
         Some(Description::Add.into())
     }
+
+    #[cfg(feature="docs")]
+    fn category(&self) -> Option<&str> {
+        Some(Description::Add.category().into())
+    }
 }
 
 pub struct SubOperator;
@@ -57,6 +62,11 @@ impl<N: CheckedSub> BinaryFunction<N> for SubOperator {
     fn description(&self) -> Option<&str> {
         Some(Description::Sub.into())
     }
+
+    #[cfg(feature="docs")]
+    fn category(&self) -> Option<&str> {
+        Some(Description::Sub.category().into())
+    }
 }
 
 pub struct MulOperator;
@@ -82,6 +92,11 @@ impl<N: CheckedMul> BinaryFunction<N> for MulOperator {
     fn description(&self) -> Option<&str> {
         Some(Description::Mul.into())
     }
+
+    #[cfg(feature="docs")]
+    fn category(&self) -> Option<&str> {
+        Some(Description::Mul.category().into())
+    }
 }
 
 pub struct DivOperator;
@@ -111,6 +126,11 @@ impl<N: CheckedDiv + Zero> BinaryFunction<N> for DivOperator {
     fn description(&self) -> Option<&str> {
         Some(Description::Div.into())
     }
+
+    #[cfg(feature="docs")]
+    fn category(&self) -> Option<&str> {
+        Some(Description::Div.category().into())
+    }
 }
 
 pub struct ModOperator;
@@ -140,6 +160,11 @@ impl<N: CheckedRem + Zero> BinaryFunction<N> for ModOperator {
     fn description(&self) -> Option<&str> {
         Some(Description::Mod.into())
     }
+
+    #[cfg(feature="docs")]
+    fn category(&self) -> Option<&str> {
+        Some(Description::Mod.category().into())
+    }
 }
 
 pub struct UnaryMinus;
@@ -160,6 +185,11 @@ impl<N: CheckedNeg> UnaryFunction<N> for UnaryMinus {
     fn description(&self) -> Option<&str> {
         Some(Description::Neg.into())
     }
+
+    #[cfg(feature="docs")]
+    fn category(&self) -> Option<&str> {
+        Some(Description::Neg.category().into())
+    }
 }
 
 pub struct AbsFunction;
@@ -168,6 +198,10 @@ impl<N: Zero + PartialOrd + CheckedNeg + Clone> Function<N> for AbsFunction {
         "abs"
     }
 
+    fn arity(&self) -> Arity {
+        Arity::Exactly(1)
+    }
+
     fn call(&self, args: &[N]) -> Result<N> {
         if args.len() != 1 {
             Err(Error::from(ErrorKind::InvalidArgumentCount))
@@ -184,6 +218,11 @@ impl<N: Zero + PartialOrd + CheckedNeg + Clone> Function<N> for AbsFunction {
     fn description(&self) -> Option<&str> {
         Some(Description::Abs.into())
     }
+
+    #[cfg(feature="docs")]
+    fn category(&self) -> Option<&str> {
+        Some(Description::Abs.category().into())
+    }
 }
 
 pub struct SumFunction;
@@ -192,6 +231,10 @@ impl<N: CheckedAdd + Clone> Function<N> for SumFunction {
         "sum"
     }
 
+    fn arity(&self) -> Arity {
+        Arity::AtLeast(1)
+    }
+
     fn call(&self, args: &[N]) -> Result<N> {
         let mut result = None;
 
@@ -212,6 +255,11 @@ impl<N: CheckedAdd + Clone> Function<N> for SumFunction {
     fn description(&self) -> Option<&str> {
         Some(Description::Sum.into())
     }
+
+    #[cfg(feature="docs")]
+    fn category(&self) -> Option<&str> {
+        Some(Description::Sum.category().into())
+    }
 }
 
 pub struct ProdFunction;
@@ -220,6 +268,10 @@ impl<N: CheckedMul + Clone> Function<N> for ProdFunction {
         "product"
     }
 
+    fn arity(&self) -> Arity {
+        Arity::AtLeast(1)
+    }
+
     fn call(&self, args: &[N]) -> Result<N> {
         let mut result = None;
 
@@ -240,6 +292,11 @@ impl<N: CheckedMul + Clone> Function<N> for ProdFunction {
     fn description(&self) -> Option<&str> {
         Some(Description::Prod.into())
     }
+
+    #[cfg(feature="docs")]
+    fn category(&self) -> Option<&str> {
+        Some(Description::Prod.category().into())
+    }
 }
 
 pub struct AvgFunction;
@@ -248,6 +305,10 @@ impl<N: CheckedAdd + CheckedDiv + FromPrimitive + Clone> Function<N> for AvgFunc
         "avg"
     }
 
+    fn arity(&self) -> Arity {
+        Arity::AtLeast(1)
+    }
+
     fn call(&self, args: &[N]) -> Result<N> {
         let mut sum = None;
 
@@ -277,6 +338,111 @@ impl<N: CheckedAdd + CheckedDiv + FromPrimitive + Clone> Function<N> for AvgFunc
     fn description(&self) -> Option<&str> {
         Some(Description::Avg.into())
     }
+
+    #[cfg(feature="docs")]
+    fn category(&self) -> Option<&str> {
+        Some(Description::Avg.category().into())
+    }
+}
+
+/// Gets the number of bits of the integers that `N` represents exactly, `None` if it
+/// represents exactly all the integers in its range, eg: `53` for `f64`.
+fn exact_integer_bits<N: FromPrimitive + ToPrimitive>() -> Option<u32> {
+    for bits in 1..127 {
+        let value = (1_u128 << bits) + 1;
+        match N::from_u128(value) {
+            Some(n) if n.to_u128() != Some(value) => return Some(bits),
+            Some(_) => {}
+            None => return None,
+        }
+    }
+
+    None
+}
+
+pub struct PowModFunction;
+impl<N> Function<N> for PowModFunction
+where
+    N: CheckedMul + CheckedRem + CheckedAdd + Zero + One + PartialOrd + ToPrimitive + FromPrimitive + Clone,
+{
+    fn name(&self) -> &str {
+        "powmod"
+    }
+
+    fn arity(&self) -> Arity {
+        Arity::Exactly(3)
+    }
+
+    fn call(&self, args: &[N]) -> Result<N> {
+        if args.len() != 3 {
+            return Err(Error::from(ErrorKind::InvalidArgumentCount));
+        }
+
+        let is_integer = |n: &N| n.checked_rem(&N::one()).is_some_and(|r| r.is_zero());
+        if !args.iter().all(is_integer) {
+            return Err(Error::new(ErrorKind::InvalidInput, "powmod expects integer values"));
+        }
+
+        let (base, exponent, modulus) = (&args[0], &args[1], &args[2]);
+        if *modulus <= N::zero() {
+            return Err(Error::new(ErrorKind::InvalidInput, "powmod expects a positive modulus"));
+        }
+
+        if *exponent < N::zero() {
+            return Err(Error::from(ErrorKind::NegativeValue));
+        }
+
+        let overflow = || Error::from(ErrorKind::Overflow);
+
+        // The square of the values reduced by the modulus must be exact, eg: for `f64`
+        // the products over `2^53` are rounded, so only moduli up to `2^26` are allowed
+        if let Some(bits) = exact_integer_bits::<N>() {
+            let max = modulus.to_u128().ok_or_else(overflow)? - 1;
+            if max.checked_mul(max).is_none_or(|square| square >= 1 << bits) {
+                return Err(Error::new(
+                    ErrorKind::Overflow,
+                    "The modulus of powmod is too big to be computed exactly",
+                ));
+            }
+        }
+
+        let reduce = |n: N| -> Result<N> {
+            let r = n.checked_rem(modulus).ok_or_else(overflow)?;
+            if r < N::zero() {
+                r.checked_add(modulus).ok_or_else(overflow)
+            } else {
+                Ok(r)
+            }
+        };
+
+        // Exponentiation by squaring, reducing at each step to avoid overflow
+        let mut exponent = exponent.to_u128().ok_or_else(overflow)?;
+        let mut base = reduce(base.clone())?;
+        let mut result = reduce(N::one())?;
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = reduce(result.checked_mul(&base).ok_or_else(overflow)?)?;
+            }
+
+            exponent >>= 1;
+            if exponent > 0 {
+                base = reduce(base.checked_mul(&base).ok_or_else(overflow)?)?;
+            }
+        }
+
+        Ok(result)
+    }
+
+    #[cfg(feature="docs")]
+    fn description(&self) -> Option<&str> {
+        Some(Description::PowMod.into())
+    }
+
+    #[cfg(feature="docs")]
+    fn category(&self) -> Option<&str> {
+        Some(Description::PowMod.category().into())
+    }
 }
 
 #[cfg(test)]
@@ -386,4 +552,28 @@ mod tests{
         assert!(instance.call(empty_array::<i64>().as_ref()).is_err());
         assert!(instance.call(&[i32::MAX, 10, 20, 30]).is_err());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn pow_mod_test(){
+        let instance = PowModFunction;
+
+        assert_eq!(instance.call(&[3, 4, 5]), Ok(1));
+        assert_eq!(instance.call(&[2, 10, 1000]), Ok(24));
+        assert_eq!(instance.call(&[-2, 3, 5]), Ok(2));
+        assert_eq!(instance.call(&[7, 0, 1]), Ok(0));
+
+        // 2^100 and 123456789^1000 overflow `i64`
+        assert_eq!(instance.call(&[2_i64, 100, 1_000_000_007]), Ok(976_371_285));
+        assert_eq!(instance.call(&[123_456_789_i64, 1000, 1_000_000_007]), Ok(620_139_939));
+        assert_eq!(instance.call(&[3_f64, 200_f64, 13_f64]), Ok(9_f64));
+        assert_eq!(instance.call(&[2_f64, 100_f64, 67_108_859_f64]), Ok(54_525_987_f64));
+
+        // The squares of big moduli are rounded in `f64`
+        assert_eq!(instance.call(&[2_f64, 100_f64, 1_000_000_007_f64]).unwrap_err().kind(), ErrorKind::Overflow);
+        assert_eq!(instance.call(&[123_456_789_f64, 1000_f64, 1_000_000_007_f64]).unwrap_err().kind(), ErrorKind::Overflow);
+
+        assert!(instance.call(&[2, -1, 5]).is_err());
+        assert!(instance.call(&[2, 3, 0]).is_err());
+        assert!(instance.call(&[2.5_f64, 3_f64, 5_f64]).is_err());
+    }
+}
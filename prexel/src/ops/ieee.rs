@@ -0,0 +1,269 @@
+use num_traits::{FromPrimitive, ToPrimitive, Zero};
+use crate::error::*;
+use crate::function::{Arity, Associativity, BinaryFunction, Function, Precedence};
+use crate::num::checked::{CheckedDiv, CheckedRem};
+use crate::ops::negative_base_pow_sign;
+use crate::Result;
+
+#[cfg(feature = "docs")]
+use crate::descriptions::Description;
+
+/// Computes `left op 0` in `f64`, the result is only returned if `N` can represent it.
+#[inline]
+fn call_with_zero<N: ToPrimitive + FromPrimitive>(left: &N, op: fn(f64, f64) -> f64) -> Result<N> {
+    left.to_f64()
+        .and_then(|n| N::from_f64(op(n, 0_f64)))
+        .ok_or_else(|| Error::from(ErrorKind::DivisionByZero))
+}
+
+/// Converts the value to `f64`, the non-finite values are accepted.
+#[inline]
+fn to_f64<N: ToPrimitive>(n: &N) -> Result<f64> {
+    n.to_f64().ok_or_else(|| Error::from(ErrorKind::Overflow))
+}
+
+/// Converts the result to `N`, the non-finite results are only returned if `N` can represent them.
+#[inline]
+fn from_f64<N: FromPrimitive>(result: f64) -> Result<N> {
+    N::from_f64(result).ok_or_else(|| Error::from(ErrorKind::Overflow))
+}
+
+macro_rules! ieee_func_impl {
+    ($func_name:ident, $name:expr, $op:expr, $description:expr) => {
+        pub struct $func_name;
+        impl<N: ToPrimitive + FromPrimitive> Function<N> for $func_name {
+            fn name(&self) -> &str {
+                $name
+            }
+
+            fn arity(&self) -> Arity {
+                Arity::Exactly(1)
+            }
+
+            fn call(&self, args: &[N]) -> Result<N> {
+                match args {
+                    [value] => {
+                        let op: fn(f64) -> f64 = $op;
+                        from_f64(op(to_f64(value)?))
+                    }
+                    _ => Err(Error::from(ErrorKind::InvalidArgumentCount)),
+                }
+            }
+
+            #[cfg(feature = "docs")]
+            fn description(&self) -> Option<&str> {
+                Some($description.into())
+            }
+
+            #[cfg(feature = "docs")]
+            fn category(&self) -> Option<&str> {
+                Some($description.category().into())
+            }
+        }
+    };
+}
+
+ieee_func_impl!(SqrtFunction, "sqrt", f64::sqrt, Description::Sqrt);
+ieee_func_impl!(ExpFunction, "exp", f64::exp, Description::Exp);
+ieee_func_impl!(LnFunction, "ln", f64::ln, Description::Ln);
+ieee_func_impl!(Log2Function, "log2", f64::log2, Description::Log2);
+ieee_func_impl!(Log10Function, "log10", f64::log10, Description::Log10);
+
+/// Logarithm in base 10 or the given base: `log(x)` or `log(x, base)`.
+pub struct LogFunction;
+impl<N: ToPrimitive + FromPrimitive> Function<N> for LogFunction {
+    fn name(&self) -> &str {
+        "log"
+    }
+
+    fn arity(&self) -> Arity {
+        Arity::Range(1, 2)
+    }
+
+    fn call(&self, args: &[N]) -> Result<N> {
+        match args {
+            [value] => from_f64(to_f64(value)?.log10()),
+            [value, base] => from_f64(to_f64(value)?.log(to_f64(base)?)),
+            _ => Err(Error::from(ErrorKind::InvalidArgumentCount)),
+        }
+    }
+
+    #[cfg(feature = "docs")]
+    fn description(&self) -> Option<&str> {
+        Some(Description::Log.into())
+    }
+
+    #[cfg(feature = "docs")]
+    fn category(&self) -> Option<&str> {
+        Some(Description::Log.category().into())
+    }
+}
+
+pub struct PowOperator;
+impl<N: ToPrimitive + FromPrimitive> BinaryFunction<N> for PowOperator {
+    fn name(&self) -> &str {
+        "^"
+    }
+
+    fn precedence(&self) -> Precedence {
+        Precedence::HIGH
+    }
+
+    fn associativity(&self) -> Associativity {
+        Associativity::Right
+    }
+
+    fn call(&self, left: N, right: N) -> Result<N> {
+        let base = to_f64(&left)?;
+        let exp = to_f64(&right)?;
+
+        // The real root of a negative base is used if exists, eg: (-8)^(1/3) = -2, otherwise is NaN
+        let result = if base < 0_f64 && base.is_finite() && exp.is_finite() && exp.fract() != 0_f64 {
+            match negative_base_pow_sign(exp) {
+                Ok(sign) => sign * f64::powf(-base, exp),
+                Err(_) => f64::NAN,
+            }
+        } else {
+            f64::powf(base, exp)
+        };
+
+        from_f64(result)
+    }
+
+    #[cfg(feature="docs")]
+    fn description(&self) -> Option<&str> {
+        Some(Description::Pow.into())
+    }
+
+    #[cfg(feature="docs")]
+    fn category(&self) -> Option<&str> {
+        Some(Description::Pow.category().into())
+    }
+}
+
+pub struct DivOperator;
+impl<N: CheckedDiv + Zero + ToPrimitive + FromPrimitive> BinaryFunction<N> for DivOperator {
+    fn name(&self) -> &str {
+        "/"
+    }
+
+    fn precedence(&self) -> Precedence {
+        Precedence::MEDIUM
+    }
+
+    fn associativity(&self) -> Associativity {
+        Associativity::Left
+    }
+
+    fn call(&self, left: N, right: N) -> Result<N> {
+        if right.is_zero() {
+            return call_with_zero(&left, |a, b| a / b);
+        }
+
+        left.checked_div(&right)
+            .ok_or_else(|| Error::from(ErrorKind::Overflow))
+    }
+
+    #[cfg(feature="docs")]
+    fn description(&self) -> Option<&str> {
+        Some(Description::Div.into())
+    }
+
+    #[cfg(feature="docs")]
+    fn category(&self) -> Option<&str> {
+        Some(Description::Div.category().into())
+    }
+}
+
+pub struct ModOperator;
+impl<N: CheckedRem + Zero + ToPrimitive + FromPrimitive> BinaryFunction<N> for ModOperator {
+    fn name(&self) -> &str {
+        "mod"
+    }
+
+    fn precedence(&self) -> Precedence {
+        Precedence::MEDIUM
+    }
+
+    fn associativity(&self) -> Associativity {
+        Associativity::Left
+    }
+
+    fn call(&self, left: N, right: N) -> Result<N> {
+        if right.is_zero() {
+            return call_with_zero(&left, |a, b| a % b);
+        }
+
+        left.checked_rem(&right)
+            .ok_or_else(|| Error::from(ErrorKind::Overflow))
+    }
+
+    #[cfg(feature="docs")]
+    fn description(&self) -> Option<&str> {
+        Some(Description::Mod.into())
+    }
+
+    #[cfg(feature="docs")]
+    fn category(&self) -> Option<&str> {
+        Some(Description::Mod.category().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn div_test() {
+        let instance = DivOperator;
+
+        assert_eq!(instance.call(10_f64, 4_f64), Ok(2.5_f64));
+        assert_eq!(instance.call(1_f64, 0_f64), Ok(f64::INFINITY));
+        assert_eq!(instance.call(-1_f64, 0_f64), Ok(f64::NEG_INFINITY));
+        assert!(instance.call(0_f64, 0_f64).unwrap().is_nan());
+
+        // Integers cannot represent the result
+        assert_eq!(instance.call(20, 4), Ok(5));
+        assert_eq!(instance.call(5, 0).unwrap_err().kind(), ErrorKind::DivisionByZero);
+    }
+
+    #[test]
+    fn mod_test() {
+        let instance = ModOperator;
+
+        assert_eq!(instance.call(10_f64, 4_f64), Ok(2_f64));
+        assert!(instance.call(1_f64, 0_f64).unwrap().is_nan());
+        assert_eq!(instance.call(5, 0).unwrap_err().kind(), ErrorKind::DivisionByZero);
+    }
+
+    #[test]
+    fn pow_test() {
+        let instance = PowOperator;
+
+        assert_eq!(instance.call(2_f64, 3_f64), Ok(8_f64));
+        assert_eq!(instance.call(-8_f64, 1_f64 / 3_f64), Ok(-2_f64));
+        assert_eq!(instance.call(10_f64, 400_f64), Ok(f64::INFINITY));
+        assert_eq!(instance.call(0_f64, -1_f64), Ok(f64::INFINITY));
+        assert_eq!(instance.call(f64::INFINITY, 0.5_f64), Ok(f64::INFINITY));
+        assert!(instance.call(-4_f64, 0.5_f64).unwrap().is_nan());
+
+        assert_eq!(instance.call(2, 10), Ok(1024));
+        assert_eq!(instance.call(0, -1).unwrap_err().kind(), ErrorKind::Overflow);
+    }
+
+    #[test]
+    fn unary_functions_test() {
+        assert_eq!(ExpFunction.call(&[f64::INFINITY]), Ok(f64::INFINITY));
+        assert_eq!(ExpFunction.call(&[f64::NEG_INFINITY]), Ok(0_f64));
+        assert_eq!(LnFunction.call(&[0_f64]), Ok(f64::NEG_INFINITY));
+        assert!(LnFunction.call(&[-1_f64]).unwrap().is_nan());
+        assert_eq!(LogFunction.call(&[0_f64]), Ok(f64::NEG_INFINITY));
+        assert_eq!(LogFunction.call(&[100_f64, 10_f64]), Ok(2_f64));
+        assert_eq!(Log2Function.call(&[f64::INFINITY]), Ok(f64::INFINITY));
+        assert_eq!(Log10Function.call(&[1000_f64]), Ok(3_f64));
+        assert!(SqrtFunction.call(&[-1_f64]).unwrap().is_nan());
+
+        assert_eq!(LnFunction.call(&[0_i64]).unwrap_err().kind(), ErrorKind::Overflow);
+        assert_eq!(SqrtFunction.call(&[1_f64, 2_f64]).unwrap_err().kind(), ErrorKind::InvalidArgumentCount);
+    }
+}
@@ -6,6 +6,9 @@ pub mod checked;
 /// Unchecked math operations, (may panic).
 pub mod unchecked;
 
+/// Math operations that follow IEEE 754, (returns `inf` or `NaN` instead of an error).
+pub mod ieee;
+
 #[cfg(not(feature = "docs"))]
 macro_rules! forward_func_impl {
         ($func_name:ident, $method_name:ident) => {
@@ -18,6 +21,10 @@ macro_rules! forward_func_impl {
                     stringify!($name)
                 }
 
+                fn arity(&self) -> Arity {
+                    Arity::Exactly(1)
+                }
+
                 fn call(&self, args: &[N]) -> Result<N> {
                     if args.len() != 1 {
                         Err(Error::from(ErrorKind::InvalidArgumentCount))
@@ -46,6 +53,10 @@ macro_rules! forward_func_inv_impl {
                     stringify!($name)
                 }
 
+                fn arity(&self) -> Arity {
+                    Arity::Exactly(1)
+                }
+
                 fn call(&self, args: &[N]) -> Result<N> {
                     if args.len() != 1 {
                         Err(Error::from(ErrorKind::InvalidArgumentCount))
@@ -75,6 +86,10 @@ macro_rules! forward_func_impl {
                     stringify!($name)
                 }
 
+                fn arity(&self) -> Arity {
+                    Arity::Exactly(1)
+                }
+
                 fn call(&self, args: &[N]) -> Result<N> {
                     if args.len() != 1 {
                         Err(Error::from(ErrorKind::InvalidArgumentCount))
@@ -91,6 +106,10 @@ macro_rules! forward_func_impl {
                 fn description(&self) -> Option<&str> {
                     Some($description.into())
                 }
+
+                fn category(&self) -> Option<&str> {
+                    Some($description.category().into())
+                }
             }
         };
     }
@@ -107,6 +126,10 @@ macro_rules! forward_func_inv_impl {
                     stringify!($name)
                 }
 
+                fn arity(&self) -> Arity {
+                    Arity::Exactly(1)
+                }
+
                 fn call(&self, args: &[N]) -> Result<N> {
                     if args.len() != 1 {
                         Err(Error::from(ErrorKind::InvalidArgumentCount))
@@ -124,6 +147,10 @@ macro_rules! forward_func_inv_impl {
                 fn description(&self) -> Option<&str> {
                     Some($description.into())
                 }
+
+                fn category(&self) -> Option<&str> {
+                    Some($description.category().into())
+                }
             }
         };
     }
@@ -140,6 +167,10 @@ macro_rules! impl_arc_trig {
                     stringify!($name)
                 }
 
+                fn arity(&self) -> Arity {
+                    Arity::Exactly(1)
+                }
+
                 fn call(&self, args: &[N]) -> Result<N> {
                     if args.len() != 1 {
                         Err(Error::from(ErrorKind::InvalidArgumentCount))
@@ -175,6 +206,10 @@ macro_rules! impl_arc_trig_rec {
                     stringify!($name)
                 }
 
+                fn arity(&self) -> Arity {
+                    Arity::Exactly(1)
+                }
+
                 fn call(&self, args: &[N]) -> Result<N> {
                     if args.len() != 1 {
                         Err(Error::from(ErrorKind::InvalidArgumentCount))
@@ -211,6 +246,10 @@ macro_rules! impl_arc_trig {
                     stringify!($name)
                 }
 
+                fn arity(&self) -> Arity {
+                    Arity::Exactly(1)
+                }
+
                 fn call(&self, args: &[N]) -> Result<N> {
                     if args.len() != 1 {
                         Err(Error::from(ErrorKind::InvalidArgumentCount))
@@ -234,6 +273,10 @@ macro_rules! impl_arc_trig {
                 fn description(&self) -> Option<&str> {
                     Some($description.into())
                 }
+
+                fn category(&self) -> Option<&str> {
+                    Some($description.category().into())
+                }
             }
         };
     }
@@ -250,6 +293,10 @@ macro_rules! impl_arc_trig_rec {
                     stringify!($name)
                 }
 
+                fn arity(&self) -> Arity {
+                    Arity::Exactly(1)
+                }
+
                 fn call(&self, args: &[N]) -> Result<N> {
                     if args.len() != 1 {
                         Err(Error::from(ErrorKind::InvalidArgumentCount))
@@ -274,6 +321,10 @@ macro_rules! impl_arc_trig_rec {
                 fn description(&self) -> Option<&str> {
                     Some($description.into())
                 }
+
+                fn category(&self) -> Option<&str> {
+                    Some($description.category().into())
+                }
             }
         };
     }
@@ -292,6 +343,39 @@ pub(crate) fn try_to_float<N: num_traits::ToPrimitive>(n: &N) -> crate::Result<f
     }
 }
 
+/// Gets the sign of `base ^ exponent` for a negative base and a fractional exponent.
+///
+/// # Remarks
+/// The exponent is taken as a fraction `p/q`, the result only exists in the real numbers
+/// if `q` is odd eg: `(-8)^(1/3) = -2`, so an error is returned for even roots eg: `(-4)^(1/2)`.
+pub(crate) fn negative_base_pow_sign(exponent: f64) -> crate::Result<f64> {
+    const MAX_DENOMINATOR: u32 = 1000;
+    const TOLERANCE: f64 = 1e-10;
+
+    for q in 1..=MAX_DENOMINATOR {
+        let n = exponent * q as f64;
+        let p = n.round();
+
+        if (n - p).abs() < TOLERANCE {
+            return if q % 2 == 0 {
+                Err(Error::new(
+                    ErrorKind::NegativeValue,
+                    format!("Even root of a negative number, exponent: {}", exponent),
+                ))
+            } else if p % 2_f64 == 0_f64 {
+                Ok(1_f64)
+            } else {
+                Ok(-1_f64)
+            };
+        }
+    }
+
+    Err(Error::new(
+        ErrorKind::NAN,
+        format!("Cannot raise a negative number to the irrational exponent: {}", exponent),
+    ))
+}
+
 /// For reduce errors as: `0.1 + 0.2 ≠ 0.3`.
 impl crate::utils::approx::Approx for f64{
     #[inline]
@@ -320,13 +404,13 @@ pub mod math {
 
 mod math_ops {
     use std::fmt::Debug;
-    use std::ops::{Mul, Sub};
+    use std::ops::{Add, Mul, Rem, Sub};
     use num_traits::{FromPrimitive, One, ToPrimitive, Zero};
     use rand::random;
     use crate::error::*;
     pub use crate::function::{BinaryFunction, Function, UnaryFunction};
-    use crate::function::{Associativity, Notation, Precedence};
-    use crate::ops::try_to_float;
+    use crate::function::{Arity, Associativity, LazyArg, Notation, Precedence};
+    use crate::ops::{negative_base_pow_sign, try_to_float};
     use crate::utils::gamma::gamma;
     use crate::Result;
     use crate::utils::approx::Approx;
@@ -352,6 +436,11 @@ mod math_ops {
         fn description(&self) -> Option<&str> {
             Some(Description::Plus.into())
         }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Plus.category().into())
+        }
     }
 
     pub struct Factorial;
@@ -395,6 +484,12 @@ mod math_ops {
                 };
             }
 
+            if let Some(n) = value.to_f64() {
+                if factorial_overflows::<N>(n) {
+                    return Err(Error::from(ErrorKind::Overflow));
+                }
+            }
+
             let mut total = value;
             let mut next = total.clone() - N::one();
 
@@ -420,6 +515,144 @@ mod math_ops {
         fn description(&self) -> Option<&str> {
             Some(Description::Factorial.into())
         }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Factorial.category().into())
+        }
+    }
+
+    /// Checks if `n!` can't be represented by `N` using the Stirling approximation,
+    /// so the factorial loop is never entered for huge values.
+    fn factorial_overflows<N: FromPrimitive>(n: f64) -> bool {
+        let ln_factorial = n * n.ln() - n + 0.5 * (2.0 * std::f64::consts::PI * n).ln() + 1.0 / (12.0 * n);
+        let estimate = ln_factorial.exp();
+
+        !estimate.is_finite() || N::from_f64(estimate).is_none()
+    }
+
+    /// Computes the combinations `nCr` or permutations `nPr` of the arguments `[n, r]`,
+    /// non-integer values use the gamma function as the factorial does.
+    fn combinatorics<N: ToPrimitive + FromPrimitive>(args: &[N], combination: bool) -> Result<N> {
+        let (n, r) = match args {
+            [n, r] => (try_to_float(n)?, try_to_float(r)?),
+            _ => return Err(Error::from(ErrorKind::InvalidArgumentCount)),
+        };
+
+        if n < 0_f64 || r < 0_f64 {
+            return Err(Error::from(ErrorKind::NegativeValue));
+        }
+
+        let result = if n.fract() == 0_f64 && r.fract() == 0_f64 {
+            if r > n {
+                return N::from_u8(0).ok_or_else(|| Error::from(ErrorKind::Overflow));
+            }
+
+            let exact = match (args[0].to_u128(), args[1].to_u128()) {
+                (Some(n), Some(r)) => integer_combinatorics(n, r, combination),
+                _ => None,
+            };
+
+            if let Some(result) = exact {
+                return N::from_u128(result).ok_or_else(|| Error::from(ErrorKind::Overflow));
+            }
+
+            // Too big for `u128`, eg: `200 nCr 100` is about `9e58`
+            let r = if combination { r.min(n - r) } else { r };
+            let mut result = 1_f64;
+            let mut k = 0_f64;
+
+            while k < r && result.is_finite() {
+                result *= n - k;
+                if combination {
+                    result /= k + 1_f64;
+                }
+                k += 1_f64;
+            }
+
+            result
+        } else {
+            let denominator = if combination {
+                gamma(r + 1_f64) * gamma(n - r + 1_f64)
+            } else {
+                gamma(n - r + 1_f64)
+            };
+
+            (gamma(n + 1_f64) / denominator).approx()
+        };
+
+        if !result.is_finite() {
+            return Err(Error::from(ErrorKind::Overflow));
+        }
+
+        N::from_f64(result).ok_or_else(|| Error::from(ErrorKind::Overflow))
+    }
+
+    /// Computes `n! / (n - r)!` or `n! / (r! * (n - r)!)` if is a `combination`,
+    /// returns `None` on overflow.
+    fn integer_combinatorics(n: u128, r: u128, combination: bool) -> Option<u128> {
+        let r = if combination { r.min(n - r) } else { r };
+        let mut result = 1_u128;
+
+        for k in 0..r {
+            // Each step is exact, the product of `k + 1` consecutive integers is divisible by `(k + 1)!`
+            result = result.checked_mul(n - k)?;
+            if combination {
+                result /= k + 1;
+            }
+        }
+
+        Some(result)
+    }
+
+    pub struct CombinationFunction;
+    impl<N: ToPrimitive + FromPrimitive> Function<N> for CombinationFunction {
+        fn name(&self) -> &str {
+            "nCr"
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::Exactly(2)
+        }
+
+        fn call(&self, args: &[N]) -> Result<N> {
+            combinatorics(args, true)
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::Combination.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Combination.category().into())
+        }
+    }
+
+    pub struct PermutationFunction;
+    impl<N: ToPrimitive + FromPrimitive> Function<N> for PermutationFunction {
+        fn name(&self) -> &str {
+            "nPr"
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::Exactly(2)
+        }
+
+        fn call(&self, args: &[N]) -> Result<N> {
+            combinatorics(args, false)
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::Permutation.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Permutation.category().into())
+        }
     }
 
     pub struct PowOperator;
@@ -438,81 +671,924 @@ mod math_ops {
 
         fn call(&self, left: N, right: N) -> Result<N> {
             if let (Some(base), Some(exp)) = (left.to_f64(), right.to_f64()) {
-                N::from_f64(f64::powf(base, exp)).ok_or_else(|| Error::from(ErrorKind::Overflow))
+                // `powf` returns NaN for negative bases with fractional exponents,
+                // so the real root is computed from the absolute value, eg: (-8)^(1/3)
+                let result = if base < 0_f64 && exp.fract() != 0_f64 {
+                    negative_base_pow_sign(exp)? * f64::powf(-base, exp)
+                } else {
+                    f64::powf(base, exp)
+                };
+
+                N::from_f64(result).ok_or_else(|| Error::from(ErrorKind::Overflow))
             } else {
                 Err(Error::from(ErrorKind::Overflow))
             }
         }
 
-        #[cfg(feature = "docs")]
-        fn description(&self) -> Option<&str> {
-            Some(Description::Pow.into())
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::Pow.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Pow.category().into())
+        }
+    }
+
+    pub struct MaxFunction;
+    impl<N: PartialOrd + Clone> Function<N> for MaxFunction {
+        fn name(&self) -> &str {
+            "max"
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::AtLeast(2)
+        }
+
+        fn call(&self, args: &[N]) -> Result<N> {
+            if args.len() <= 1{
+                return Err(Error::from(ErrorKind::InvalidArgumentCount));
+            }
+
+            // The winning argument is returned as is, so values with the same value
+            // but different representation (eg: decimals `1.50` and `1.5`) keep the first one.
+            let mut max = &args[0];
+
+            for n in &args[1..] {
+                if n > max {
+                    max = n;
+                }
+            }
+
+            Ok(max.clone())
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::Max.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Max.category().into())
+        }
+    }
+
+    pub struct MinFunction;
+    impl<N: PartialOrd + Clone> Function<N> for MinFunction {
+        fn name(&self) -> &str {
+            "min"
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::AtLeast(2)
+        }
+
+        fn call(&self, args: &[N]) -> Result<N> {
+            if args.len() <= 1{
+                return Err(Error::from(ErrorKind::InvalidArgumentCount));
+            }
+
+            // The winning argument is returned as is, so values with the same value
+            // but different representation (eg: decimals `1.50` and `1.5`) keep the first one.
+            let mut min = &args[0];
+
+            for n in &args[1..] {
+                if n < min {
+                    min = n;
+                }
+            }
+
+            Ok(min.clone())
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::Min.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Min.category().into())
+        }
+    }
+
+    /// Gets the finite value of the arguments that `is_better` than the others,
+    /// the values as `NaN` or `Infinity` are skipped.
+    fn finite_extreme<N, F>(args: &[N], is_better: F) -> Result<N>
+    where
+        N: ToPrimitive + Clone,
+        F: Fn(&N, &N) -> bool,
+    {
+        if args.is_empty() {
+            return Err(Error::from(ErrorKind::InvalidArgumentCount));
+        }
+
+        args.iter()
+            .filter(|n| n.to_f64().is_some_and(f64::is_finite))
+            .fold(None, |result: Option<&N>, n| match result {
+                Some(cur) if !is_better(n, cur) => Some(cur),
+                _ => Some(n),
+            })
+            .cloned()
+            .ok_or_else(|| Error::new(ErrorKind::NAN, "All the values are NaN or infinite"))
+    }
+
+    pub struct MaxIgnoreNanFunction;
+    impl<N: PartialOrd + ToPrimitive + Clone> Function<N> for MaxIgnoreNanFunction {
+        fn name(&self) -> &str {
+            "maxignorenan"
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::AtLeast(1)
+        }
+
+        fn call(&self, args: &[N]) -> Result<N> {
+            finite_extreme(args, |n, max| n > max)
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::MaxIgnoreNan.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::MaxIgnoreNan.category().into())
+        }
+    }
+
+    pub struct MinIgnoreNanFunction;
+    impl<N: PartialOrd + ToPrimitive + Clone> Function<N> for MinIgnoreNanFunction {
+        fn name(&self) -> &str {
+            "minignorenan"
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::AtLeast(1)
+        }
+
+        fn call(&self, args: &[N]) -> Result<N> {
+            finite_extreme(args, |n, min| n < min)
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::MinIgnoreNan.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::MinIgnoreNan.category().into())
+        }
+    }
+
+    /// Gets the `p` percentile of the values, where the first argument is `p` in `[0, 100]`
+    /// and the rest are the values, interpolating between the two closest ranks,
+    /// eg: `percentile(50, 1, 2, 3, 4) = 2.5`.
+    ///
+    /// # Remarks
+    /// The interpolation is computed as `f64`, so for integer types the interpolated value
+    /// is truncated, eg: `percentile(50, 1, 2) = 1`.
+    pub struct PercentileFunction;
+    impl<N> Function<N> for PercentileFunction
+    where
+        N: Clone + PartialOrd + ToPrimitive + FromPrimitive,
+    {
+        fn name(&self) -> &str {
+            "percentile"
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::AtLeast(2)
+        }
+
+        fn call(&self, args: &[N]) -> Result<N> {
+            let (percentile, values) = match args {
+                [percentile, values @ ..] if !values.is_empty() => (try_to_float(percentile)?, values),
+                _ => return Err(Error::from(ErrorKind::InvalidArgumentCount)),
+            };
+
+            if !(0_f64..=100_f64).contains(&percentile) {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The percentile must be in the range [0, 100]",
+                ));
+            }
+
+            // NaN values cannot be sorted
+            if values.iter().any(|n| n.partial_cmp(n).is_none()) {
+                return Err(Error::from(ErrorKind::NAN));
+            }
+
+            let mut sorted = values.to_vec();
+            sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+            // The rank of the percentile between `0` and `len - 1`
+            let rank = percentile * (sorted.len() - 1) as f64 / 100_f64;
+            let index = (rank.floor() as usize).min(sorted.len() - 1);
+            let fraction = rank - index as f64;
+
+            match sorted.get(index + 1) {
+                Some(upper) if fraction > 0_f64 => {
+                    let lower = try_to_float(&sorted[index])?;
+                    let upper = try_to_float(upper)?;
+                    let result = lower + (upper - lower) * fraction;
+
+                    N::from_f64(result).ok_or_else(|| Error::from(ErrorKind::Overflow))
+                }
+                _ => Ok(sorted.swap_remove(index)),
+            }
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::Percentile.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Percentile.category().into())
+        }
+    }
+
+    pub struct WrapFunction;
+    impl<N> Function<N> for WrapFunction
+    where
+        N: Zero + PartialOrd + Clone + Rem<Output = N> + Add<Output = N>,
+    {
+        fn name(&self) -> &str {
+            "wrap"
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::Exactly(2)
+        }
+
+        fn call(&self, args: &[N]) -> Result<N> {
+            match args {
+                [value, period] => {
+                    if period.is_zero() {
+                        return Err(Error::from(ErrorKind::DivisionByZero));
+                    }
+
+                    if *period < N::zero() {
+                        return Err(Error::new(ErrorKind::NegativeValue, "The period of `wrap` must be positive"));
+                    }
+
+                    let mut result = value.clone() % period.clone();
+                    if result < N::zero() {
+                        result = result + period.clone();
+                    }
+
+                    // A tiny negative float can round up to the period, eg: `wrap(-1e-20, 360)`
+                    if result >= *period {
+                        result = N::zero();
+                    }
+
+                    Ok(result)
+                }
+                _ => Err(Error::from(ErrorKind::InvalidArgumentCount)),
+            }
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::Wrap.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Wrap.category().into())
+        }
+    }
+
+    pub struct ClampFunction;
+    impl<N: PartialOrd + Clone> Function<N> for ClampFunction {
+        fn name(&self) -> &str {
+            "clamp"
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::Exactly(3)
+        }
+
+        fn call(&self, args: &[N]) -> Result<N> {
+            match args {
+                [_, min, max] if min > max => Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "The min value of `clamp` is greater than the max value",
+                )),
+                [value, min, _] if value < min => Ok(min.clone()),
+                [value, _, max] if value > max => Ok(max.clone()),
+                [value, _, _] => Ok(value.clone()),
+                _ => Err(Error::from(ErrorKind::InvalidArgumentCount)),
+            }
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::Clamp.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Clamp.category().into())
+        }
+    }
+
+    pub struct Clamp01Function;
+    impl<N: Zero + One + PartialOrd + Clone> Function<N> for Clamp01Function {
+        fn name(&self) -> &str {
+            "clamp01"
+        }
+
+        fn aliases(&self) -> Option<&[&str]> {
+            Some(&["saturate"])
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::Exactly(1)
+        }
+
+        fn call(&self, args: &[N]) -> Result<N> {
+            match args {
+                [value] if *value < N::zero() => Ok(N::zero()),
+                [value] if *value > N::one() => Ok(N::one()),
+                [value] => Ok(value.clone()),
+                _ => Err(Error::from(ErrorKind::InvalidArgumentCount)),
+            }
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::Clamp01.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Clamp01.category().into())
+        }
+    }
+
+    pub struct StepFunction;
+    impl<N: Zero + One + PartialOrd> Function<N> for StepFunction {
+        fn name(&self) -> &str {
+            "step"
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::Exactly(2)
+        }
+
+        fn call(&self, args: &[N]) -> Result<N> {
+            match args {
+                [edge, value] if value < edge => Ok(N::zero()),
+                [_, _] => Ok(N::one()),
+                _ => Err(Error::from(ErrorKind::InvalidArgumentCount)),
+            }
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::Step.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Step.category().into())
+        }
+    }
+
+    pub struct SmoothStepFunction;
+    impl<N: ToPrimitive + FromPrimitive> Function<N> for SmoothStepFunction {
+        fn name(&self) -> &str {
+            "smoothstep"
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::Exactly(3)
+        }
+
+        fn call(&self, args: &[N]) -> Result<N> {
+            match args {
+                [edge0, edge1, value] => {
+                    let edge0 = try_to_float(edge0)?;
+                    let edge1 = try_to_float(edge1)?;
+
+                    if edge0 == edge1 {
+                        return Err(Error::from(ErrorKind::DivisionByZero));
+                    }
+
+                    let t = ((try_to_float(value)? - edge0) / (edge1 - edge0)).clamp(0_f64, 1_f64);
+                    N::from_f64(t * t * (3_f64 - 2_f64 * t))
+                        .ok_or_else(|| Error::from(ErrorKind::Overflow))
+                }
+                _ => Err(Error::from(ErrorKind::InvalidArgumentCount)),
+            }
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::SmoothStep.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::SmoothStep.category().into())
+        }
+    }
+
+    /// A function that is not valid for the numeric type of a context, eg: `max` for complex
+    /// numbers, which are not ordered. Always returns an `ErrorKind::Unsupported` error.
+    pub struct UnsupportedFunction {
+        name: &'static str,
+        type_name: &'static str,
+    }
+
+    impl UnsupportedFunction {
+        /// Constructs an `UnsupportedFunction` with the given name, and the name of the numeric type
+        /// used in the error message, eg: `complex numbers`.
+        #[inline]
+        pub const fn new(name: &'static str, type_name: &'static str) -> Self {
+            UnsupportedFunction { name, type_name }
+        }
+    }
+
+    impl<N> Function<N> for UnsupportedFunction {
+        #[inline]
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn call(&self, _: &[N]) -> Result<N> {
+            Err(unsupported_error(self.name, self.type_name))
+        }
+    }
+
+    macro_rules! comparison_operator_impl {
+        ($struct_name:ident, $name:expr, [$($alias:expr),*], $op:tt, $description:expr) => {
+            impl<N: PartialOrd + Zero + One> BinaryFunction<N> for $struct_name {
+                #[inline]
+                fn name(&self) -> &str {
+                    $name
+                }
+
+                #[inline]
+                fn aliases(&self) -> Option<&[&str]> {
+                    Some(&[$($alias),*])
+                }
+
+                #[inline]
+                fn precedence(&self) -> Precedence {
+                    Precedence::VERY_LOW
+                }
+
+                #[inline]
+                fn associativity(&self) -> Associativity {
+                    Associativity::Left
+                }
+
+                #[inline]
+                fn call(&self, left: N, right: N) -> Result<N> {
+                    if left $op right {
+                        Ok(N::one())
+                    } else {
+                        Ok(N::zero())
+                    }
+                }
+
+                #[cfg(feature = "docs")]
+                fn description(&self) -> Option<&str> {
+                    Some($description.into())
+                }
+
+                #[cfg(feature = "docs")]
+                fn category(&self) -> Option<&str> {
+                    Some($description.category().into())
+                }
+            }
+        };
+    }
+
+    /// Returns `1` if the values are equal, `0` otherwise.
+    pub struct EqOperator;
+    comparison_operator_impl!(EqOperator, "==", ["eq"], ==, Description::Eq);
+
+    /// Returns `1` if the values are not equal, `0` otherwise.
+    pub struct NeOperator;
+    comparison_operator_impl!(NeOperator, "!=", ["ne"], !=, Description::Ne);
+
+    /// Returns `1` if the left value is greater than the right value, `0` otherwise.
+    pub struct GtOperator;
+    comparison_operator_impl!(GtOperator, ">", ["gt"], >, Description::Gt);
+
+    /// Returns `1` if the left value is less than the right value, `0` otherwise.
+    pub struct LtOperator;
+    comparison_operator_impl!(LtOperator, "<", ["lt"], <, Description::Lt);
+
+    /// Returns `1` if the left value is greater than or equal to the right value, `0` otherwise.
+    pub struct GteOperator;
+    comparison_operator_impl!(GteOperator, ">=", ["gte"], >=, Description::Gte);
+
+    /// Returns `1` if the left value is less than or equal to the right value, `0` otherwise.
+    pub struct LteOperator;
+    comparison_operator_impl!(LteOperator, "<=", ["lte"], <=, Description::Lte);
+
+    /// Returns `1` if all the values are not `0`, `0` otherwise.
+    ///
+    /// # Remarks
+    /// The logical operations are functions instead of operators, because the comparison
+    /// operators already have the lowest precedence.
+    pub struct AndFunction;
+    impl<N: Zero + One> Function<N> for AndFunction {
+        fn name(&self) -> &str {
+            "and"
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::AtLeast(2)
+        }
+
+        fn call(&self, args: &[N]) -> Result<N> {
+            if args.len() <= 1 {
+                return Err(Error::from(ErrorKind::InvalidArgumentCount));
+            }
+
+            if args.iter().all(|n| !n.is_zero()) {
+                Ok(N::one())
+            } else {
+                Ok(N::zero())
+            }
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::And.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::And.category().into())
+        }
+    }
+
+    /// Returns `1` if any of the values is not `0`, `0` otherwise.
+    pub struct OrFunction;
+    impl<N: Zero + One> Function<N> for OrFunction {
+        fn name(&self) -> &str {
+            "or"
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::AtLeast(2)
+        }
+
+        fn call(&self, args: &[N]) -> Result<N> {
+            if args.len() <= 1 {
+                return Err(Error::from(ErrorKind::InvalidArgumentCount));
+            }
+
+            if args.iter().any(|n| !n.is_zero()) {
+                Ok(N::one())
+            } else {
+                Ok(N::zero())
+            }
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::Or.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Or.category().into())
+        }
+    }
+
+    /// Returns `1` if the value is `0`, `0` otherwise.
+    pub struct NotFunction;
+    impl<N: Zero + One> Function<N> for NotFunction {
+        fn name(&self) -> &str {
+            "not"
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::Exactly(1)
+        }
+
+        fn call(&self, args: &[N]) -> Result<N> {
+            match args {
+                [n] if n.is_zero() => Ok(N::one()),
+                [_] => Ok(N::zero()),
+                _ => Err(Error::from(ErrorKind::InvalidArgumentCount)),
+            }
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::Not.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Not.category().into())
+        }
+    }
+
+    /// A binary operator that is not valid for the numeric type of a context, eg: `>` for complex
+    /// numbers. Always returns an `ErrorKind::Unsupported` error.
+    pub struct UnsupportedOperator {
+        name: &'static str,
+        type_name: &'static str,
+        precedence: Precedence,
+    }
+
+    impl UnsupportedOperator {
+        /// Constructs an `UnsupportedOperator` with the given name and precedence, and the name
+        /// of the numeric type used in the error message, eg: `complex numbers`.
+        #[inline]
+        pub const fn new(name: &'static str, type_name: &'static str, precedence: Precedence) -> Self {
+            UnsupportedOperator { name, type_name, precedence }
+        }
+    }
+
+    impl<N> BinaryFunction<N> for UnsupportedOperator {
+        #[inline]
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        #[inline]
+        fn precedence(&self) -> Precedence {
+            self.precedence
+        }
+
+        #[inline]
+        fn associativity(&self) -> Associativity {
+            Associativity::Left
+        }
+
+        fn call(&self, _: N, _: N) -> Result<N> {
+            Err(unsupported_error(self.name, self.type_name))
+        }
+    }
+
+    #[inline]
+    fn unsupported_error(name: &str, type_name: &str) -> Error {
+        Error::new(
+            ErrorKind::Unsupported,
+            format!("`{}` is not supported for {}", name, type_name),
+        )
+    }
+
+    /// The conditional `if(cond, then, otherwise)`, also named `ternary`.
+    ///
+    /// # Remarks
+    /// The arguments are evaluated lazily, so only the condition and the returned branch
+    /// are evaluated, `call` is only used if the arguments were already evaluated.
+    pub struct IfFunction;
+    impl<N: Zero + Clone> Function<N> for IfFunction {
+        fn name(&self) -> &str {
+            "if"
+        }
+
+        fn aliases(&self) -> Option<&[&str]> {
+            Some(&["ternary"])
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::Exactly(3)
+        }
+
+        fn call(&self, args: &[N]) -> Result<N> {
+            match args {
+                [cond, then, otherwise] => Ok(if cond.is_zero() { otherwise.clone() } else { then.clone() }),
+                _ => Err(Error::from(ErrorKind::InvalidArgumentCount)),
+            }
+        }
+
+        fn wants_lazy_args(&self) -> bool {
+            true
+        }
+
+        fn call_lazy(&self, args: &[LazyArg<'_, N>]) -> Result<N> {
+            match args {
+                [cond, then, otherwise] => {
+                    if cond.eval()?.is_zero() {
+                        otherwise.eval()
+                    } else {
+                        then.eval()
+                    }
+                }
+                _ => Err(Error::from(ErrorKind::InvalidArgumentCount)),
+            }
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::If.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::If.category().into())
+        }
+    }
+
+    pub struct GeoMeanFunction;
+    impl<N: ToPrimitive + FromPrimitive> Function<N> for GeoMeanFunction {
+        fn name(&self) -> &str {
+            "geomean"
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::AtLeast(1)
+        }
+
+        fn call(&self, args: &[N]) -> Result<N> {
+            if args.is_empty() {
+                return Err(Error::from(ErrorKind::InvalidArgumentCount));
+            }
+
+            // The geometric mean is only defined for positive values
+            let mut product = 1_f64;
+
+            for n in args {
+                let value = try_to_float(n)?;
+                if value < 0_f64 {
+                    return Err(Error::from(ErrorKind::NegativeValue));
+                }
+
+                if value.is_zero() {
+                    return Err(Error::from(ErrorKind::Zero));
+                }
+
+                product *= value;
+            }
+
+            let result = product.powf(1_f64 / args.len() as f64);
+            if result.is_infinite() {
+                return Err(Error::from(ErrorKind::Overflow));
+            }
+
+            N::from_f64(result).ok_or_else(|| Error::from(ErrorKind::Overflow))
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::GeoMean.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::GeoMean.category().into())
+        }
+    }
+
+    pub struct HarMeanFunction;
+    impl<N: ToPrimitive + FromPrimitive> Function<N> for HarMeanFunction {
+        fn name(&self) -> &str {
+            "harmean"
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::AtLeast(1)
+        }
+
+        fn call(&self, args: &[N]) -> Result<N> {
+            if args.is_empty() {
+                return Err(Error::from(ErrorKind::InvalidArgumentCount));
+            }
+
+            let mut sum = 0_f64;
+
+            for n in args {
+                let value = try_to_float(n)?;
+                if value.is_zero() {
+                    return Err(Error::from(ErrorKind::DivisionByZero));
+                }
+
+                sum += 1_f64 / value;
+            }
+
+            if sum.is_zero() {
+                return Err(Error::from(ErrorKind::DivisionByZero));
+            }
+
+            let result = args.len() as f64 / sum;
+            N::from_f64(result).ok_or_else(|| Error::from(ErrorKind::Overflow))
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::HarMean.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::HarMean.category().into())
+        }
+    }
+
+    /// Converts the arguments to its absolute integer values, used by `gcd` and `lcm`.
+    fn to_unsigned_integers<N: ToPrimitive>(args: &[N]) -> Result<Vec<u128>> {
+        if args.len() < 2 {
+            return Err(Error::from(ErrorKind::InvalidArgumentCount));
+        }
+
+        args.iter()
+            .map(|n| {
+                if try_to_float(n)?.fract() != 0_f64 {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        "Expected integer values",
+                    ));
+                }
+
+                n.to_i128()
+                    .map(i128::unsigned_abs)
+                    .ok_or_else(|| Error::from(ErrorKind::Overflow))
+            })
+            .collect()
+    }
+
+    fn gcd(mut a: u128, mut b: u128) -> u128 {
+        while b != 0 {
+            let r = a % b;
+            a = b;
+            b = r;
         }
+
+        a
     }
 
-    pub struct MaxFunction;
-    impl<N: PartialOrd + Clone> Function<N> for MaxFunction {
+    pub struct GcdFunction;
+    impl<N: ToPrimitive + FromPrimitive> Function<N> for GcdFunction {
         fn name(&self) -> &str {
-            "max"
+            "gcd"
         }
 
-        fn call(&self, args: &[N]) -> Result<N> {
-            if args.len() <= 1{
-                return Err(Error::from(ErrorKind::InvalidArgumentCount));
-            }
-
-            let mut max = None;
+        fn arity(&self) -> Arity {
+            Arity::AtLeast(2)
+        }
 
-            for n in args {
-                match max {
-                    None => max = Some(n.clone()),
-                    Some(ref current_max) => {
-                        if n > current_max {
-                            max = Some(n.clone())
-                        }
-                    }
-                }
-            }
+        fn call(&self, args: &[N]) -> Result<N> {
+            let result = to_unsigned_integers(args)?
+                .into_iter()
+                .fold(0, gcd);
 
-            max.ok_or_else(|| Error::from(ErrorKind::InvalidArgumentCount))
+            N::from_u128(result).ok_or_else(|| Error::from(ErrorKind::Overflow))
         }
 
         #[cfg(feature = "docs")]
         fn description(&self) -> Option<&str> {
-            Some(Description::Max.into())
+            Some(Description::Gcd.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Gcd.category().into())
         }
     }
 
-    pub struct MinFunction;
-    impl<N: PartialOrd + Clone> Function<N> for MinFunction {
+    pub struct LcmFunction;
+    impl<N: ToPrimitive + FromPrimitive> Function<N> for LcmFunction {
         fn name(&self) -> &str {
-            "min"
+            "lcm"
         }
 
-        fn call(&self, args: &[N]) -> Result<N> {
-            if args.len() <= 1{
-                return Err(Error::from(ErrorKind::InvalidArgumentCount));
-            }
+        fn arity(&self) -> Arity {
+            Arity::AtLeast(2)
+        }
 
-            let mut min = None;
+        fn call(&self, args: &[N]) -> Result<N> {
+            let mut result = 1_u128;
 
-            for n in args {
-                match min {
-                    None => min = Some(n.clone()),
-                    Some(ref current_min) => {
-                        if n < current_min {
-                            min = Some(n.clone());
-                        }
-                    }
+            for n in to_unsigned_integers(args)? {
+                if n == 0 {
+                    return N::from_u128(0).ok_or_else(|| Error::from(ErrorKind::Overflow));
                 }
+
+                result = (result / gcd(result, n))
+                    .checked_mul(n)
+                    .ok_or_else(|| Error::from(ErrorKind::Overflow))?;
             }
 
-            min.ok_or_else(|| Error::from(ErrorKind::InvalidArgumentCount))
+            N::from_u128(result).ok_or_else(|| Error::from(ErrorKind::Overflow))
         }
 
         #[cfg(feature = "docs")]
         fn description(&self) -> Option<&str> {
-            Some(Description::Min.into())
+            Some(Description::Lcm.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Lcm.category().into())
         }
     }
 
@@ -558,6 +1634,13 @@ mod math_ops {
     #[cfg(feature = "docs")]
     forward_func_impl!(SqrtFunction, sqrt, Description::Sqrt);
 
+    pub struct CbrtFunction;
+    #[cfg(not(feature = "docs"))]
+    forward_func_impl!(CbrtFunction, cbrt);
+
+    #[cfg(feature = "docs")]
+    forward_func_impl!(CbrtFunction, cbrt, Description::Cbrt);
+
     pub struct ExpFunction;
     #[cfg(not(feature = "docs"))]
     forward_func_impl!(ExpFunction, exp);
@@ -565,6 +1648,54 @@ mod math_ops {
     #[cfg(feature = "docs")]
     forward_func_impl!(ExpFunction, exp, Description::Exp);
 
+    macro_rules! base_pow_func_impl {
+        ($func_name:ident, $name:expr, $base:expr, $description:expr) => {
+            impl<N: ToPrimitive + FromPrimitive> Function<N> for $func_name {
+                fn name(&self) -> &str {
+                    $name
+                }
+
+                fn arity(&self) -> Arity {
+                    Arity::Exactly(1)
+                }
+
+                fn call(&self, args: &[N]) -> Result<N> {
+                    if args.len() != 1 {
+                        return Err(Error::from(ErrorKind::InvalidArgumentCount));
+                    }
+
+                    let exponent = try_to_float(&args[0])?;
+
+                    // `powi` is exact for integer exponents, eg: `10^3 = 1000`
+                    let result = if exponent.fract() == 0_f64 && exponent.abs() <= i32::MAX as f64 {
+                        f64::powi($base, exponent as i32)
+                    } else {
+                        f64::powf($base, exponent)
+                    };
+
+                    N::from_f64(result)
+                        .ok_or_else(|| Error::from(ErrorKind::Overflow))
+                }
+
+                #[cfg(feature = "docs")]
+                fn description(&self) -> Option<&str> {
+                    Some($description.into())
+                }
+
+                #[cfg(feature = "docs")]
+                fn category(&self) -> Option<&str> {
+                    Some($description.category().into())
+                }
+            }
+        };
+    }
+
+    pub struct Pow10Function;
+    base_pow_func_impl!(Pow10Function, "pow10", 10_f64, Description::Pow10);
+
+    pub struct Exp2Function;
+    base_pow_func_impl!(Exp2Function, "exp2", 2_f64, Description::Exp2);
+
     pub struct LnFunction;
     #[cfg(not(feature = "docs"))]
     forward_func_impl!(LnFunction, ln);
@@ -572,22 +1703,30 @@ mod math_ops {
     #[cfg(feature = "docs")]
     forward_func_impl!(LnFunction, ln, Description::Ln);
 
+    /// Converts the result of a logarithm, the logarithm of zero or a negative value is an error.
+    fn log_result<N: FromPrimitive>(result: f64) -> Result<N> {
+        if result.is_nan() || result.is_infinite() {
+            Err(Error::from(ErrorKind::NAN))
+        } else {
+            N::from_f64(result).ok_or_else(|| Error::from(ErrorKind::Overflow))
+        }
+    }
+
+    /// Logarithm in base 10 or the given base: `log(x)` or `log(x, base)`.
     pub struct LogFunction;
     impl<N: ToPrimitive + FromPrimitive> Function<N> for LogFunction {
         fn name(&self) -> &str {
             "log"
         }
 
+        fn arity(&self) -> Arity {
+            Arity::Range(1, 2)
+        }
+
         fn call(&self, args: &[N]) -> Result<N> {
             match args.len() {
-                1 => match args[0].to_f64().map(f64::log10) {
-                    Some(n) => {
-                        if n.is_nan() || n.is_infinite() {
-                            Err(Error::from(ErrorKind::NAN))
-                        } else {
-                            N::from_f64(n).ok_or_else(|| Error::from(ErrorKind::Overflow))
-                        }
-                    }
+                1 => match args[0].to_f64() {
+                    Some(value) => log_result(value.log10()),
                     None => Err(Error::from(ErrorKind::Overflow)),
                 },
                 2 => {
@@ -595,14 +1734,7 @@ mod math_ops {
                     let y = args[1].to_f64();
 
                     match (x, y) {
-                        (Some(value), Some(base)) => {
-                            let result = value.log(base);
-                            if result.is_nan() || result.is_infinite() {
-                                Err(Error::from(ErrorKind::NAN))
-                            } else {
-                                N::from_f64(result).ok_or_else(|| Error::from(ErrorKind::Overflow))
-                            }
-                        }
+                        (Some(value), Some(base)) => log_result(value.log(base)),
                         _ => Err(Error::from(ErrorKind::Overflow)),
                     }
                 }
@@ -614,6 +1746,101 @@ mod math_ops {
         fn description(&self) -> Option<&str> {
             Some(Description::Log.into())
         }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Log.category().into())
+        }
+    }
+
+    /// Logarithm in base 2: `log2(x)`.
+    pub struct Log2Function;
+    impl<N: ToPrimitive + FromPrimitive> Function<N> for Log2Function {
+        fn name(&self) -> &str {
+            "log2"
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::Exactly(1)
+        }
+
+        fn call(&self, args: &[N]) -> Result<N> {
+            match args {
+                [value] => log_result(try_to_float(value)?.log2()),
+                _ => Err(Error::from(ErrorKind::InvalidArgumentCount)),
+            }
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::Log2.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Log2.category().into())
+        }
+    }
+
+    /// Logarithm in base 10: `log10(x)`, the same as `log(x)`.
+    pub struct Log10Function;
+    impl<N: ToPrimitive + FromPrimitive> Function<N> for Log10Function {
+        fn name(&self) -> &str {
+            "log10"
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::Exactly(1)
+        }
+
+        fn call(&self, args: &[N]) -> Result<N> {
+            match args {
+                [value] => log_result(try_to_float(value)?.log10()),
+                _ => Err(Error::from(ErrorKind::InvalidArgumentCount)),
+            }
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::Log10.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Log10.category().into())
+        }
+    }
+
+    /// Logarithm in the given base, which goes first as in `log_b(x)`: `logb(base, x)`.
+    ///
+    /// # Remarks
+    /// `log(x, base)` takes the base last, so `logb(b, x)` is the same as `log(x, b)`.
+    pub struct LogBFunction;
+    impl<N: ToPrimitive + FromPrimitive> Function<N> for LogBFunction {
+        fn name(&self) -> &str {
+            "logb"
+        }
+
+        fn arity(&self) -> Arity {
+            Arity::Exactly(2)
+        }
+
+        fn call(&self, args: &[N]) -> Result<N> {
+            match args {
+                [base, value] => log_result(try_to_float(value)?.log(try_to_float(base)?)),
+                _ => Err(Error::from(ErrorKind::InvalidArgumentCount)),
+            }
+        }
+
+        #[cfg(feature = "docs")]
+        fn description(&self) -> Option<&str> {
+            Some(Description::LogB.into())
+        }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::LogB.category().into())
+        }
     }
 
     pub struct RandFunction;
@@ -623,6 +1850,10 @@ mod math_ops {
             "random"
         }
 
+        fn arity(&self) -> Arity {
+            Arity::Range(0, 2)
+        }
+
         fn call(&self, args: &[N]) -> Result<N> {
             match args.len() {
                 0 => N::from_f64(random::<f64>()).ok_or_else(|| Error::from(ErrorKind::Overflow)),
@@ -656,6 +1887,11 @@ mod math_ops {
         fn description(&self) -> Option<&str> {
             Some(Description::Rand.into())
         }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::Rand.category().into())
+        }
     }
 
     pub struct ToRadiansFunction;
@@ -676,7 +1912,8 @@ mod math_ops {
 mod trig_ops {
     use num_traits::{FromPrimitive, Inv, ToPrimitive, Zero};
     use crate::error::*;
-    pub use crate::function::{BinaryFunction, Function, UnaryFunction};
+    pub use crate::function::Function;
+    use crate::function::Arity;
     use crate::ops::try_to_float;
     use crate::Result;
     use crate::utils::approx::Approx;
@@ -692,6 +1929,10 @@ mod trig_ops {
                     stringify!($method_name)
                 }
 
+                fn arity(&self) -> Arity {
+                    Arity::Exactly(1)
+                }
+
                 fn call(&self, args: &[N]) -> Result<N> {
                     if args.len() != 1 {
                         Err(Error::from(ErrorKind::InvalidArgumentCount))
@@ -723,6 +1964,10 @@ mod trig_ops {
                     stringify!($name)
                 }
 
+                fn arity(&self) -> Arity {
+                    Arity::Exactly(1)
+                }
+
                 fn call(&self, args: &[N]) -> Result<N> {
                     if args.len() != 1 {
                         Err(Error::from(ErrorKind::InvalidArgumentCount))
@@ -757,6 +2002,10 @@ mod trig_ops {
                     stringify!($method_name)
                 }
 
+                fn arity(&self) -> Arity {
+                    Arity::Exactly(1)
+                }
+
                 fn call(&self, args: &[N]) -> Result<N> {
                     if args.len() != 1 {
                         Err(Error::from(ErrorKind::InvalidArgumentCount))
@@ -780,6 +2029,10 @@ mod trig_ops {
                 fn description(&self) -> Option<&str> {
                     Some($description.into())
                 }
+
+                fn category(&self) -> Option<&str> {
+                    Some($description.category().into())
+                }
             }
         };
     }
@@ -792,6 +2045,10 @@ mod trig_ops {
                     stringify!($name)
                 }
 
+                fn arity(&self) -> Arity {
+                    Arity::Exactly(1)
+                }
+
                 fn call(&self, args: &[N]) -> Result<N> {
                     if args.len() != 1 {
                         Err(Error::from(ErrorKind::InvalidArgumentCount))
@@ -818,6 +2075,10 @@ mod trig_ops {
                 fn description(&self) -> Option<&str> {
                     Some($description.into())
                 }
+
+                fn category(&self) -> Option<&str> {
+                    Some($description.category().into())
+                }
             }
         };
     }
@@ -928,6 +2189,10 @@ mod trig_ops {
             stringify!(atan)
         }
 
+        fn arity(&self) -> Arity {
+            Arity::Range(1, 2)
+        }
+
         fn call(&self, args: &[N]) -> Result<N> {
             match args.len() {
                 1 => match args[0].to_f64().map(f64::atan).map(f64::to_degrees) {
@@ -964,6 +2229,11 @@ mod trig_ops {
         fn description(&self) -> Option<&str> {
             Some(Description::ATan.into())
         }
+
+        #[cfg(feature = "docs")]
+        fn category(&self) -> Option<&str> {
+            Some(Description::ATan.category().into())
+        }
     }
 
     pub struct ACscFunction;
@@ -1034,6 +2304,7 @@ mod trig_ops {
 mod tests{
     use super::math::*;
     use num_traits::Inv;
+    use crate::error::{Error, ErrorKind};
     use crate::utils::approx::Approx;
 
     const ERROR : f64 = 0.000_000_000_01;
@@ -1055,6 +2326,7 @@ mod tests{
     }
 
     #[test]
+    #[allow(clippy::excessive_precision)]
     fn factorial_test(){
         let instance = Factorial;
 
@@ -1068,9 +2340,17 @@ mod tests{
         //assert_eq!(instance.call(3.2_f64), Ok(7.75668953579317763_f64));
         assert!(almost_eq(instance.call(0.2_f64).unwrap(), 0.91816874239976061_f64, ERROR));
         assert!(almost_eq(instance.call(3.2_f64).unwrap(), 7.75668953579317763_f64, ERROR));
+
+        assert!(instance.call(170_f64).unwrap().is_finite());
+        assert_eq!(instance.call(171_f64), Err(Error::from(ErrorKind::Overflow)));
+        assert_eq!(instance.call(1_000_000_f64), Err(Error::from(ErrorKind::Overflow)));
+
+        assert!(instance.call(33_i128).is_ok());
+        assert_eq!(instance.call(34_i128), Err(Error::from(ErrorKind::Overflow)));
     }
 
     #[test]
+    #[allow(clippy::excessive_precision)]
     fn pow_test(){
         let instance = PowOperator;
 
@@ -1083,6 +2363,16 @@ mod tests{
 
         assert!(almost_eq(instance.call(3_f64, 0.2_f64).unwrap(), 1.245730939615517325966_f64, ERROR));
         assert!(almost_eq(instance.call(3_f64, -0.2_f64).unwrap(), 0.802741561760230682095_f64, ERROR));
+
+        // Real roots of negative bases
+        assert!(almost_eq(instance.call(-8_f64, 1_f64 / 3_f64).unwrap(), -2_f64, ERROR));
+        assert!(almost_eq(instance.call(-8_f64, 2_f64 / 3_f64).unwrap(), 4_f64, ERROR));
+        assert!(almost_eq(instance.call(-32_f64, 0.2_f64).unwrap(), -2_f64, ERROR));
+        assert!(almost_eq(instance.call(-8_f64, -1_f64 / 3_f64).unwrap(), -0.5_f64, ERROR));
+        assert_eq!(instance.call(-2_f64, 3_f64), Ok(-8_f64));
+
+        assert_eq!(instance.call(-4_f64, 0.5_f64).unwrap_err().kind(), ErrorKind::NegativeValue);
+        assert!(instance.call(-4_f64, std::f64::consts::PI).is_err());
     }
 
     #[test]
@@ -1107,6 +2397,182 @@ mod tests{
         assert!(instance.call(&empty_array::<f64>()).is_err())
     }
 
+    #[test]
+    fn combination_test(){
+        let instance = CombinationFunction;
+
+        assert_eq!(instance.call(&[5_i64, 2]), Ok(10));
+        assert_eq!(instance.call(&[10_i64, 0]), Ok(1));
+        assert_eq!(instance.call(&[10_i64, 10]), Ok(1));
+        assert_eq!(instance.call(&[3_i64, 5]), Ok(0));
+        assert_eq!(instance.call(&[60_i128, 30]), Ok(118264581564861424));
+        assert_eq!(instance.call(&[5_f64, 2_f64]), Ok(10_f64));
+        assert!((instance.call(&[2.5_f64, 1_f64]).unwrap() - 2.5_f64).abs() < 1e-10);
+        assert!((instance.call(&[200_f64, 100_f64]).unwrap() - 9.054851465610328e58).abs() < 1e45);
+
+        assert_eq!(instance.call(&[-5_i64, 2]).unwrap_err().kind(), ErrorKind::NegativeValue);
+        assert_eq!(instance.call(&[5_i64, -2]).unwrap_err().kind(), ErrorKind::NegativeValue);
+        assert_eq!(instance.call(&[200_i64, 100]).unwrap_err().kind(), ErrorKind::Overflow);
+        assert_eq!(instance.call(&[5_i64]).unwrap_err().kind(), ErrorKind::InvalidArgumentCount);
+    }
+
+    #[test]
+    fn permutation_test(){
+        let instance = PermutationFunction;
+
+        assert_eq!(instance.call(&[5_i64, 2]), Ok(20));
+        assert_eq!(instance.call(&[5_i64, 0]), Ok(1));
+        assert_eq!(instance.call(&[5_i64, 5]), Ok(120));
+        assert_eq!(instance.call(&[3_i64, 5]), Ok(0));
+        assert!((instance.call(&[2.5_f64, 2_f64]).unwrap() - 3.75_f64).abs() < 1e-10);
+        assert!((instance.call(&[0.5_f64, 0.5_f64]).unwrap() - 0.886226925452758).abs() < 1e-10);
+
+        assert_eq!(instance.call(&[-1_f64, 1_f64]).unwrap_err().kind(), ErrorKind::NegativeValue);
+        assert_eq!(instance.call(&[30_i64, 30]).unwrap_err().kind(), ErrorKind::Overflow);
+        assert_eq!(instance.call(&[500_f64, 400_f64]).unwrap_err().kind(), ErrorKind::Overflow);
+    }
+
+    #[test]
+    fn gcd_test(){
+        let instance = GcdFunction;
+
+        assert_eq!(instance.call(&[8_i128, 15]), Ok(1));
+        assert_eq!(instance.call(&[12_i128, 36]), Ok(12));
+        assert_eq!(instance.call(&[-12_i128, 18]), Ok(6));
+        assert_eq!(instance.call(&[12_i128, 18, 27]), Ok(3));
+        assert_eq!(instance.call(&[0_i128, 5]), Ok(5));
+        assert_eq!(instance.call(&[24_f64, 36_f64]), Ok(12_f64));
+
+        assert_eq!(instance.call(&[12_i128]).unwrap_err().kind(), ErrorKind::InvalidArgumentCount);
+        assert_eq!(instance.call(&[1.5_f64, 3_f64]).unwrap_err().kind(), ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn lcm_test(){
+        let instance = LcmFunction;
+
+        assert_eq!(instance.call(&[8_i128, 15]), Ok(120));
+        assert_eq!(instance.call(&[12_i128, 36]), Ok(36));
+        assert_eq!(instance.call(&[-4_i128, 6]), Ok(12));
+        assert_eq!(instance.call(&[4_i128, 6, 10]), Ok(60));
+        assert_eq!(instance.call(&[0_i128, 5]), Ok(0));
+
+        assert_eq!(instance.call(&[12_i128]).unwrap_err().kind(), ErrorKind::InvalidArgumentCount);
+        assert_eq!(instance.call(&[i128::MAX, i128::MAX - 1]).unwrap_err().kind(), ErrorKind::Overflow);
+        assert_eq!(instance.call(&[i64::MAX, i64::MAX - 1]).unwrap_err().kind(), ErrorKind::Overflow);
+    }
+
+    #[test]
+    fn clamp_test(){
+        let instance = ClampFunction;
+
+        assert_eq!(instance.call(&[-5, 0, 10]), Ok(0));
+        assert_eq!(instance.call(&[5, 0, 10]), Ok(5));
+        assert_eq!(instance.call(&[15, 0, 10]), Ok(10));
+        assert_eq!(instance.call(&[2.5_f64, 2.5_f64, 2.5_f64]), Ok(2.5_f64));
+
+        assert_eq!(instance.call(&[5, 10, 0]).unwrap_err().kind(), ErrorKind::InvalidInput);
+        assert_eq!(instance.call(&[5, 10]).unwrap_err().kind(), ErrorKind::InvalidArgumentCount);
+        assert_eq!(instance.call(&[5, 0, 10, 1]).unwrap_err().kind(), ErrorKind::InvalidArgumentCount);
+    }
+
+    #[test]
+    fn comparison_test(){
+        assert_eq!(EqOperator.call(2, 2), Ok(1));
+        assert_eq!(NeOperator.call(2, 2), Ok(0));
+        assert_eq!(GtOperator.call(3_f64, 2_f64), Ok(1_f64));
+        assert_eq!(LtOperator.call(3_f64, 2_f64), Ok(0_f64));
+        assert_eq!(GteOperator.call(2, 2), Ok(1));
+        assert_eq!(LteOperator.call(3, 2), Ok(0));
+        assert_eq!(GtOperator.call(f64::NAN, 2_f64), Ok(0_f64));
+    }
+
+    #[test]
+    fn logical_test(){
+        assert_eq!(AndFunction.call(&[1, 2, -3]), Ok(1));
+        assert_eq!(AndFunction.call(&[1, 0]), Ok(0));
+        assert_eq!(OrFunction.call(&[0, 0, 5]), Ok(1));
+        assert_eq!(OrFunction.call(&[0_f64, 0_f64]), Ok(0_f64));
+        assert_eq!(NotFunction.call(&[0]), Ok(1));
+        assert_eq!(NotFunction.call(&[7]), Ok(0));
+
+        assert!(AndFunction.call(&[1]).is_err());
+        assert!(NotFunction.call(&[1, 2]).is_err());
+    }
+
+    #[test]
+    fn ignore_nan_test(){
+        let max = MaxIgnoreNanFunction;
+        let min = MinIgnoreNanFunction;
+
+        assert_eq!(max.call(&[f64::NAN, 3_f64, -1_f64, f64::INFINITY]), Ok(3_f64));
+        assert_eq!(min.call(&[3_f64, f64::NAN, -1_f64, f64::NEG_INFINITY]), Ok(-1_f64));
+        assert_eq!(max.call(&[f64::NAN, 2_f64]), Ok(2_f64));
+        assert_eq!(min.call(&[5, 3, 4]), Ok(3));
+
+        assert_eq!(max.call(&[f64::NAN, f64::INFINITY]).unwrap_err().kind(), ErrorKind::NAN);
+        assert_eq!(min.call(&[f64::NAN]).unwrap_err().kind(), ErrorKind::NAN);
+        assert!(max.call(&empty_array::<f64>()).is_err())
+    }
+
+    #[test]
+    fn percentile_test(){
+        let instance = PercentileFunction;
+
+        // Median
+        assert_eq!(instance.call(&[50_f64, 3_f64, 1_f64, 2_f64]), Ok(2_f64));
+        assert_eq!(instance.call(&[50_f64, 4_f64, 1_f64, 3_f64, 2_f64]), Ok(2.5_f64));
+
+        // Boundaries
+        assert_eq!(instance.call(&[0_f64, 5_f64, -2_f64, 9_f64]), Ok(-2_f64));
+        assert_eq!(instance.call(&[100_f64, 5_f64, -2_f64, 9_f64]), Ok(9_f64));
+        assert_eq!(instance.call(&[90_f64, 7_f64]), Ok(7_f64));
+
+        // Interpolation between the closest ranks
+        let values = (1..=100).map(|n| n as f64);
+        let args = std::iter::once(90_f64).chain(values).collect::<Vec<_>>();
+        assert_eq!(instance.call(&args), Ok(90.1_f64));
+        assert_eq!(instance.call(&[25_f64, 10_f64, 20_f64, 30_f64]), Ok(15_f64));
+
+        assert_eq!(instance.call(&[50_f64]).unwrap_err().kind(), ErrorKind::InvalidArgumentCount);
+        assert_eq!(instance.call(&[101_f64, 1_f64]).unwrap_err().kind(), ErrorKind::InvalidInput);
+        assert_eq!(instance.call(&[-1_f64, 1_f64]).unwrap_err().kind(), ErrorKind::InvalidInput);
+        assert_eq!(instance.call(&[f64::NAN, 1_f64]).unwrap_err().kind(), ErrorKind::NAN);
+
+        // Integers don't overflow and the interpolated value is truncated
+        assert_eq!(instance.call(&[50_i64, i64::MIN, i64::MAX]), Ok(0));
+        assert_eq!(instance.call(&[100_i64, i64::MIN, i64::MAX]), Ok(i64::MAX));
+        assert_eq!(instance.call(&[0_i64, i64::MAX, i64::MIN]), Ok(i64::MIN));
+        assert_eq!(instance.call(&[50_i64, 1, 2]), Ok(1));
+        assert_eq!(instance.call(&[50_i64, 1, 2, 3]), Ok(2));
+        assert_eq!(instance.call(&[50_f64, f64::NAN, 1_f64]).unwrap_err().kind(), ErrorKind::NAN);
+    }
+
+    #[test]
+    fn geomean_test(){
+        let instance = GeoMeanFunction;
+
+        assert!(almost_eq(instance.call(&[2_f64, 8_f64]).unwrap(), 4_f64, ERROR));
+        assert!(almost_eq(instance.call(&[1_f64, 4_f64, 16_f64]).unwrap(), 4_f64, ERROR));
+        assert_eq!(instance.call(&[5_f64]), Ok(5_f64));
+
+        assert_eq!(instance.call(&[2_f64, 0_f64]).unwrap_err().kind(), ErrorKind::Zero);
+        assert_eq!(instance.call(&[2_f64, -8_f64]).unwrap_err().kind(), ErrorKind::NegativeValue);
+        assert!(instance.call(&empty_array::<f64>()).is_err())
+    }
+
+    #[test]
+    fn harmean_test(){
+        let instance = HarMeanFunction;
+
+        // 3 / (1/1 + 1/2 + 1/4)
+        assert!(almost_eq(instance.call(&[1_f64, 2_f64, 4_f64]).unwrap(), 3_f64 / 1.75_f64, ERROR));
+        assert_eq!(instance.call(&[5_f64]), Ok(5_f64));
+
+        assert!(instance.call(&[2_f64, 0_f64]).is_err());
+        assert!(instance.call(&empty_array::<f64>()).is_err())
+    }
+
     #[test]
     fn floor_test(){
         let instance = FloorFunction;
@@ -1176,6 +2642,54 @@ mod tests{
         assert!(instance.call(&empty_array::<f64>()).is_err())
     }
 
+    #[test]
+    fn wrap_test(){
+        let instance = WrapFunction;
+
+        assert_eq!(instance.call(&[370, 360]), Ok(10));
+        assert_eq!(instance.call(&[-30, 360]), Ok(330));
+        assert_eq!(instance.call(&[720, 360]), Ok(0));
+        assert_eq!(instance.call(&[-370.5_f64, 360_f64]), Ok(349.5_f64));
+        assert_eq!(instance.call(&[-1e-20_f64, 360_f64]), Ok(0_f64));
+
+        assert_eq!(instance.call(&[10, 0]).unwrap_err().kind(), ErrorKind::DivisionByZero);
+        assert_eq!(instance.call(&[10, -360]).unwrap_err().kind(), ErrorKind::NegativeValue);
+        assert!(instance.call(&[10]).is_err());
+    }
+
+    #[test]
+    fn clamp01_step_smoothstep_test(){
+        assert_eq!(Clamp01Function.call(&[1.5_f64]), Ok(1_f64));
+        assert_eq!(Clamp01Function.call(&[-0.5_f64]), Ok(0_f64));
+        assert_eq!(Clamp01Function.call(&[0.25_f64]), Ok(0.25_f64));
+        assert_eq!(Clamp01Function.call(&[5]), Ok(1));
+
+        assert_eq!(StepFunction.call(&[0.5_f64, 0.7_f64]), Ok(1_f64));
+        assert_eq!(StepFunction.call(&[0.5_f64, 0.5_f64]), Ok(1_f64));
+        assert_eq!(StepFunction.call(&[0.5_f64, 0.2_f64]), Ok(0_f64));
+
+        assert_eq!(SmoothStepFunction.call(&[0_f64, 1_f64, 0.5_f64]), Ok(0.5_f64));
+        assert_eq!(SmoothStepFunction.call(&[0_f64, 10_f64, 2.5_f64]), Ok(0.15625_f64));
+        assert_eq!(SmoothStepFunction.call(&[0_f64, 1_f64, 2_f64]), Ok(1_f64));
+        assert_eq!(SmoothStepFunction.call(&[0_f64, 1_f64, -2_f64]), Ok(0_f64));
+        assert!(SmoothStepFunction.call(&[1_f64, 1_f64, 0.5_f64]).is_err());
+        assert!(StepFunction.call(&[1_f64]).is_err());
+    }
+
+    #[test]
+    fn cbrt_test(){
+        let instance = CbrtFunction;
+
+        assert_eq!(instance.call(&[27]), Ok(3));
+        assert_eq!(instance.call(&[-8]), Ok(-2));
+        assert_eq!(instance.call(&[27_f64]), Ok(3_f64));
+        assert_eq!(instance.call(&[-8_f64]), Ok(-2_f64));
+        assert_eq!(instance.call(&[5.5_f64]), Ok(5.5_f64.cbrt()));
+
+        assert!(instance.call(&[8_f64, 3_f64]).is_err());
+        assert!(instance.call(&empty_array::<f64>()).is_err())
+    }
+
     #[test]
     fn exp_test(){
         let instance = ExpFunction;
@@ -1230,6 +2744,23 @@ mod tests{
         assert!(instance.call(&empty_array::<f64>()).is_err())
     }
 
+    #[test]
+    fn log2_log10_logb_test(){
+        assert_eq!(Log2Function.call(&[8_f64]), Ok(3_f64));
+        assert_eq!(Log10Function.call(&[1000_f64]), Ok(3_f64));
+        assert_eq!(Log10Function.call(&[25_f64]), LogFunction.call(&[25_f64]));
+
+        // The base goes first in `logb` and last in `log`
+        assert_eq!(LogBFunction.call(&[2_f64, 32_f64]), Ok(5_f64));
+        assert_eq!(LogBFunction.call(&[3_f64, 20_f64]), LogFunction.call(&[20_f64, 3_f64]));
+
+        assert!(Log2Function.call(&[0_f64]).is_err());
+        assert!(Log10Function.call(&[-10_f64]).is_err());
+        assert!(LogBFunction.call(&[2_f64, 0_f64]).is_err());
+        assert!(LogBFunction.call(&[8_f64]).is_err());
+        assert!(Log2Function.call(&[8_f64, 2_f64]).is_err());
+    }
+
     #[test]
     fn rand_test(){
         const SAMPLES : usize = 1000;
@@ -1238,7 +2769,7 @@ mod tests{
         fn compute_random(rand: &RandFunction){
             for _ in 0..SAMPLES{
                 let value : f64 = rand.call(&empty_array::<f64>()).unwrap();
-                assert!(value >= 0_f64 && value < 1_f64, "value out of range: {0} >= 0 && {0} < 1", value)
+                assert!((0_f64..1_f64).contains(&value), "value out of range: {0} >= 0 && {0} < 1", value)
             }
         }
 
@@ -1711,4 +3242,29 @@ mod tests{
         assert!(instance.call(&[10_f64, 3_f64, 7_f64]).is_err());
         assert!(instance.call(&empty_array::<f64>()).is_err());
     }
+
+    #[cfg(feature = "docs")]
+    #[test]
+    fn category_test(){
+        use crate::function::{BinaryFunction, Function};
+        use crate::ops::checked::AddOperator;
+
+        assert_eq!(Function::<f64>::category(&SinFunction), Some("Trigonometry"));
+        assert_eq!(Function::<f64>::category(&SinhFunction), Some("Hyperbolic"));
+        assert_eq!(BinaryFunction::<i64>::category(&AddOperator), Some("Arithmetic"));
+    }
+
+    #[test]
+    fn pow10_exp2_test(){
+        assert_eq!(Pow10Function.call(&[3_f64]), Ok(1000_f64));
+        assert_eq!(Pow10Function.call(&[-2_f64]), Ok(0.01_f64));
+        assert_eq!(Pow10Function.call(&[3_i64]), Ok(1000_i64));
+        assert!(almost_eq(Pow10Function.call(&[0.5_f64]).unwrap(), 3.162_277_660_168_379_5_f64, ERROR));
+
+        assert_eq!(Exp2Function.call(&[10_f64]), Ok(1024_f64));
+        assert_eq!(Exp2Function.call(&[-1_f64]), Ok(0.5_f64));
+        assert_eq!(Exp2Function.call(&[10_i64]), Ok(1024_i64));
+
+        assert!(Pow10Function.call(&[40_i64]).is_err());
+    }
 }
@@ -4,7 +4,7 @@ use num_traits::{FromPrimitive, Zero};
 
 use crate::error::*;
 use crate::function::{
-    Associativity, BinaryFunction, Function, Notation, Precedence, UnaryFunction,
+    Arity, Associativity, BinaryFunction, Function, Notation, Precedence, UnaryFunction,
 };
 use crate::Result;
 
@@ -33,6 +33,11 @@ impl<N: Add<N, Output = N>> BinaryFunction<N> for AddOperator {
     fn description(&self) -> Option<&str> {
         Some(Description::Add.into())
     }
+
+    #[cfg(feature="docs")]
+    fn category(&self) -> Option<&str> {
+        Some(Description::Add.category().into())
+    }
 }
 
 pub struct SubOperator;
@@ -57,6 +62,11 @@ impl<N: Sub<N, Output = N>> BinaryFunction<N> for SubOperator {
     fn description(&self) -> Option<&str> {
         Some(Description::Sub.into())
     }
+
+    #[cfg(feature="docs")]
+    fn category(&self) -> Option<&str> {
+        Some(Description::Sub.category().into())
+    }
 }
 
 pub struct MulOperator;
@@ -81,6 +91,11 @@ impl<N: Mul<N, Output = N>> BinaryFunction<N> for MulOperator {
     fn description(&self) -> Option<&str> {
         Some(Description::Mul.into())
     }
+
+    #[cfg(feature="docs")]
+    fn category(&self) -> Option<&str> {
+        Some(Description::Mul.category().into())
+    }
 }
 
 pub struct DivOperator;
@@ -109,6 +124,11 @@ impl<N: Div<N, Output = N> + Zero> BinaryFunction<N> for DivOperator {
     fn description(&self) -> Option<&str> {
         Some(Description::Div.into())
     }
+
+    #[cfg(feature="docs")]
+    fn category(&self) -> Option<&str> {
+        Some(Description::Div.category().into())
+    }
 }
 
 pub struct ModOperator;
@@ -137,6 +157,11 @@ impl<N: Rem<N, Output = N> + Zero> BinaryFunction<N> for ModOperator {
     fn description(&self) -> Option<&str> {
         Some(Description::Mod.into())
     }
+
+    #[cfg(feature="docs")]
+    fn category(&self) -> Option<&str> {
+        Some(Description::Mod.category().into())
+    }
 }
 
 pub struct UnaryMinus;
@@ -157,6 +182,11 @@ impl<N: Neg<Output = N>> UnaryFunction<N> for UnaryMinus {
     fn description(&self) -> Option<&str> {
         Some(Description::Neg.into())
     }
+
+    #[cfg(feature="docs")]
+    fn category(&self) -> Option<&str> {
+        Some(Description::Neg.category().into())
+    }
 }
 
 pub struct AbsFunction;
@@ -165,6 +195,10 @@ impl<N: Zero + PartialOrd + Neg<Output = N> + Clone> Function<N> for AbsFunction
         "abs"
     }
 
+    fn arity(&self) -> Arity {
+        Arity::Exactly(1)
+    }
+
     fn call(&self, args: &[N]) -> Result<N> {
         if args.len() != 1 {
             Err(Error::from(ErrorKind::InvalidArgumentCount))
@@ -179,6 +213,11 @@ impl<N: Zero + PartialOrd + Neg<Output = N> + Clone> Function<N> for AbsFunction
     fn description(&self) -> Option<&str> {
         Some(Description::Abs.into())
     }
+
+    #[cfg(feature="docs")]
+    fn category(&self) -> Option<&str> {
+        Some(Description::Abs.category().into())
+    }
 }
 
 pub struct SumFunction;
@@ -187,6 +226,10 @@ impl<N: Add<N, Output = N> + Clone> Function<N> for SumFunction {
         "sum"
     }
 
+    fn arity(&self) -> Arity {
+        Arity::AtLeast(1)
+    }
+
     fn call(&self, args: &[N]) -> Result<N> {
         let mut result = None;
 
@@ -206,6 +249,11 @@ impl<N: Add<N, Output = N> + Clone> Function<N> for SumFunction {
     fn description(&self) -> Option<&str> {
         Some(Description::Sum.into())
     }
+
+    #[cfg(feature="docs")]
+    fn category(&self) -> Option<&str> {
+        Some(Description::Sum.category().into())
+    }
 }
 
 pub struct ProdFunction;
@@ -214,6 +262,10 @@ impl<N: Mul<N, Output = N> + Clone> Function<N> for ProdFunction {
         "product"
     }
 
+    fn arity(&self) -> Arity {
+        Arity::AtLeast(1)
+    }
+
     fn call(&self, args: &[N]) -> Result<N> {
         let mut result = None;
 
@@ -233,6 +285,11 @@ impl<N: Mul<N, Output = N> + Clone> Function<N> for ProdFunction {
     fn description(&self) -> Option<&str> {
         Some(Description::Prod.into())
     }
+
+    #[cfg(feature="docs")]
+    fn category(&self) -> Option<&str> {
+        Some(Description::Prod.category().into())
+    }
 }
 
 pub struct AvgFunction;
@@ -265,6 +322,11 @@ impl<N: Add<N, Output = N> + Div<N, Output = N> + FromPrimitive + Clone> Functio
     fn description(&self) -> Option<&str> {
         Some(Description::Avg.into())
     }
+
+    #[cfg(feature="docs")]
+    fn category(&self) -> Option<&str> {
+        Some(Description::Avg.category().into())
+    }
 }
 
 #[cfg(test)]
@@ -2,10 +2,17 @@ use std::fmt::{Debug, Display, Formatter};
 use Token::*;
 
 /// Represents a token in an expression.
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd)]
+///
+/// # Remarks
+/// `Token<N>` is `Hash` and `Eq` when `N` is, so the tokens can be the key of a cache,
+/// eg: `HashMap<Vec<Token<i64>>, Vec<Token<i64>>>` to store the rpn of an expression.
+/// Types like `f64` are not `Eq`, in that case use the expression source as key instead.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub enum Token<N> {
     /// A number
     Number(N),
+    /// A number followed by an unit, eg: `5km`, see `Config::with_unit`
+    NumberWithUnit(N, String),
     /// A variable
     Variable(String),
     /// A constant
@@ -22,9 +29,11 @@ pub enum Token<N> {
     GroupingOpen(char),
     /// A close grouping symbol
     GroupingClose(char),
+    /// A string literal, without the quotes and with its escape sequences resolved
+    Str(String),
     /// An unknown value
     Unknown(String),
-    /// A comma
+    /// A comma, the separator of the function arguments, see `Config::with_arg_separator`
     Comma,
 }
 
@@ -32,6 +41,7 @@ impl<N: Display> Display for Token<N> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             Number(n) => write!(f, "Number({})", n),
+            NumberWithUnit(n, unit) => write!(f, "NumberWithUnit({}, {})", n, unit),
             Variable(name) => write!(f, "Variable({})", name),
             Constant(name) => write!(f, "Constant({})", name),
             Function(name) => write!(f, "Function({})", name),
@@ -41,6 +51,7 @@ impl<N: Display> Display for Token<N> {
             ArgCount(n) => write!(f, "ArgCount({})", n),
             GroupingOpen(c) => write!(f, "ParenthesisOpen('{}')", c),
             GroupingClose(c) => write!(f, "ParenthesisClose('{}')", c),
+            Str(s) => write!(f, "Str({})", s),
             Unknown(name) => write!(f, "Unknown({})", name),
             Comma => write!(f, "Comma"),
         }
@@ -51,6 +62,7 @@ impl<N: Debug> Debug for Token<N> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             Number(n) => write!(f, "Number({:?})", n),
+            NumberWithUnit(n, unit) => write!(f, "NumberWithUnit({:?}, {:?})", n, unit),
             Variable(name) => write!(f, "Variable({:?})", name),
             Constant(name) => write!(f, "Constant({:?})", name),
             Function(name) => write!(f, "Function({:?})", name),
@@ -60,6 +72,7 @@ impl<N: Debug> Debug for Token<N> {
             ArgCount(n) => write!(f, "ArgCount({:?})", n),
             GroupingOpen(c) => write!(f, "ParenthesisOpen('{:?}')", c),
             GroupingClose(c) => write!(f, "ParenthesisClose('{:?}')", c),
+            Str(s) => write!(f, "Str({:?})", s),
             Unknown(name) => write!(f, "Unknown({:?})", name),
             Comma => write!(f, "Comma"),
         }
@@ -73,6 +86,12 @@ impl<N> Token<N> {
         matches!(self, Token::Number(_))
     }
 
+    /// Checks if the token is a number followed by an unit.
+    #[inline]
+    pub fn is_number_with_unit(&self) -> bool {
+        matches!(self, Token::NumberWithUnit(_, _))
+    }
+
     /// Checks if the token is a variable.
     #[inline]
     pub fn is_variable(&self) -> bool {
@@ -130,6 +149,12 @@ impl<N> Token<N> {
         matches!(self, Token::Comma)
     }
 
+    /// Checks if the token is a string literal.
+    #[inline]
+    pub fn is_str(&self) -> bool {
+        matches!(self, Token::Str(_))
+    }
+
     /// Checks if the token is an unknown value.
     ///
     /// # Remarks
@@ -153,3 +178,90 @@ impl<N> Token<N> {
         }
     }
 }
+
+/// Converts the given infix tokens into an expression string.
+///
+/// # Remarks
+/// The resulting string is canonical: binary operators are surrounded by a whitespace,
+/// commas are followed by a whitespace and any other token is written without spaces,
+/// except when is needed to separate 2 names eg: `not x`.
+/// `ArgCount` tokens are ignored, those are not part of an infix expression.
+///
+/// # Example
+/// ```
+/// use prexel::token::{Token::*, tokens_to_string};
+///
+/// let tokens = [
+///     Function("Max".to_string()),
+///     GroupingOpen('('),
+///     Number(2),
+///     Comma,
+///     Variable("x".to_string()),
+///     GroupingClose(')'),
+///     BinaryOperator("*".to_string()),
+///     Number(3),
+/// ];
+///
+/// assert_eq!("Max(2, x) * 3", tokens_to_string(&tokens));
+/// ```
+pub fn tokens_to_string<N: Display>(tokens: &[Token<N>]) -> String {
+    fn is_name_char(c: char) -> bool {
+        c.is_alphanumeric() || c == '_' || c == '.'
+    }
+
+    let mut result = String::new();
+
+    for token in tokens {
+        let text = match token {
+            Number(n) => n.to_string(),
+            NumberWithUnit(n, unit) => format!("{}{}", n, unit),
+            Variable(name) | Constant(name) | Function(name) | UnaryOperator(name) | Unknown(name) => {
+                name.clone()
+            }
+            GroupingOpen(c) | GroupingClose(c) => c.to_string(),
+            Str(s) => format!("{:?}", s),
+            BinaryOperator(name) => {
+                result.push(' ');
+                result.push_str(name);
+                result.push(' ');
+                continue;
+            }
+            Comma => {
+                result.push_str(", ");
+                continue;
+            }
+            ArgCount(_) => continue,
+        };
+
+        let needs_space = matches!(
+            (result.chars().last(), text.chars().next()),
+            (Some(prev), Some(next)) if is_name_char(prev) && is_name_char(next)
+        );
+
+        if needs_space {
+            result.push(' ');
+        }
+
+        result.push_str(&text);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn token_hash_test() {
+        let tokens = || vec![Number(2_i64), BinaryOperator("+".to_string()), Variable("x".to_string())];
+        let rpn = vec![Number(2_i64), Variable("x".to_string()), BinaryOperator("+".to_string())];
+
+        let mut cache = HashMap::new();
+        cache.insert(tokens(), rpn.clone());
+
+        assert_eq!(cache.get(&tokens()), Some(&rpn));
+        assert_eq!(cache.get(&rpn), None);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::context::{Context, DefaultContext};
+use crate::context::{Config, Context, DefaultContext};
 use crate::error::{Error, ErrorKind};
 use crate::function::Notation;
 use crate::token::Token;
@@ -16,6 +16,11 @@ trait TokenizeRule {
 
 /// The default `Tokenizer`.
 ///
+/// # Remarks
+/// Numbers in scientific notation take precedence over the names starting with `e` only
+/// when the exponent digits follow, so `2e3` is `2000` and `2e-3` is `0.002`, while `2e`
+/// is `2` followed by the constant `e`, eg: `2*e` with implicit multiplication.
+///
 /// # Example
 /// ```
 /// use prexel::tokenizer::Tokenizer;
@@ -66,23 +71,73 @@ where
     N: FromStr,
     S: Splitter,
 {
+    #[inline]
     pub fn tokenize(&self, context: &C, expression: &str) -> Result<Vec<Token<N>>> {
-        const COMMA: &str = ",";
+        self.tokenize_with(context, expression)
+    }
+
+    /// Converts the given expression into tokens using any `Context`, not only the one
+    /// this `Tokenizer` was declared for.
+    pub(crate) fn tokenize_with<T>(&self, context: &T, expression: &str) -> Result<Vec<Token<N>>>
+    where
+        T: Context<'a, N>,
+    {
+        self.tokenize_until(context, expression, false).map(|(tokens, _, _)| tokens)
+    }
+
+    /// Converts the given expression into tokens, if `stop_at_unknown` is `true` stops at the first
+    /// unknown token and returns the tokens before it, with the char position and value of the unknown token.
+    ///
+    /// Also returns the char position in the expression of each token.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn tokenize_until<T>(
+        &self,
+        context: &T,
+        expression: &str,
+        stop_at_unknown: bool,
+    ) -> Result<(Vec<Token<N>>, Vec<usize>, Option<UnknownToken>)>
+    where
+        T: Context<'a, N>,
+    {
         const WHITESPACE: &str = " ";
 
         if expression.trim().is_empty() {
             return Err(Error::new(ErrorKind::Empty, "Expression is empty"));
         }
 
+        if let Some(max_length) = context.config().max_expression_length {
+            if expression.chars().count() > max_length {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("Expression exceeds the max length of {} characters", max_length),
+                ));
+            }
+        }
+
         // `Vec` used for fast access indexing, Iterator.nth(..) could be O(N)
-        let raw_tokens = self.splitter.split_into_tokens(expression);
+        let raw_tokens = join_function_names(expression, self.splitter.split_into_tokens(expression), context);
         // Actual iterator over the string tokens.
         let mut iter = raw_tokens.iter().enumerate().peekable();
         // Stores the tokens to return.
         let mut tokens = Vec::new();
+        // The char position of each raw token and of each token to return.
+        let raw_positions = token_positions(expression, &raw_tokens);
+        let mut positions = Vec::new();
+        let mut current_position = 0;
 
         while let Some((pos, string)) = iter.next() {
-            let parsed_number = N::from_str(string);
+            // The tokens pushed in the last iteration come from the previous raw token
+            positions.resize(tokens.len(), current_position);
+            current_position = raw_positions[pos];
+
+            let parsed_number = match N::from_str(string) {
+                Ok(n) => Ok(n),
+                // Types like `Decimal` or integers don't parse scientific notation, eg: `2e3`
+                Err(err) => match expand_scientific(string) {
+                    Some(expanded) => N::from_str(&expanded),
+                    None => Err(err),
+                },
+            };
             if parsed_number.is_ok() {
                 // `complex_number` is enable in the context, check the next value and
                 // if is the imaginary unit append it to the current number.
@@ -100,6 +155,7 @@ where
                                 std::any::type_name::<N>()
                             ),
                         )
+                        .with_position(current_position)
                     })?;
                     tokens.push(Token::Number(n));
                 } else {
@@ -112,14 +168,52 @@ where
                                 std::any::type_name::<N>()
                             ),
                         )
+                        .with_position(current_position)
                     })?;
-                    tokens.push(Token::Number(n));
+
+                    // If the next value is an unit of the `Config`, append it to the number.
+                    match iter.peek().map(|s| s.1).filter(|s| context.config().is_unit(s)) {
+                        Some(unit) => {
+                            tokens.push(Token::NumberWithUnit(n, unit.clone()));
+                            iter.next();
+                        }
+                        None => tokens.push(Token::Number(n)),
+                    }
                 }
+            } else if is_integer_literal(string) {
+                // The value is a number that cannot be represented by `N`, eg: `300` for `u8`
+                return Err(Error::new(
+                    ErrorKind::Overflow,
+                    format!(
+                        "Integer literal `{}` is out of range for `{}`",
+                        string,
+                        std::any::type_name::<N>()
+                    ),
+                )
+                .with_position(current_position));
+            } else if let Some(s) = parse_string_literal(string) {
+                tokens.push(Token::Str(s));
             } else if context.is_variable(string) {
                 tokens.push(Token::Variable(string.clone()));
             } else if context.is_constant(string) {
                 tokens.push(Token::Constant(string.clone()));
             } else if context.is_function(string) {
+                if context.config().strict_function_call {
+                    let is_call = iter
+                        .peek()
+                        .map(|(_, next)| is_grouping_open(next, context.config()))
+                        .unwrap_or(false)
+                        || (pos > 0 && raw_tokens[pos - 1] == PIPE_OPERATOR);
+
+                    if !is_call {
+                        return Err(Error::new(
+                            ErrorKind::InvalidExpression,
+                            format!("Function `{}` must be called, eg: `{}()`", string, string),
+                        )
+                        .with_position(current_position));
+                    }
+                }
+
                 tokens.push(Token::Function(string.clone()));
             } else if context.is_binary_function(string) || context.is_unary_function(string) {
                 let prev = if pos == 0 {
@@ -139,19 +233,29 @@ where
                 } else {
                     // If the operator is not unary, should be binary so need 2 operands.
                     if prev.is_none() || next.is_none() {
+                        // A trailing operator can be completed with more input, eg: `8 +`
+                        let kind = if prev.is_some() {
+                            ErrorKind::IncompleteExpression
+                        } else {
+                            ErrorKind::InvalidExpression
+                        };
+
                         return Err(Error::new(
-                            ErrorKind::InvalidExpression,
+                            kind,
                             format!(
                                 "Binary operations need 2 operands: {:?} {} {:?}",
                                 prev, string, next
                             ),
-                        ));
+                        )
+                        .with_position(current_position));
                     }
 
                     let operator = string.clone();
                     tokens.push(Token::BinaryOperator(operator));
                 }
-            } else if string == COMMA {
+            } else if string == PIPE_OPERATOR {
+                tokens.push(Token::BinaryOperator(string.clone()));
+            } else if is_arg_separator(string, context.config().arg_separator()) {
                 tokens.push(Token::Comma);
             } else if string == WHITESPACE {
                 // Ignore whitespaces
@@ -170,11 +274,27 @@ where
                     }
                 }
 
+                if context.unknown_variable_value().is_some() && is_identifier(string) {
+                    tokens.push(Token::Variable(string.clone()));
+                    continue;
+                }
+
+                if stop_at_unknown {
+                    let position = match token_spans(expression, &raw_tokens)[pos] {
+                        Some((start, _)) => expression[..start].chars().count(),
+                        None => expression.chars().count(),
+                    };
+
+                    positions.resize(tokens.len(), current_position);
+                    return Ok((tokens, positions, Some((position, string.clone()))));
+                }
+
                 tokens.push(Token::Unknown(string.clone()));
             }
         }
 
-        Ok(tokens)
+        positions.resize(tokens.len(), current_position);
+        Ok((tokens, positions, None))
     }
 }
 
@@ -204,8 +324,11 @@ where
                         }
                     }
 
-                    // 10! , PI!, x!
-                    N::from_str(s).is_ok() || context.is_constant(s) || context.is_variable(s)
+                    // 10! , PI!, x!, 10km!
+                    N::from_str(s).is_ok()
+                        || context.is_constant(s)
+                        || context.is_variable(s)
+                        || config.is_unit(s)
                 }
                 None => false,
             }
@@ -223,10 +346,11 @@ where
                     }
                 }
 
-                // 10+, PI-, x+
+                // 10+, PI-, x+, 10km-
                 if N::from_str(prev_str).is_ok()
                     || context.is_variable(prev_str)
                     || context.is_constant(prev_str)
+                    || config.is_unit(prev_str)
                 {
                     return false;
                 }
@@ -255,6 +379,193 @@ where
     }
 }
 
+/// Checks if the value only contains decimal digits, eg: `12345`.
+#[inline]
+fn is_integer_literal(value: &str) -> bool {
+    !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Checks if the value is a valid variable name, eg: `x`, `_total`, `x2`.
+#[inline]
+fn is_identifier(value: &str) -> bool {
+    value.starts_with(|c: char| c.is_alphabetic() || c == '_')
+        && value.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// Expands a number in scientific notation to its plain representation, eg: `1.5e3` to `1500`.
+/// Returns `None` if the value is not in scientific notation.
+fn expand_scientific(value: &str) -> Option<String> {
+    // Limits the size of the expanded `String`
+    const MAX_EXPONENT: i32 = 1000;
+
+    let (mantissa, exponent) = value.split_once(['e', 'E'])?;
+    let exponent = exponent.parse::<i32>().ok().filter(|n| n.abs() <= MAX_EXPONENT)?;
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+    if integer.is_empty() || !is_integer_literal(integer) || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let digits = format!("{}{}", integer, fraction);
+    let point = integer.len() as i32 + exponent;
+
+    let expanded = if point <= 0 {
+        format!("0.{}{}", "0".repeat(point.unsigned_abs() as usize), digits)
+    } else if point as usize >= digits.len() {
+        format!("{}{}", digits, "0".repeat(point as usize - digits.len()))
+    } else {
+        let (left, right) = digits.split_at(point as usize);
+        format!("{}.{}", left, right)
+    };
+
+    Some(expanded)
+}
+
+/// The char position and value of an unknown token in an expression, see `Tokenizer::tokenize_until`.
+pub(crate) type UnknownToken = (usize, String);
+
+/// The pipe operator, applies the function at its right to the value at its left,
+/// eg: `16 |> sqrt` is `sqrt(16)`. Used only if the context doesn't define an operator with this name.
+pub(crate) const PIPE_OPERATOR: &str = "|>";
+
+/// Gets the byte range of each token in the expression, `None` for the tokens
+/// that don't appear as is, eg: `.5` split as `0.5`.
+fn token_spans(expression: &str, raw_tokens: &[String]) -> Vec<Option<(usize, usize)>> {
+    let mut cursor = 0;
+    raw_tokens
+        .iter()
+        .map(|token| {
+            let start = cursor + expression[cursor..].find(token.as_str())?;
+            cursor = start + token.len();
+            Some((start, cursor))
+        })
+        .collect()
+}
+
+/// Gets the char position of each token in the expression, the tokens that
+/// don't appear as is take the position after the previous token.
+fn token_positions(expression: &str, raw_tokens: &[String]) -> Vec<usize> {
+    let mut byte_position = 0;
+    let mut char_position = 0;
+
+    token_spans(expression, raw_tokens)
+        .into_iter()
+        .map(|span| match span {
+            Some((start, end)) => {
+                let position = char_position + expression[byte_position..start].chars().count();
+                char_position = position + expression[start..end].chars().count();
+                byte_position = end;
+                position
+            }
+            None => char_position,
+        })
+        .collect()
+}
+
+/// Joins the parts of the function names separated by the `Config` function name chars
+/// without whitespaces, eg: `math`, `.`, `sin` to `math.sin`.
+fn join_function_names<'a, N, C>(expression: &str, raw_tokens: Vec<String>, context: &C) -> Vec<String>
+where
+    C: Context<'a, N>,
+{
+    let is_joiner = |s: &str| s.chars().all(|c| context.config().is_function_name_char(c));
+    if !raw_tokens.iter().any(|s| is_joiner(s)) {
+        return raw_tokens;
+    }
+
+    // The start and end of each token in the expression, used to check for whitespaces
+    let spans = token_spans(expression, &raw_tokens);
+
+    let is_adjacent = |left: usize, right: usize| match (spans[left], spans[right]) {
+        (Some((_, end)), Some((start, _))) => end == start,
+        _ => false,
+    };
+
+    let is_name = |s: &str| s.starts_with(|c: char| c.is_alphabetic() || c == '_');
+
+    let mut result: Vec<String> = Vec::with_capacity(raw_tokens.len());
+    let mut index = 0;
+
+    while index < raw_tokens.len() {
+        let mut name = raw_tokens[index].clone();
+        let mut end = index;
+
+        if is_name(&name) {
+            // Finds the longest name that is a function, eg: `a.b.c`
+            let mut candidate = name.clone();
+            let mut next = index;
+
+            while next + 2 < raw_tokens.len()
+                && is_joiner(&raw_tokens[next + 1])
+                && is_name(&raw_tokens[next + 2])
+                && is_adjacent(next, next + 1)
+                && is_adjacent(next + 1, next + 2)
+            {
+                candidate.push_str(&raw_tokens[next + 1]);
+                candidate.push_str(&raw_tokens[next + 2]);
+                next += 2;
+
+                if context.is_function(&candidate) {
+                    name = candidate.clone();
+                    end = next;
+                }
+            }
+        }
+
+        result.push(name);
+        index = end + 1;
+    }
+
+    result
+}
+
+/// Checks if the value is the separator of the function arguments.
+#[inline]
+fn is_arg_separator(value: &str, separator: char) -> bool {
+    let mut chars = value.chars();
+    chars.next() == Some(separator) && chars.next().is_none()
+}
+
+/// Checks if the value is a grouping open symbol of the `Config`, eg: `(`.
+#[inline]
+fn is_grouping_open(value: &str, config: &Config) -> bool {
+    let mut chars = value.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => matches!(config.get_group_symbol(c), Some((open, _)) if open == c),
+        _ => false,
+    }
+}
+
+/// Gets the content of a quoted string literal eg: `"abc"`, resolving its escape sequences.
+/// Returns `None` if the value is not a closed string literal.
+fn parse_string_literal(value: &str) -> Option<String> {
+    if value.len() < 2 || !value.starts_with('"') || !value.ends_with('"') {
+        return None;
+    }
+
+    let mut result = String::new();
+    let mut chars = value[1..value.len() - 1].chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some('t') => result.push('\t'),
+            Some('r') => result.push('\r'),
+            Some('0') => result.push('\0'),
+            Some(escaped) => result.push(escaped),
+            // The closing quote is escaped eg: `"abc\"`
+            None => return None,
+        }
+    }
+
+    Some(result)
+}
+
 // TODO: remove
 #[allow(unused)]
 fn is_number(value: &str) -> bool {
@@ -298,15 +609,11 @@ fn is_number(value: &str) -> bool {
                 }
             }
             (_, '1'..='9') => {}
-            (n, '.') if n < value_len - 1 => {
-                if !is_signed || n > 1 {
-                    if has_decimal_point {
-                        return false;
-                    } else {
-                        has_decimal_point = true;
-                    }
-                } else {
+            (n, '.') if n < value_len - 1 && (!is_signed || n > 1) => {
+                if has_decimal_point {
                     return false;
+                } else {
+                    has_decimal_point = true;
                 }
             }
             _ => {
@@ -515,4 +822,203 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn tokenize_string_literal_test() {
+        use crate::utils::splitter::rules::{
+            SplitIdentifier, SplitNumeric, SplitOperator, SplitStringLiteral,
+        };
+        use crate::utils::splitter::SplitWhitespaceOption;
+
+        let splitter = DefaultSplitter::builder()
+            .rule(SplitNumeric)
+            .rule(SplitIdentifier)
+            .rule(SplitStringLiteral)
+            .rule(SplitOperator)
+            .whitespace(SplitWhitespaceOption::Remove)
+            .build();
+
+        let mut context = DefaultContext::<f64>::new();
+        context.add_function(FooFunction).unwrap();
+
+        let tokenizer = Tokenizer::with_splitter(splitter);
+
+        assert_eq!(
+            tokenizer.tokenize(&context, "foo(\"a,b\")").unwrap(),
+            [
+                Function("foo".to_string()),
+                GroupingOpen('('),
+                Str("a,b".to_string()),
+                GroupingClose(')')
+            ]
+        );
+
+        assert_eq!(
+            tokenizer.tokenize(&context, "foo(\"\\\"a\\\"\\n\", 2)").unwrap(),
+            [
+                Function("foo".to_string()),
+                GroupingOpen('('),
+                Str("\"a\"\n".to_string()),
+                Comma,
+                Number(2_f64),
+                GroupingClose(')')
+            ]
+        );
+
+        struct FooFunction;
+        impl crate::function::Function<f64> for FooFunction {
+            fn name(&self) -> &str {
+                "foo"
+            }
+
+            fn call(&self, _: &[f64]) -> Result<f64> {
+                Ok(0_f64)
+            }
+        }
+    }
+
+    #[test]
+    fn tokenize_number_with_unit_test() {
+        use crate::context::Config;
+        use crate::utils::splitter::SplitWhitespaceOption;
+
+        let config = Config::new().with_unit("km").with_unit("m");
+        let mut context: DefaultContext<i64> = DefaultContext::with_config_checked(config);
+        context.set_variable("x", 2).unwrap();
+
+        let tokenizer: Tokenizer<i64> = Tokenizer::new();
+
+        assert_eq!(
+            tokenizer.tokenize(&context, "5km").unwrap(),
+            [NumberWithUnit(5, "km".to_string())]
+        );
+        assert_eq!(
+            tokenizer.tokenize(&context, "5x").unwrap(),
+            [Number(5), Variable("x".to_string())]
+        );
+        assert_eq!(
+            tokenizer.tokenize(&context, "2km + 300m").unwrap(),
+            [
+                NumberWithUnit(2, "km".to_string()),
+                BinaryOperator('+'.to_string()),
+                NumberWithUnit(300, "m".to_string())
+            ]
+        );
+
+        // The unit should follow the number when whitespaces are kept
+        let tokenizer: Tokenizer<i64> =
+            Tokenizer::with_splitter(DefaultSplitter::new(SplitWhitespaceOption::None));
+        assert_eq!(
+            tokenizer.tokenize(&context, "5 km").unwrap(),
+            [Number(5), Unknown("km".to_string())]
+        );
+    }
+
+    #[test]
+    fn tokenize_out_of_range_literal_test() {
+        let context: DefaultContext<i128> = DefaultContext::new_checked();
+        let tokenizer: Tokenizer<i128> = Tokenizer::new();
+
+        let error = tokenizer
+            .tokenize(&context, "99999999999999999999999999999999999999999 + 1")
+            .unwrap_err();
+
+        assert_eq!(error.kind(), ErrorKind::Overflow);
+        assert_eq!(
+            error.to_string(),
+            "Integer literal `99999999999999999999999999999999999999999` is out of range for `i128`"
+        );
+
+        assert_eq!(
+            tokenizer.tokenize(&context, "170141183460469231731687303715884105727").unwrap(),
+            [Number(i128::MAX)]
+        );
+
+        let context: DefaultContext<i8> = DefaultContext::new_checked();
+        let tokenizer: Tokenizer<i8> = Tokenizer::new();
+        assert_eq!(tokenizer.tokenize(&context, "127").unwrap(), [Number(127)]);
+        assert_eq!(tokenizer.tokenize(&context, "128").unwrap_err().kind(), ErrorKind::Overflow);
+        assert_eq!(tokenizer.tokenize(&context, "1 + 128").unwrap_err().position(), Some(4));
+    }
+
+    #[test]
+    fn tokenize_decimal_point_test() {
+        let context: DefaultContext<f64> = DefaultContext::new_checked();
+        let tokenizer: Tokenizer<f64> = Tokenizer::new();
+
+        assert_eq!(tokenizer.tokenize(&context, ".5").unwrap(), [Number(0.5)]);
+        assert_eq!(tokenizer.tokenize(&context, "5.").unwrap(), [Number(5.0)]);
+        assert_eq!(
+            tokenizer.tokenize(&context, "2*.25").unwrap(),
+            [Number(2.0), BinaryOperator("*".to_string()), Number(0.25)]
+        );
+
+        // A lone decimal point is not a number
+        assert_eq!(tokenizer.tokenize(&context, ".").unwrap(), [Unknown(".".to_string())]);
+    }
+
+    #[test]
+    fn tokenize_strict_function_call_test() {
+        let context: DefaultContext<f64> =
+            DefaultContext::with_config_checked(Config::new().with_strict_function_call(true));
+        let tokenizer: Tokenizer<f64> = Tokenizer::new();
+
+        assert_eq!(
+            tokenizer.tokenize(&context, "random()").unwrap(),
+            [Function("random".to_string()), GroupingOpen('('), GroupingClose(')')]
+        );
+
+        let error = tokenizer.tokenize(&context, "random").unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidExpression);
+        assert_eq!(error.position(), Some(0));
+        assert_eq!(tokenizer.tokenize(&context, "2 * random + 1").unwrap_err().position(), Some(4));
+        assert_eq!(tokenizer.tokenize(&context, "2 * 3 +").unwrap_err().position(), Some(6));
+    }
+
+    #[test]
+    fn tokenize_scientific_notation_test() {
+        let context: DefaultContext<f64> = DefaultContext::new_checked();
+        let tokenizer: Tokenizer<f64> = Tokenizer::new();
+
+        assert_eq!(tokenizer.tokenize(&context, "2e3").unwrap(), [Number(2000.0)]);
+        assert_eq!(tokenizer.tokenize(&context, "1.5E-2").unwrap(), [Number(0.015)]);
+        assert_eq!(
+            tokenizer.tokenize(&context, "2*e").unwrap(),
+            [Number(2.0), BinaryOperator("*".to_string()), Constant("e".to_string())]
+        );
+
+        let context: DefaultContext<i64> = DefaultContext::new_checked();
+        let tokenizer: Tokenizer<i64> = Tokenizer::new();
+        assert_eq!(tokenizer.tokenize(&context, "2e3").unwrap(), [Number(2000)]);
+        assert_eq!(tokenizer.tokenize(&context, "2e-3").unwrap(), [Unknown("2e-3".to_string())]);
+
+        assert_eq!(expand_scientific("1.25e1"), Some("12.5".to_string()));
+        assert_eq!(expand_scientific("12e-3"), Some("0.012".to_string()));
+        assert_eq!(expand_scientific("1.5e+2"), Some("150".to_string()));
+        assert_eq!(expand_scientific("12"), None);
+        assert_eq!(expand_scientific("e3"), None);
+    }
+
+    #[test]
+    fn tokenize_namespaced_function_test() {
+        use crate::ops::math::SinFunction;
+
+        let config = Config::new().with_function_name_chars(&['.']);
+        let mut context: DefaultContext<f64> = DefaultContext::with_config_checked(config);
+        context.add_function_as(SinFunction, "math.sin").unwrap();
+        let tokenizer: Tokenizer<f64> = Tokenizer::new();
+
+        assert_eq!(
+            tokenizer.tokenize(&context, "math.sin(0)").unwrap(),
+            [Function("math.sin".to_string()), GroupingOpen('('), Number(0.0), GroupingClose(')')]
+        );
+
+        assert_eq!(
+            tokenizer.tokenize(&context, "math . sin(0)").unwrap()[0],
+            Unknown("math".to_string())
+        );
+
+        let evaluator = crate::evaluator::Evaluator::with_context(context);
+        assert_eq!(evaluator.eval("math.sin(0) + 1"), Ok(1.0));
+    }
 }
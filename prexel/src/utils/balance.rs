@@ -0,0 +1,83 @@
+use crate::context::Config;
+
+/// The state of the grouping symbols of an expression.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum BalanceState {
+    /// All the grouping symbols are closed.
+    Balanced,
+    /// There are the given number of grouping symbols that are not closed, eg: `(1+2`.
+    Unbalanced(usize),
+    /// A grouping symbol is closed by other kind of symbol or is closed without be open, eg: `(1+2]`.
+    Mismatched,
+}
+
+/// Checks if the grouping symbols of the `Config` in the expression are balanced,
+/// the symbols inside string literals are ignored.
+///
+/// # Example
+/// ```
+/// use prexel::context::Config;
+/// use prexel::utils::{is_balanced, BalanceState};
+///
+/// let config = Config::new();
+/// assert_eq!(is_balanced("(1+2)", &config), BalanceState::Balanced);
+/// assert_eq!(is_balanced("Max(1, (2", &config), BalanceState::Unbalanced(2));
+/// assert_eq!(is_balanced("(1+2]", &config), BalanceState::Mismatched);
+/// ```
+pub fn is_balanced(expression: &str, config: &Config) -> BalanceState {
+    let mut open_symbols = Vec::new();
+    let mut chars = expression.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '"' {
+            // Skips the string literal
+            while let Some(next) = chars.next() {
+                match next {
+                    '\\' => {
+                        chars.next();
+                    }
+                    '"' => break,
+                    _ => {}
+                }
+            }
+            continue;
+        }
+
+        if let Some((open, close)) = config.get_group_symbol(c) {
+            if c == open {
+                open_symbols.push(close);
+            } else if open_symbols.pop() != Some(c) {
+                return BalanceState::Mismatched;
+            }
+        }
+    }
+
+    if open_symbols.is_empty() {
+        BalanceState::Balanced
+    } else {
+        BalanceState::Unbalanced(open_symbols.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_balanced_test() {
+        let config = Config::new().with_grouping(crate::context::Grouping::Bracket);
+
+        assert_eq!(is_balanced("(1+2)", &config), BalanceState::Balanced);
+        assert_eq!(is_balanced("1+2", &config), BalanceState::Balanced);
+        assert_eq!(is_balanced("[(1+2)*3]", &config), BalanceState::Balanced);
+
+        assert_eq!(is_balanced("(1+2", &config), BalanceState::Unbalanced(1));
+        assert_eq!(is_balanced("((1+2", &config), BalanceState::Unbalanced(2));
+
+        assert_eq!(is_balanced("(1+2]", &config), BalanceState::Mismatched);
+        assert_eq!(is_balanced("1+2)", &config), BalanceState::Mismatched);
+
+        assert_eq!(is_balanced("len(\"(\")", &config), BalanceState::Balanced);
+        assert_eq!(is_balanced("len(\"a\\\")\"", &config), BalanceState::Unbalanced(1));
+    }
+}
@@ -0,0 +1,156 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use super::ignore_case_str::{eq_ignore_case, partial_cmp_by};
+
+/// Represents a `String` that ignores case when comparing, unless is case sensitive.
+///
+/// # Remarks
+/// Two values are compared with its case if any of them is case sensitive,
+/// the hash always ignores the case so both kind of values can be used as keys of the same map.
+#[derive(Clone)]
+pub struct CaseString {
+    value: String,
+    case_sensitive: bool,
+}
+
+impl CaseString {
+    /// Creates a new `CaseString`, which ignores case when comparing if not `case_sensitive`.
+    #[inline]
+    pub fn new(value: &str, case_sensitive: bool) -> CaseString {
+        CaseString {
+            value: value.to_string(),
+            case_sensitive,
+        }
+    }
+
+    /// Creates a new `CaseString` that ignores case when comparing.
+    #[inline]
+    pub fn ignore_case(value: &str) -> CaseString {
+        CaseString::new(value, false)
+    }
+
+    /// Checks if this value is compared with its case.
+    #[inline]
+    pub fn is_case_sensitive(&self) -> bool {
+        self.case_sensitive
+    }
+
+    /// Gets the inner value of this `CaseString`.
+    #[inline]
+    pub fn into_inner(self) -> String {
+        self.value
+    }
+
+    /// Gets this instance inner value as a `&str`.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self.value.as_str()
+    }
+}
+
+impl Deref for CaseString {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.value.as_str()
+    }
+}
+
+impl Display for CaseString {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.value.as_str())
+    }
+}
+
+impl Debug for CaseString {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.value.as_str())
+    }
+}
+
+impl Eq for CaseString {}
+
+impl PartialEq for CaseString {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        if self.case_sensitive || other.case_sensitive {
+            self.value == other.value
+        } else {
+            eq_ignore_case(&self.value, &other.value)
+        }
+    }
+}
+
+impl Ord for CaseString {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        let ordering = partial_cmp_by(self.value.chars(), other.value.chars(), |a, b| {
+            a.to_lowercase().partial_cmp(b.to_lowercase())
+        })
+        .unwrap();
+
+        if ordering == Ordering::Equal && (self.case_sensitive || other.case_sensitive) {
+            self.value.cmp(&other.value)
+        } else {
+            ordering
+        }
+    }
+}
+
+impl PartialOrd for CaseString {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Hash for CaseString {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for c in self.value.bytes() {
+            c.to_ascii_lowercase().hash(state);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn eq_test() {
+        assert_eq!(CaseString::ignore_case("HeLLo"), CaseString::ignore_case("hello"));
+        assert_eq!(CaseString::new("Hello", true), CaseString::new("Hello", true));
+        assert_ne!(CaseString::new("HeLLo", true), CaseString::new("hello", true));
+        assert_ne!(CaseString::new("HeLLo", true), CaseString::ignore_case("hello"));
+    }
+
+    #[test]
+    fn cmp_test() {
+        assert_eq!(CaseString::ignore_case("HeLLo").cmp(&CaseString::ignore_case("hello")), Ordering::Equal);
+        assert_eq!(CaseString::new("N", true).cmp(&CaseString::new("n", true)), Ordering::Less);
+        assert_eq!(CaseString::new("a", true).cmp(&CaseString::new("B", true)), Ordering::Less);
+    }
+
+    #[test]
+    fn map_key_test() {
+        let mut map = HashMap::new();
+        map.insert(CaseString::new("n", true), 1);
+        map.insert(CaseString::new("N", true), 2);
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get(&CaseString::new("n", true)), Some(&1));
+        assert_eq!(map.get(&CaseString::new("N", true)), Some(&2));
+
+        let mut map = HashMap::new();
+        map.insert(CaseString::ignore_case("n"), 1);
+        map.insert(CaseString::ignore_case("N"), 2);
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.get(&CaseString::ignore_case("n")), Some(&2));
+    }
+}
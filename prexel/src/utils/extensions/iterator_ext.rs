@@ -84,7 +84,7 @@ mod tests{
         let items = ["4a", "2b", "1c", "3d"];
         let sorted = items.iter()
             .copied()
-            .sorted_by_key(|c| c.chars().nth(0).map(|c| c as i32).unwrap())
+            .sorted_by_key(|c| c.chars().next().map(|c| c as i32).unwrap())
             .collect::<Vec<&str>>();
 
         assert_eq!(&["1c", "2b", "3d", "4a"], sorted.as_slice());
@@ -36,7 +36,7 @@ impl OptionStrExt for Option<String> {
     }
 }
 
-impl<'a> OptionStrExt for Option<&'a String> {
+impl OptionStrExt for Option<&String> {
     #[inline]
     fn contains_str(&self, value: &str) -> bool {
         match self {
@@ -24,7 +24,7 @@ pub trait StrExt{
 impl<'a, S: Borrow<&'a str>> StrExt for S{
     #[inline]
     fn eq_char(&self, ch: char) -> bool {
-        self.borrow().chars().next().map_or(false, |c| c == ch)
+        self.borrow().starts_with(ch)
             && self.borrow().chars().count() == 1
     }
 
@@ -34,9 +34,9 @@ impl<'a, S: Borrow<&'a str>> StrExt for S{
             return None;
         }
 
-        return self.borrow()
+        self.borrow()
             .chars()
-            .next();
+            .next()
     }
 
     fn to_sentence_case(&self) -> String {
@@ -0,0 +1,170 @@
+/// Inserts the given separator between each group of thousands of the integer parts
+/// of the numbers in the string, eg: `1234567.89` to `1,234,567.89`.
+///
+/// # Remarks
+/// The fractional parts and exponents are not grouped, so this also can be used with the
+/// display of complex numbers eg: `1000000+2500i` to `1,000,000+2,500i`.
+///
+/// # Example
+/// ```
+/// use prexel::utils::format::group_thousands;
+///
+/// assert_eq!(group_thousands("1000000", ','), "1,000,000");
+/// assert_eq!(group_thousands("-1234567.891", ','), "-1,234,567.891");
+/// ```
+pub fn group_thousands(value: &str, separator: char) -> String {
+    let mut result = String::with_capacity(value.len() + value.len() / 3);
+    let chars = value.chars().collect::<Vec<char>>();
+    let mut pos = 0;
+
+    while pos < chars.len() {
+        let c = chars[pos];
+
+        if !c.is_ascii_digit() {
+            result.push(c);
+            pos += 1;
+            continue;
+        }
+
+        let start = pos;
+        while pos < chars.len() && chars[pos].is_ascii_digit() {
+            pos += 1;
+        }
+
+        // Digits after a decimal point or an exponent are not grouped
+        let is_integer_part = match start.checked_sub(1).map(|i| chars[i]) {
+            Some('.') | Some('e') | Some('E') => false,
+            Some('+') | Some('-') => !matches!(
+                start.checked_sub(2).map(|i| chars[i]),
+                Some('e') | Some('E')
+            ),
+            _ => true,
+        };
+
+        let digits = &chars[start..pos];
+        for (i, digit) in digits.iter().enumerate() {
+            if is_integer_part && i > 0 && (digits.len() - i) % 3 == 0 {
+                result.push(separator);
+            }
+
+            result.push(*digit);
+        }
+    }
+
+    result
+}
+
+/// Formats the number in the string in scientific notation with the given number of digits
+/// after the decimal point, eg: `123456` to `1.23e5` with a precision of 2.
+///
+/// # Remarks
+/// The digits are rounded from the string itself so the result is exact for numbers of any size,
+/// returns `None` if the string is not a plain decimal number, eg: `NaN` or `1+2i`.
+///
+/// # Example
+/// ```
+/// use prexel::utils::format::to_scientific;
+///
+/// assert_eq!(to_scientific("123456", 2).unwrap(), "1.23e5");
+/// assert_eq!(to_scientific("-0.000999", 1).unwrap(), "-1.0e-3");
+/// assert_eq!(to_scientific("NaN", 2), None);
+/// ```
+pub fn to_scientific(value: &str, precision: usize) -> Option<String> {
+    let (sign, unsigned) = match value.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", value.strip_prefix('+').unwrap_or(value)),
+    };
+
+    let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+
+    if integer.is_empty() || !is_digits(integer) || !is_digits(fraction) {
+        return None;
+    }
+
+    let integer = integer.trim_start_matches('0');
+    let (mut exponent, significand) = if integer.is_empty() {
+        let leading_zeros = fraction.len() - fraction.trim_start_matches('0').len();
+        (-(leading_zeros as i64) - 1, fraction.trim_start_matches('0').to_owned())
+    } else {
+        (integer.len() as i64 - 1, format!("{}{}", integer, fraction))
+    };
+
+    if significand.is_empty() {
+        let zeros = "0".repeat(precision);
+        let point = if precision > 0 { "." } else { "" };
+        return Some(format!("0{}{}e0", point, zeros));
+    }
+
+    // Takes the digits to show and rounds half up using the next digit
+    let mut digits = significand.bytes().map(|b| b - b'0').collect::<Vec<u8>>();
+    digits.resize(digits.len().max(precision + 2), 0);
+    let round_up = digits[precision + 1] >= 5;
+    digits.truncate(precision + 1);
+
+    if round_up {
+        let mut pos = digits.len();
+        loop {
+            if pos == 0 {
+                // All the digits were 9, eg: 9.99 to 10.0
+                digits.insert(0, 1);
+                digits.pop();
+                exponent += 1;
+                break;
+            }
+
+            pos -= 1;
+            if digits[pos] == 9 {
+                digits[pos] = 0;
+            } else {
+                digits[pos] += 1;
+                break;
+            }
+        }
+    }
+
+    let mut result = String::with_capacity(precision + 8);
+    result.push_str(sign);
+    result.push((b'0' + digits[0]) as char);
+
+    if precision > 0 {
+        result.push('.');
+        result.extend(digits[1..].iter().map(|d| (b'0' + d) as char));
+    }
+
+    result.push('e');
+    result.push_str(&exponent.to_string());
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{group_thousands, to_scientific};
+
+    #[test]
+    fn group_thousands_test() {
+        assert_eq!(group_thousands("1000000", ','), "1,000,000");
+        assert_eq!(group_thousands("1234567.89", ','), "1,234,567.89");
+        assert_eq!(group_thousands("0.123456", ','), "0.123456");
+        assert_eq!(group_thousands("999", ','), "999");
+        assert_eq!(group_thousands("-1000", ','), "-1,000");
+        assert_eq!(group_thousands("1000000", '_'), "1_000_000");
+        assert_eq!(group_thousands("1.5e1000", ','), "1.5e1000");
+        assert_eq!(group_thousands("1000000+2500i", ','), "1,000,000+2,500i");
+        assert_eq!(group_thousands("NaN", ','), "NaN");
+    }
+
+    #[test]
+    fn to_scientific_test() {
+        assert_eq!(to_scientific("123456", 2).unwrap(), "1.23e5");
+        assert_eq!(to_scientific("123556", 2).unwrap(), "1.24e5");
+        assert_eq!(to_scientific("999.6", 2).unwrap(), "1.00e3");
+        assert_eq!(to_scientific("5", 3).unwrap(), "5.000e0");
+        assert_eq!(to_scientific("0.00125", 1).unwrap(), "1.3e-3");
+        assert_eq!(to_scientific("-42.5", 0).unwrap(), "-4e1");
+        assert_eq!(to_scientific("0.0", 2).unwrap(), "0.00e0");
+        assert_eq!(to_scientific("1e10", 2), None);
+        assert_eq!(to_scientific("1000000+2500i", 2), None);
+        assert_eq!(to_scientific("", 2), None);
+    }
+}
@@ -58,6 +58,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::excessive_precision)]
     fn gamma_test() {
         const ERROR: f64 = 0.00000000001;
         assert!(f64_eq(
@@ -62,7 +62,10 @@ impl<'a> Eq for IgnoreCaseStr<'a> {}
 impl<'a> Ord for IgnoreCaseStr<'a> {
     #[inline]
     fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap()
+        partial_cmp_by(self.0.chars(), other.0.chars(), |a, b| {
+            a.to_lowercase().partial_cmp(b.to_lowercase())
+        })
+        .unwrap()
     }
 }
 
@@ -76,9 +79,7 @@ impl<'a> PartialEq for IgnoreCaseStr<'a> {
 impl<'a> PartialOrd for IgnoreCaseStr<'a> {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        partial_cmp_by(self.0.chars(), other.0.chars(), |a, b| {
-            a.to_lowercase().partial_cmp(b.to_lowercase())
-        })
+        Some(self.cmp(other))
     }
 }
 
@@ -76,16 +76,17 @@ impl PartialEq for IgnoreCaseString {
 impl Ord for IgnoreCaseString {
     #[inline]
     fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap()
+        partial_cmp_by(self.0.chars(), other.0.chars(), |a, b| {
+            a.to_lowercase().partial_cmp(b.to_lowercase())
+        })
+        .unwrap()
     }
 }
 
 impl PartialOrd for IgnoreCaseString {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        partial_cmp_by(self.0.chars(), other.0.chars(), |a, b| {
-            a.to_lowercase().partial_cmp(b.to_lowercase())
-        })
+        Some(self.cmp(other))
     }
 }
 
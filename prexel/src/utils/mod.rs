@@ -1,6 +1,11 @@
 pub mod gamma;
 pub mod ignore_case_str;
 pub mod ignore_case_string;
+pub mod case_string;
 pub mod splitter;
 pub mod extensions;
 pub mod approx;
+pub mod format;
+pub mod balance;
+
+pub use balance::{is_balanced, BalanceState};
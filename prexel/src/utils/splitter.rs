@@ -40,7 +40,7 @@ impl<'a> DefaultSplitter<'a> {
             .build()
     }
 
-    pub fn with_numeric_rule<F: 'a>(rule: F) -> Self
+    pub fn with_numeric_rule<F>(rule: F) -> Self
     where
         F: SplitRule + 'a,
     {
@@ -52,7 +52,7 @@ impl<'a> DefaultSplitter<'a> {
             .build()
     }
 
-    pub fn with_identifier_rule<F: 'a>(rule: F) -> Self
+    pub fn with_identifier_rule<F>(rule: F) -> Self
     where
         F: SplitRule + 'a,
     {
@@ -131,7 +131,7 @@ impl<'a> DefaultSplitterBuilder<'a> {
         }
     }
 
-    pub fn insert_rule<F: 'a>(mut self, index: usize, rule: F) -> Self
+    pub fn insert_rule<F>(mut self, index: usize, rule: F) -> Self
     where
         F: SplitRule + 'a,
     {
@@ -139,7 +139,7 @@ impl<'a> DefaultSplitterBuilder<'a> {
         self
     }
 
-    pub fn rule<F: 'a>(mut self, rule: F) -> Self
+    pub fn rule<F>(mut self, rule: F) -> Self
     where
         F: SplitRule + 'a,
     {
@@ -196,27 +196,76 @@ pub mod rules {
         fn split(&self, c: char, rest: &mut Peekable<Chars>) -> Outcome;
     }
 
+    /// Splits an identifier, which starts with a letter or `_` followed by letters, digits
+    /// or `_`, eg: `log2`, `deg2rad` or `a_b_c`.
     pub struct SplitIdentifier;
     impl SplitRule for SplitIdentifier {
+        #[inline]
         fn split(&self, c: char, rest: &mut Peekable<Chars>) -> Outcome {
-            #[inline]
-            fn is_valid_char(c: &char) -> bool {
-                matches!(c, 'a'..='z' | 'A'..='Z' | '0'..='9' | '_')
+            split_identifier(c, rest, |_| false)
+        }
+    }
+
+    /// Splits an identifier as `SplitIdentifier`, which also can contain the given characters
+    /// between its letters, digits or `_`, eg: `'` in `a'b` or `$` in `x$1`.
+    ///
+    /// # Remarks
+    /// A joiner at the end of the identifier is not part of it, eg: `a'` is split as `a` and `'`.
+    /// This rule should be placed before `SplitOperator` if any of the joiners is an operator.
+    pub struct SplitIdentifierWith {
+        joiners: HashSet<char>,
+    }
+
+    impl SplitIdentifierWith {
+        /// Constructs a `SplitIdentifierWith` using the given joiner characters.
+        pub fn new(joiners: &[char]) -> Self {
+            SplitIdentifierWith {
+                joiners: joiners.iter().cloned().collect(),
             }
+        }
+    }
 
-            match c {
-                'a'..='z' | 'A'..='Z' | '_' => {
-                    let mut temp = String::new();
-                    temp.push(c);
+    impl SplitRule for SplitIdentifierWith {
+        #[inline]
+        fn split(&self, c: char, rest: &mut Peekable<Chars>) -> Outcome {
+            split_identifier(c, rest, |c| self.joiners.contains(c))
+        }
+    }
 
-                    while let Some(c) = rest.next_if(is_valid_char) {
+    fn split_identifier<F>(c: char, rest: &mut Peekable<Chars>, is_joiner: F) -> Outcome
+    where
+        F: Fn(&char) -> bool,
+    {
+        #[inline]
+        fn is_valid_char(c: &char) -> bool {
+            matches!(c, 'a'..='z' | 'A'..='Z' | '0'..='9' | '_')
+        }
+
+        match c {
+            'a'..='z' | 'A'..='Z' | '_' => {
+                let mut temp = String::new();
+                temp.push(c);
+
+                loop {
+                    if let Some(c) = rest.next_if(is_valid_char) {
                         temp.push(c);
+                        continue;
                     }
 
-                    Outcome::Data(temp)
+                    // A joiner is only taken if is followed by other identifier char
+                    let mut ahead = rest.clone();
+                    match ahead.next() {
+                        Some(joiner) if is_joiner(&joiner) && ahead.peek().is_some_and(is_valid_char) => {
+                            temp.push(joiner);
+                            rest.next();
+                        }
+                        _ => break,
+                    }
                 }
-                _ => Outcome::Continue,
+
+                Outcome::Data(temp)
             }
+            _ => Outcome::Continue,
         }
     }
 
@@ -229,11 +278,20 @@ pub mod rules {
             }
 
             match c {
-                '0'..='9' => {
+                '0'..='9' | '.' => {
                     let mut temp = String::new();
-                    temp.push(c);
+                    let mut has_decimal_point = c == '.';
 
-                    let mut has_decimal_point = false;
+                    // A leading decimal point needs a digit after it: `.5` is read as `0.5`
+                    if has_decimal_point {
+                        if !matches!(rest.peek(), Some('0'..='9')) {
+                            return Outcome::Continue;
+                        }
+
+                        temp.push('0');
+                    }
+
+                    temp.push(c);
 
                     while let Some(c) = rest.next_if(is_valid_char) {
                         if c == '.' {
@@ -247,6 +305,12 @@ pub mod rules {
                         temp.push(c);
                     }
 
+                    // Scientific notation takes precedence over a name starting with `e`
+                    // only if the exponent follows, eg: `2e3` and `2e-3` but not `2e` or `2exp(1)`
+                    if let Some(exponent) = next_exponent(rest) {
+                        temp.push_str(&exponent);
+                    }
+
                     Outcome::Data(temp)
                 }
                 _ => Outcome::Continue,
@@ -254,6 +318,25 @@ pub mod rules {
         }
     }
 
+    /// Takes the exponent of a number in scientific notation, eg: `e3`, `E-3` or `e+3`.
+    fn next_exponent(rest: &mut Peekable<Chars>) -> Option<String> {
+        let mut ahead = rest.clone();
+        let mut exponent = String::new();
+
+        exponent.push(ahead.next().filter(|c| matches!(c, 'e' | 'E'))?);
+        if let Some(sign) = ahead.next_if(|c| matches!(c, '+' | '-')) {
+            exponent.push(sign);
+        }
+
+        ahead.peek().filter(|c| c.is_ascii_digit())?;
+        while let Some(c) = ahead.next_if(char::is_ascii_digit) {
+            exponent.push(c);
+        }
+
+        *rest = ahead;
+        Some(exponent)
+    }
+
     pub struct SplitOperator;
     impl SplitRule for SplitOperator {
         fn split(&self, c: char, rest: &mut Peekable<Chars>) -> Outcome {
@@ -293,8 +376,12 @@ pub mod rules {
                     let mut temp = String::new();
                     temp.push(c);
 
-                    while let Some(c) = rest.next_if(is_valid_char) {
-                        temp.push(c);
+                    // Leaves a decimal point followed by a digit to `SplitNumeric`, eg: `*.5`
+                    while !is_decimal_point_number(rest) {
+                        match rest.next_if(is_valid_char) {
+                            Some(c) => temp.push(c),
+                            None => break,
+                        }
                     }
 
                     Outcome::Data(temp)
@@ -304,9 +391,20 @@ pub mod rules {
         }
     }
 
+    fn is_decimal_point_number(rest: &Peekable<Chars>) -> bool {
+        let mut ahead = rest.clone();
+        ahead.next() == Some('.') && matches!(ahead.next(), Some('0'..='9'))
+    }
+
     pub struct SplitWithOperatorsBuilder {
         operators: HashSet<char>,
     }
+    impl Default for SplitWithOperatorsBuilder {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
     impl SplitWithOperatorsBuilder {
         pub fn new() -> Self {
             let operators = HashSet::from([
@@ -341,6 +439,12 @@ pub mod rules {
     pub struct SplitWithOperators {
         operators: HashSet<char>,
     }
+    impl Default for SplitWithOperators {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
     impl SplitWithOperators {
         pub fn new() -> Self {
             SplitWithOperatorsBuilder::new().build()
@@ -372,6 +476,40 @@ pub mod rules {
         }
     }
 
+    /// Splits a quoted string literal eg: `"a, b"`.
+    ///
+    /// # Remarks
+    /// The resulting data includes the surrounding quotes and the escape sequences
+    /// as written, so `\"` within the literal doesn't close it.
+    /// This rule should be placed before `SplitOperator` because `"` is considered an operator.
+    pub struct SplitStringLiteral;
+    impl SplitRule for SplitStringLiteral {
+        fn split(&self, c: char, rest: &mut Peekable<Chars>) -> Outcome {
+            if c != '"' {
+                return Outcome::Continue;
+            }
+
+            let mut temp = String::new();
+            temp.push(c);
+
+            while let Some(c) = rest.next() {
+                temp.push(c);
+
+                match c {
+                    '\\' => {
+                        if let Some(escaped) = rest.next() {
+                            temp.push(escaped);
+                        }
+                    }
+                    '"' => break,
+                    _ => {}
+                }
+            }
+
+            Outcome::Data(temp)
+        }
+    }
+
     pub struct SkipWhitespace;
     impl SplitRule for SkipWhitespace {
         fn split(&self, c: char, _: &mut Peekable<Chars>) -> Outcome {
@@ -415,6 +553,7 @@ pub mod rules {
 
 #[cfg(test)]
 mod tests {
+    use super::rules::{SplitIdentifier, SplitIdentifierWith, SplitNumeric, SplitOperator, SplitStringLiteral};
     use super::DefaultSplitter;
     use super::{SplitWhitespaceOption, Splitter};
 
@@ -456,4 +595,75 @@ mod tests {
             DefaultSplitter::default().split_into_tokens("256 >> 3")
         );
     }
+
+    #[test]
+    fn split_decimal_point_test() {
+        let splitter = DefaultSplitter::default();
+
+        assert_eq!(["0.5", "+", "1"].to_vec(), splitter.split_into_tokens(".5 + 1"));
+        assert_eq!(["2", "*", "0.25"].to_vec(), splitter.split_into_tokens("2*.25"));
+        assert_eq!(["5.", "+", "1"].to_vec(), splitter.split_into_tokens("5. + 1"));
+        assert_eq!(["."].to_vec(), splitter.split_into_tokens("."));
+    }
+
+    #[test]
+    fn split_scientific_notation_test() {
+        let splitter = DefaultSplitter::default();
+
+        assert_eq!(["2e3"].to_vec(), splitter.split_into_tokens("2e3"));
+        assert_eq!(["1.5E-2", "+", "1"].to_vec(), splitter.split_into_tokens("1.5E-2 + 1"));
+        assert_eq!(["2e+3"].to_vec(), splitter.split_into_tokens("2e+3"));
+        assert_eq!(["2", "e"].to_vec(), splitter.split_into_tokens("2e"));
+        assert_eq!(["2", "e", "+", "x"].to_vec(), splitter.split_into_tokens("2e+x"));
+        assert_eq!(["2", "exp", "(", "1", ")"].to_vec(), splitter.split_into_tokens("2exp(1)"));
+    }
+
+    #[test]
+    fn split_identifier_test() {
+        let splitter = DefaultSplitter::default();
+
+        assert_eq!(["log2", "(", "8", ")"].to_vec(), splitter.split_into_tokens("log2(8)"));
+        assert_eq!(["deg2rad", "(", "180", ")"].to_vec(), splitter.split_into_tokens("deg2rad(180)"));
+        assert_eq!(["a_b_c", "+", "_x1"].to_vec(), splitter.split_into_tokens("a_b_c + _x1"));
+        assert_eq!(["2", "log10"].to_vec(), splitter.split_into_tokens("2log10"));
+    }
+
+    #[test]
+    fn split_identifier_with_joiners_test() {
+        let splitter = DefaultSplitter::builder()
+            .rule(SplitNumeric)
+            .rule(SplitIdentifierWith::new(&['\'', '$']))
+            .rule(SplitOperator)
+            .whitespace(SplitWhitespaceOption::Remove)
+            .build();
+
+        assert_eq!(["a'b", "+", "x$1"].to_vec(), splitter.split_into_tokens("a'b + x$1"));
+        assert_eq!(["log2", "*", "a_b_c"].to_vec(), splitter.split_into_tokens("log2 * a_b_c"));
+        assert_eq!(["a", "'", "+", "b"].to_vec(), splitter.split_into_tokens("a' + b"));
+        assert_eq!(["a", "$$", "b"].to_vec(), splitter.split_into_tokens("a$$b"));
+    }
+
+    #[test]
+    fn split_string_literal_test() {
+        let splitter = DefaultSplitter::builder()
+            .rule(SplitNumeric)
+            .rule(SplitIdentifier)
+            .rule(SplitStringLiteral)
+            .rule(SplitOperator)
+            .whitespace(SplitWhitespaceOption::Remove)
+            .build();
+
+        assert_eq!(
+            ["foo", "(", "\"a,b\"", ")"].to_vec(),
+            splitter.split_into_tokens("foo(\"a,b\")")
+        );
+        assert_eq!(
+            ["len", "(", "\"say \\\"hi\\\"\"", ",", "2", ")"].to_vec(),
+            splitter.split_into_tokens("len(\"say \\\"hi\\\"\", 2)")
+        );
+        assert_eq!(
+            ["\"unclosed"].to_vec(),
+            splitter.split_into_tokens("\"unclosed")
+        );
+    }
 }